@@ -1,7 +1,11 @@
 use std::fmt::{Display, Formatter};
 
 use crate::iggy_user::IggyUser;
+use crate::TopicConfig;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::identifier::Identifier;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct IggyConfig {
@@ -14,6 +18,10 @@ pub struct IggyConfig {
     partition_id: u32,
     messages_per_batch: u32,
     auto_commit: bool,
+    partitions_count: u32,
+    compression_algorithm: CompressionAlgorithm,
+    message_expiry: IggyExpiry,
+    max_topic_size: MaxTopicSize,
 }
 
 impl IggyConfig {
@@ -36,6 +44,10 @@ impl IggyConfig {
             partition_id,
             messages_per_batch,
             auto_commit,
+            partitions_count: 1,
+            compression_algorithm: CompressionAlgorithm::None,
+            message_expiry: IggyExpiry::ServerDefault,
+            max_topic_size: MaxTopicSize::ServerDefault,
         }
     }
 
@@ -55,6 +67,48 @@ impl IggyConfig {
             partition_id: client_id,
             messages_per_batch,
             auto_commit,
+            partitions_count: 1,
+            compression_algorithm: CompressionAlgorithm::None,
+            message_expiry: IggyExpiry::ServerDefault,
+            max_topic_size: MaxTopicSize::ServerDefault,
+        }
+    }
+}
+
+impl IggyConfig {
+    /// Sets the number of partitions of each provisioned topic, 1 by default.
+    #[must_use]
+    pub fn with_partitions_count(self, partitions_count: u32) -> Self {
+        Self {
+            partitions_count,
+            ..self
+        }
+    }
+
+    /// Sets the compression of each provisioned topic, none by default.
+    #[must_use]
+    pub fn with_compression_algorithm(self, compression_algorithm: CompressionAlgorithm) -> Self {
+        Self {
+            compression_algorithm,
+            ..self
+        }
+    }
+
+    /// Sets the message expiry of each provisioned topic, the server default by default.
+    #[must_use]
+    pub fn with_message_expiry(self, message_expiry: IggyExpiry) -> Self {
+        Self {
+            message_expiry,
+            ..self
+        }
+    }
+
+    /// Sets the maximum size of each provisioned topic, the server default by default.
+    #[must_use]
+    pub fn with_max_topic_size(self, max_topic_size: MaxTopicSize) -> Self {
+        Self {
+            max_topic_size,
+            ..self
         }
     }
 }
@@ -87,6 +141,26 @@ impl IggyConfig {
     pub fn user(&self) -> &IggyUser {
         &self.user
     }
+    pub fn partitions_count(&self) -> u32 {
+        self.partitions_count
+    }
+    pub fn compression_algorithm(&self) -> CompressionAlgorithm {
+        self.compression_algorithm
+    }
+    pub fn message_expiry(&self) -> IggyExpiry {
+        self.message_expiry
+    }
+    pub fn max_topic_size(&self) -> MaxTopicSize {
+        self.max_topic_size
+    }
+
+    /// Returns the settings of each provisioned topic.
+    pub fn topic_config(&self) -> TopicConfig {
+        TopicConfig::new(self.partitions_count)
+            .with_compression_algorithm(self.compression_algorithm)
+            .with_message_expiry(self.message_expiry)
+            .with_max_topic_size(self.max_topic_size)
+    }
 }
 
 impl Display for IggyConfig {
@@ -95,7 +169,8 @@ impl Display for IggyConfig {
             f,
             "IggyConfig: \
             iggy_user: {} tcp_server_addr: {}, stream_id: {}, stream_name: {}, topic_id: {}, topic_name: {}, \
-             partition_id: {}, messages_per_batch: {}, auto_commit: {}",
+             partition_id: {}, messages_per_batch: {}, auto_commit: {}, partitions_count: {}, \
+             compression_algorithm: {}, message_expiry: {}, max_topic_size: {}",
             self.user.username(),
             self.tcp_server_addr,
             self.stream_id,
@@ -105,6 +180,10 @@ impl Display for IggyConfig {
             self.partition_id,
             self.messages_per_batch,
             self.auto_commit,
+            self.partitions_count,
+            self.compression_algorithm,
+            self.message_expiry,
+            self.max_topic_size,
         )
     }
 }
@@ -1,5 +1,12 @@
 mod iggy_config;
 mod iggy_user;
+mod provisioning;
+mod topic_config;
 
 pub use iggy_config::IggyConfig;
 pub use iggy_user::IggyUser;
+pub use provisioning::{
+    provision, provision_stream, provision_stream_user, Drift, ProvisioningError,
+    ProvisioningReport,
+};
+pub use topic_config::TopicConfig;
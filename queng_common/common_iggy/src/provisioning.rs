@@ -0,0 +1,441 @@
+use crate::{IggyConfig, TopicConfig};
+use ahash::AHashMap;
+use common_ims::IntegrationConfig;
+use iggy::client::{PartitionClient, StreamClient, TopicClient, UserClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::permissions::{Permissions, StreamPermissions};
+use iggy::models::topic::TopicDetails;
+use iggy::models::user_status::UserStatus;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use std::error::Error;
+use std::fmt;
+
+/// Error returned when provisioning a stream, topic or stream user fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProvisioningError {
+    /// The stream, topic or user name is not a valid identifier.
+    InvalidName(String),
+    /// Reading or creating the stream failed.
+    Stream { stream: String, message: String },
+    /// Reading, creating or extending the topic failed.
+    Topic {
+        stream: String,
+        topic: String,
+        message: String,
+    },
+    /// Creating the stream user or updating its permissions failed.
+    User { username: String, message: String },
+}
+
+impl Error for ProvisioningError {}
+
+impl fmt::Display for ProvisioningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName(name) => {
+                write!(f, "ProvisioningError: Invalid name: {name}")
+            }
+            Self::Stream { stream, message } => {
+                write!(f, "ProvisioningError: Stream {stream}: {message}")
+            }
+            Self::Topic {
+                stream,
+                topic,
+                message,
+            } => write!(
+                f,
+                "ProvisioningError: Topic {topic} of stream {stream}: {message}"
+            ),
+            Self::User { username, message } => {
+                write!(f, "ProvisioningError: User {username}: {message}")
+            }
+        }
+    }
+}
+
+/// Difference between the desired and the actual settings of a topic.
+///
+/// Drift is reported, but not corrected, because changing the partitions, retention
+/// or compression of a topic in use affects the data already stored in it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Drift {
+    /// The topic has more partitions than desired.
+    PartitionsCount {
+        topic: String,
+        desired: u32,
+        actual: u32,
+    },
+    /// The topic uses another compression algorithm.
+    Compression {
+        topic: String,
+        desired: CompressionAlgorithm,
+        actual: CompressionAlgorithm,
+    },
+    /// The topic uses another message expiry.
+    MessageExpiry {
+        topic: String,
+        desired: IggyExpiry,
+        actual: IggyExpiry,
+    },
+    /// The topic uses another maximum size.
+    MaxTopicSize {
+        topic: String,
+        desired: MaxTopicSize,
+        actual: MaxTopicSize,
+    },
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PartitionsCount {
+                topic,
+                desired,
+                actual,
+            } => write!(
+                f,
+                "Topic {topic}: partitions count is {actual}, desired {desired}"
+            ),
+            Self::Compression {
+                topic,
+                desired,
+                actual,
+            } => write!(
+                f,
+                "Topic {topic}: compression is {actual}, desired {desired}"
+            ),
+            Self::MessageExpiry {
+                topic,
+                desired,
+                actual,
+            } => write!(
+                f,
+                "Topic {topic}: message expiry is {actual}, desired {desired}"
+            ),
+            Self::MaxTopicSize {
+                topic,
+                desired,
+                actual,
+            } => write!(
+                f,
+                "Topic {topic}: max topic size is {actual}, desired {desired}"
+            ),
+        }
+    }
+}
+
+/// Changes applied and drift found by `provision` and `provision_stream`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProvisioningReport {
+    stream_created: bool,
+    topics_created: Vec<String>,
+    partitions_added: Vec<(String, u32)>,
+    drift: Vec<Drift>,
+}
+
+impl ProvisioningReport {
+    /// Returns true if the stream was created.
+    #[must_use]
+    pub const fn stream_created(&self) -> bool {
+        self.stream_created
+    }
+
+    /// Returns the names of the created topics.
+    #[must_use]
+    pub fn topics_created(&self) -> &[String] {
+        &self.topics_created
+    }
+
+    /// Returns the names of the extended topics with the number of added partitions.
+    #[must_use]
+    pub fn partitions_added(&self) -> &[(String, u32)] {
+        &self.partitions_added
+    }
+
+    /// Returns the drift of existing topics from the desired settings.
+    #[must_use]
+    pub fn drift(&self) -> &[Drift] {
+        &self.drift
+    }
+
+    /// Returns true if all topics match the desired settings.
+    #[must_use]
+    pub fn is_in_sync(&self) -> bool {
+        self.drift.is_empty()
+    }
+}
+
+/// Ensures the stream and channel topics of an integration exist.
+///
+/// The stream is named after the integration id and holds one topic for each of the
+/// control, data, error and execution channels and the dead letter channel.
+/// Topics are created with the partitions count, compression, message expiry and
+/// maximum size of the `IggyConfig`; see `provision_stream` for the details.
+///
+/// # Arguments
+///
+/// * `client` - A connected `IggyClient`, logged in as user allowed to manage streams.
+/// * `integration_config` - The integration providing the stream and channel names.
+/// * `iggy_config` - The desired topic settings.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `ProvisioningReport` or a `ProvisioningError`.
+///
+/// # Errors
+///
+/// Returns a `ProvisioningError` if a name is invalid or a server request fails.
+///
+pub async fn provision(
+    client: &IggyClient,
+    integration_config: &IntegrationConfig,
+    iggy_config: &IggyConfig,
+) -> Result<ProvisioningReport, ProvisioningError> {
+    let topics = [
+        integration_config.control_channel(),
+        integration_config.data_channel(),
+        integration_config.error_channel(),
+        integration_config.execution_channel(),
+        integration_config.dead_letter_channel(),
+    ];
+
+    provision_stream(
+        client,
+        integration_config.integration_id(),
+        &topics,
+        &iggy_config.topic_config(),
+    )
+    .await
+}
+
+/// Ensures a stream and its topics exist.
+///
+/// Provisioning is idempotent:
+///
+/// - a missing stream or topic is created,
+/// - missing partitions are added to an existing topic,
+/// - any other difference of an existing topic is reported as `Drift` and left unchanged.
+///
+/// A setting left to the server default is not checked for drift.
+///
+/// # Arguments
+///
+/// * `client` - A connected `IggyClient`, logged in as user allowed to manage streams.
+/// * `stream` - The name of the stream.
+/// * `topics` - The names of the topics of the stream.
+/// * `topic_config` - The desired topic settings.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `ProvisioningReport` or a `ProvisioningError`.
+///
+/// # Errors
+///
+/// Returns a `ProvisioningError` if a name is invalid or a server request fails.
+///
+pub async fn provision_stream(
+    client: &IggyClient,
+    stream: &str,
+    topics: &[String],
+    topic_config: &TopicConfig,
+) -> Result<ProvisioningReport, ProvisioningError> {
+    let stream_id = identifier(stream)?;
+    let mut report = ProvisioningReport::default();
+
+    let existing_stream = match client.get_stream(&stream_id).await {
+        Ok(existing_stream) => existing_stream,
+        Err(IggyError::StreamIdNotFound(_) | IggyError::StreamNameNotFound(_)) => None,
+        Err(e) => return Err(stream_error(stream, &e)),
+    };
+
+    if existing_stream.is_none() {
+        client
+            .create_stream(stream, None)
+            .await
+            .map_err(|e| stream_error(stream, &e))?;
+        report.stream_created = true;
+    }
+
+    for topic in topics {
+        let topic_id = identifier(topic)?;
+        let topic_error = |e: &IggyError| ProvisioningError::Topic {
+            stream: stream.to_string(),
+            topic: topic.to_string(),
+            message: e.to_string(),
+        };
+
+        let existing_topic = match client.get_topic(&stream_id, &topic_id).await {
+            Ok(existing_topic) => existing_topic,
+            Err(IggyError::TopicIdNotFound(_, _) | IggyError::TopicNameNotFound(_, _)) => None,
+            Err(e) => return Err(topic_error(&e)),
+        };
+
+        let Some(existing_topic) = existing_topic else {
+            client
+                .create_topic(
+                    &stream_id,
+                    topic,
+                    topic_config.partitions_count(),
+                    topic_config.compression_algorithm(),
+                    None,
+                    None,
+                    topic_config.message_expiry(),
+                    topic_config.max_topic_size(),
+                )
+                .await
+                .map_err(|e| topic_error(&e))?;
+            report.topics_created.push(topic.to_string());
+            continue;
+        };
+
+        let desired_partitions = topic_config.partitions_count();
+        if existing_topic.partitions_count < desired_partitions {
+            let missing_partitions = desired_partitions - existing_topic.partitions_count;
+            client
+                .create_partitions(&stream_id, &topic_id, missing_partitions)
+                .await
+                .map_err(|e| topic_error(&e))?;
+            report
+                .partitions_added
+                .push((topic.to_string(), missing_partitions));
+        }
+
+        report
+            .drift
+            .extend(topic_drift(topic, &existing_topic, topic_config));
+    }
+
+    Ok(report)
+}
+
+/// Ensures a user exists that may read the given stream and nothing else.
+///
+/// A missing user is created with the given password. The permissions of an
+/// existing user are reset to reading the stream; its password is left unchanged.
+///
+/// # Arguments
+///
+/// * `client` - A connected `IggyClient`, logged in as user allowed to manage users.
+/// * `stream` - The name of the stream the user may read; the stream must exist.
+/// * `username` - The name of the user.
+/// * `password` - The password of a created user.
+///
+/// # Errors
+///
+/// Returns a `ProvisioningError` if a name is invalid, the stream does not exist
+/// or a server request fails.
+///
+pub async fn provision_stream_user(
+    client: &IggyClient,
+    stream: &str,
+    username: &str,
+    password: &str,
+) -> Result<(), ProvisioningError> {
+    let stream_id = identifier(stream)?;
+    let user_error = |e: &dyn fmt::Display| ProvisioningError::User {
+        username: username.to_string(),
+        message: e.to_string(),
+    };
+
+    let stream_details = client
+        .get_stream(&stream_id)
+        .await
+        .map_err(|e| stream_error(stream, &e))?
+        .ok_or_else(|| ProvisioningError::Stream {
+            stream: stream.to_string(),
+            message: "Stream not found".to_string(),
+        })?;
+
+    let mut streams_permissions = AHashMap::new();
+    streams_permissions.insert(
+        stream_details.id,
+        StreamPermissions {
+            read_stream: true,
+            read_topics: true,
+            ..Default::default()
+        },
+    );
+
+    let permissions = Permissions {
+        streams: Some(streams_permissions),
+        ..Default::default()
+    };
+
+    match client
+        .create_user(
+            username,
+            password,
+            UserStatus::Active,
+            Some(permissions.clone()),
+        )
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(IggyError::UserAlreadyExists) => {
+            let user_id = identifier(username)?;
+            client
+                .update_permissions(&user_id, Some(permissions))
+                .await
+                .map_err(|e| user_error(&e))
+        }
+        Err(e) => Err(user_error(&e)),
+    }
+}
+
+/// Returns the drift of an existing topic from the desired settings.
+fn topic_drift(topic: &str, actual: &TopicDetails, desired: &TopicConfig) -> Vec<Drift> {
+    let mut drift = Vec::new();
+
+    if actual.partitions_count > desired.partitions_count() {
+        drift.push(Drift::PartitionsCount {
+            topic: topic.to_string(),
+            desired: desired.partitions_count(),
+            actual: actual.partitions_count,
+        });
+    }
+
+    if actual.compression_algorithm != desired.compression_algorithm() {
+        drift.push(Drift::Compression {
+            topic: topic.to_string(),
+            desired: desired.compression_algorithm(),
+            actual: actual.compression_algorithm,
+        });
+    }
+
+    if desired.message_expiry() != IggyExpiry::ServerDefault
+        && actual.message_expiry != desired.message_expiry()
+    {
+        drift.push(Drift::MessageExpiry {
+            topic: topic.to_string(),
+            desired: desired.message_expiry(),
+            actual: actual.message_expiry,
+        });
+    }
+
+    if desired.max_topic_size() != MaxTopicSize::ServerDefault
+        && actual.max_topic_size != desired.max_topic_size()
+    {
+        drift.push(Drift::MaxTopicSize {
+            topic: topic.to_string(),
+            desired: desired.max_topic_size(),
+            actual: actual.max_topic_size,
+        });
+    }
+
+    drift
+}
+
+fn identifier(name: &str) -> Result<Identifier, ProvisioningError> {
+    Identifier::from_str_value(name).map_err(|_| ProvisioningError::InvalidName(name.to_string()))
+}
+
+fn stream_error(stream: &str, e: &IggyError) -> ProvisioningError {
+    ProvisioningError::Stream {
+        stream: stream.to_string(),
+        message: e.to_string(),
+    }
+}
@@ -0,0 +1,74 @@
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+
+/// Desired settings of a provisioned topic.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TopicConfig {
+    partitions_count: u32,
+    compression_algorithm: CompressionAlgorithm,
+    message_expiry: IggyExpiry,
+    max_topic_size: MaxTopicSize,
+}
+
+impl TopicConfig {
+    /// Creates a `TopicConfig` with the given number of partitions, no compression
+    /// and the server default message expiry and maximum size.
+    #[must_use]
+    pub const fn new(partitions_count: u32) -> Self {
+        Self {
+            partitions_count,
+            compression_algorithm: CompressionAlgorithm::None,
+            message_expiry: IggyExpiry::ServerDefault,
+            max_topic_size: MaxTopicSize::ServerDefault,
+        }
+    }
+
+    /// Sets the compression of the topic.
+    #[must_use]
+    pub fn with_compression_algorithm(self, compression_algorithm: CompressionAlgorithm) -> Self {
+        Self {
+            compression_algorithm,
+            ..self
+        }
+    }
+
+    /// Sets the message expiry of the topic.
+    #[must_use]
+    pub fn with_message_expiry(self, message_expiry: IggyExpiry) -> Self {
+        Self {
+            message_expiry,
+            ..self
+        }
+    }
+
+    /// Sets the maximum size of the topic.
+    #[must_use]
+    pub fn with_max_topic_size(self, max_topic_size: MaxTopicSize) -> Self {
+        Self {
+            max_topic_size,
+            ..self
+        }
+    }
+}
+
+impl TopicConfig {
+    pub fn partitions_count(&self) -> u32 {
+        self.partitions_count
+    }
+    pub fn compression_algorithm(&self) -> CompressionAlgorithm {
+        self.compression_algorithm
+    }
+    pub fn message_expiry(&self) -> IggyExpiry {
+        self.message_expiry
+    }
+    pub fn max_topic_size(&self) -> MaxTopicSize {
+        self.max_topic_size
+    }
+}
+
+impl Default for TopicConfig {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
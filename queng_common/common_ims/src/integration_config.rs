@@ -1,6 +1,17 @@
 use crate::integration_message_config::IntegrationMessageConfig;
 use crate::ImsIntegrationType;
+use common_errors::ValidationError;
 use std::fmt::Display;
+use std::time::Duration;
+
+/// Default interval in which clients send a heartbeat.
+const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Default number of heartbeats a client may miss before it is logged out.
+const DEFAULT_MISSED_HEARTBEATS: u32 = 3;
+
+/// Default time within which a service shuts down after it was signaled to stop.
+const DEFAULT_SHUTDOWN_DEADLINE: Duration = Duration::from_secs(20);
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct IntegrationConfig {
@@ -9,6 +20,9 @@ pub struct IntegrationConfig {
     ims_integration_type: ImsIntegrationType,
     online: bool,
     integration_message_config: IntegrationMessageConfig,
+    heartbeat_interval: Duration,
+    missed_heartbeats: u32,
+    shutdown_deadline: Duration,
 }
 
 impl IntegrationConfig {
@@ -38,6 +52,9 @@ impl IntegrationConfig {
             ims_integration_type,
             online: false,
             integration_message_config,
+            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
+            missed_heartbeats: DEFAULT_MISSED_HEARTBEATS,
+            shutdown_deadline: DEFAULT_SHUTDOWN_DEADLINE,
         }
     }
 
@@ -69,6 +86,53 @@ impl IntegrationConfig {
             ims_integration_type,
             online,
             integration_message_config,
+            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
+            missed_heartbeats: DEFAULT_MISSED_HEARTBEATS,
+            shutdown_deadline: DEFAULT_SHUTDOWN_DEADLINE,
+        }
+    }
+}
+
+impl IntegrationConfig {
+    /// Sets the interval in which clients send a heartbeat, 10 seconds by default.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` if the interval is zero, since idle clients
+    /// are reaped on a timer running at this interval.
+    ///
+    pub fn with_heartbeat_interval(
+        self,
+        heartbeat_interval: Duration,
+    ) -> Result<Self, ValidationError> {
+        if heartbeat_interval.is_zero() {
+            return Err(ValidationError(format!(
+                "[IntegrationConfig]: heartbeat interval of integration {} must not be zero",
+                self.integration_id
+            )));
+        }
+
+        Ok(Self {
+            heartbeat_interval,
+            ..self
+        })
+    }
+
+    /// Sets the number of heartbeats a client may miss before it is logged out, 3 by default.
+    #[must_use]
+    pub fn with_missed_heartbeats(self, missed_heartbeats: u32) -> Self {
+        Self {
+            missed_heartbeats,
+            ..self
+        }
+    }
+
+    /// Sets the time within which a service shuts down after it was signaled to stop, 20 seconds by default.
+    #[must_use]
+    pub fn with_shutdown_deadline(self, shutdown_deadline: Duration) -> Self {
+        Self {
+            shutdown_deadline,
+            ..self
         }
     }
 }
@@ -130,6 +194,34 @@ impl IntegrationConfig {
         &self.integration_message_config
     }
 
+    /// Returns the interval in which clients send a heartbeat.
+    #[must_use]
+    pub const fn heartbeat_interval(&self) -> Duration {
+        self.heartbeat_interval
+    }
+
+    /// Returns the number of heartbeats a client may miss before it is logged out.
+    #[must_use]
+    pub const fn missed_heartbeats(&self) -> u32 {
+        self.missed_heartbeats
+    }
+
+    /// Returns the time within which a service shuts down after it was signaled to stop.
+    #[must_use]
+    pub const fn shutdown_deadline(&self) -> Duration {
+        self.shutdown_deadline
+    }
+
+    /// Returns the time after which a client that sent no message is logged out.
+    ///
+    /// # Returns
+    ///
+    /// The heartbeat interval times the number of heartbeats a client may miss.
+    #[must_use]
+    pub fn heartbeat_timeout(&self) -> Duration {
+        self.heartbeat_interval * self.missed_heartbeats
+    }
+
     /// Generates a channel name for the control channel based on the integration_id.
     ///
     /// # Returns
@@ -167,6 +259,15 @@ impl IntegrationConfig {
     pub fn execution_channel(&self) -> String {
         format!("{}-{}", self.integration_id, "execution")
     }
+
+    /// Generates a channel name for the dead letter channel based on the integration_id.
+    ///
+    /// # Returns
+    ///
+    /// A String in the format: "{integration_id}-dlq".
+    pub fn dead_letter_channel(&self) -> String {
+        format!("{}-{}", self.integration_id, "dlq")
+    }
 }
 
 impl Display for IntegrationConfig {
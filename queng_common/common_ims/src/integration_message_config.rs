@@ -87,6 +87,16 @@ impl IntegrationMessageConfig {
     pub fn execution_channel(&self) -> String {
         format!("{}-{}", self.name, "execution")
     }
+
+    /// Generates a channel name for the dead letter channel based on the client name.
+    ///
+    /// # Returns
+    ///
+    /// A String in the format: "{client_name}-dlq".
+    #[must_use]
+    pub fn dead_letter_channel(&self) -> String {
+        format!("{}-{}", self.name, "dlq")
+    }
 }
 
 impl Default for IntegrationMessageConfig {
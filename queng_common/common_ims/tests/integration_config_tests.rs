@@ -0,0 +1,28 @@
+use common_ims::{ImsIntegrationType, IntegrationConfig, IntegrationMessageConfig};
+use std::time::Duration;
+
+fn integration_config() -> IntegrationConfig {
+    IntegrationConfig::new(
+        "ims-test".to_string(),
+        1,
+        ImsIntegrationType::Data,
+        IntegrationMessageConfig::new(1, 1),
+    )
+}
+
+#[test]
+fn test_heartbeat_timeout() {
+    let config = integration_config()
+        .with_heartbeat_interval(Duration::from_secs(5))
+        .unwrap()
+        .with_missed_heartbeats(4);
+
+    assert_eq!(config.heartbeat_interval(), Duration::from_secs(5));
+    assert_eq!(config.heartbeat_timeout(), Duration::from_secs(20));
+}
+
+#[test]
+fn test_zero_heartbeat_interval_is_rejected() {
+    let result = integration_config().with_heartbeat_interval(Duration::ZERO);
+    assert!(result.is_err());
+}
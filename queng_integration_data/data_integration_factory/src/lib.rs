@@ -1,7 +1,36 @@
+use data_integration_traits::{DataIntegrationFactory, DataSender};
 use enum_dispatch::enum_dispatch;
-use sample_data_integration::SampleDataIntegration;
+use std::io::Error;
 
+// Re export
+pub use sample_data_integration::SampleDataIntegration;
+
+/// The data integrations a data service can serve.
 #[enum_dispatch]
-enum DataIntegrationFactory {
+pub enum DataIntegration {
     SampleDataIntegration,
 }
+
+// enum_dispatch cannot implement a trait declared in another crate,
+// so each call is dispatched to the integration by hand.
+impl DataIntegrationFactory for DataIntegration {
+    async fn start_date(&self, data_id: &str, sender: DataSender) -> Result<(), Error> {
+        match self {
+            Self::SampleDataIntegration(integration) => {
+                integration.start_date(data_id, sender).await
+            }
+        }
+    }
+
+    async fn stop_date(&self, data_id: &str) -> Result<(), Error> {
+        match self {
+            Self::SampleDataIntegration(integration) => integration.stop_date(data_id).await,
+        }
+    }
+
+    async fn stop_all_date(&self) -> Result<(), Error> {
+        match self {
+            Self::SampleDataIntegration(integration) => integration.stop_all_date().await,
+        }
+    }
+}
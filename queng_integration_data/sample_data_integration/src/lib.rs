@@ -1,17 +1,39 @@
-use data_integration_traits::DataIntegrationFactory;
+use data_integration_traits::{DataIntegrationFactory, DataSender};
+use std::collections::HashMap;
 use std::io::Error;
+use std::sync::Mutex;
 
-pub struct SampleDataIntegration {}
+#[derive(Default)]
+pub struct SampleDataIntegration {
+    senders: Mutex<HashMap<String, DataSender>>,
+}
+
+impl SampleDataIntegration {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
 
 impl DataIntegrationFactory for SampleDataIntegration {
-    async fn start_date(&self, _data_id: &str) -> Result<(), Error> {
-        println!("SampleDataIntegration/start_date");
+    async fn start_date(&self, data_id: &str, sender: DataSender) -> Result<(), Error> {
+        println!("SampleDataIntegration/start_date: {data_id}");
+
+        self.senders
+            .lock()
+            .map_err(|e| Error::other(e.to_string()))?
+            .insert(data_id.to_string(), sender);
 
         Ok(())
     }
 
-    async fn stop_date(&self, _data_id: &str) -> Result<(), Error> {
-        println!("SampleDataIntegration/stop_date");
+    async fn stop_date(&self, data_id: &str) -> Result<(), Error> {
+        println!("SampleDataIntegration/stop_date: {data_id}");
+
+        self.senders
+            .lock()
+            .map_err(|e| Error::other(e.to_string()))?
+            .remove(data_id);
 
         Ok(())
     }
@@ -19,6 +41,11 @@ impl DataIntegrationFactory for SampleDataIntegration {
     async fn stop_all_date(&self) -> Result<(), Error> {
         println!("SampleDataIntegration/stop_all_date");
 
+        self.senders
+            .lock()
+            .map_err(|e| Error::other(e.to_string()))?
+            .clear();
+
         Ok(())
     }
 }
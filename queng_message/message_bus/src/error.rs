@@ -0,0 +1,79 @@
+use std::error::Error;
+use std::fmt;
+
+/// Error returned by a `MessageBus` implementation.
+///
+/// Carries the stream, topic and partition the failed operation referred to,
+/// so callers can tell a missing resource apart from a transport failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageBusError {
+    /// The stream, topic or consumer name is not a valid identifier.
+    InvalidIdentifier(String),
+    /// The stream already exists.
+    StreamAlreadyExists(String),
+    /// The stream does not exist.
+    StreamNotFound(String),
+    /// The topic already exists in the stream.
+    TopicAlreadyExists { stream_id: String, topic_id: String },
+    /// The topic does not exist in the stream.
+    TopicNotFound { stream_id: String, topic_id: String },
+    /// The partition does not exist in the topic.
+    PartitionNotFound {
+        stream_id: String,
+        topic_id: String,
+        partition_id: u32,
+    },
+    /// The user already exists.
+    UserAlreadyExists(String),
+    /// The user does not exist.
+    UserNotFound(String),
+    /// The message bus backend failed, i.e. a lost connection to the iggy server.
+    Transport(String),
+}
+
+impl Error for MessageBusError {}
+
+impl fmt::Display for MessageBusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidIdentifier(id) => {
+                write!(f, "MessageBusError: Invalid identifier '{id}'")
+            }
+            Self::StreamAlreadyExists(stream_id) => {
+                write!(f, "MessageBusError: Stream {stream_id} already exists")
+            }
+            Self::StreamNotFound(stream_id) => {
+                write!(f, "MessageBusError: Stream {stream_id} not found")
+            }
+            Self::TopicAlreadyExists {
+                stream_id,
+                topic_id,
+            } => write!(
+                f,
+                "MessageBusError: Topic {topic_id} already exists in stream {stream_id}"
+            ),
+            Self::TopicNotFound {
+                stream_id,
+                topic_id,
+            } => write!(
+                f,
+                "MessageBusError: Topic {topic_id} not found in stream {stream_id}"
+            ),
+            Self::PartitionNotFound {
+                stream_id,
+                topic_id,
+                partition_id,
+            } => write!(
+                f,
+                "MessageBusError: Partition {partition_id} not found in topic {topic_id} of stream {stream_id}"
+            ),
+            Self::UserAlreadyExists(username) => {
+                write!(f, "MessageBusError: User {username} already exists")
+            }
+            Self::UserNotFound(username) => {
+                write!(f, "MessageBusError: User {username} not found")
+            }
+            Self::Transport(message) => write!(f, "MessageBusError: {message}"),
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::error::MessageBusError;
+use crate::message::BusMessage;
+use crate::traits::MessageBus;
+use ahash::AHashMap;
+use bytes::Bytes;
+use iggy::client::{
+    Client, ConsumerOffsetClient, MessageClient, StreamClient, TopicClient, UserClient,
+};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::permissions::{Permissions, StreamPermissions};
+use iggy::models::user_status::UserStatus;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use message_shared::utils as shared_utils;
+use message_shared::Args;
+
+/// Message bus backed by an iggy server.
+///
+/// Streams, topics and consumers are addressed by name.
+pub struct IggyMessageBus {
+    client: IggyClient,
+}
+
+impl IggyMessageBus {
+    /// Creates a new `IggyMessageBus` from a connected and logged in `IggyClient`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The `IggyClient` to use for all operations.
+    ///
+    #[must_use]
+    pub const fn new(client: IggyClient) -> Self {
+        Self { client }
+    }
+
+    /// Builds an `IggyClient` from the given `Args`, connects it and logs in
+    /// with the username and password of the `Args`.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The `Args` holding the server address and credentials.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `IggyMessageBus` instance or a `MessageBusError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MessageBusError` if the client cannot be built, connected or logged in.
+    ///
+    pub async fn connect(args: &Args) -> Result<Self, MessageBusError> {
+        let client = shared_utils::build_client_from_args(args)
+            .await
+            .map_err(|e| MessageBusError::Transport(e.to_string()))?;
+
+        client.connect().await.map_err(transport)?;
+
+        client
+            .login_user(&args.username, &args.password)
+            .await
+            .map_err(transport)?;
+
+        Ok(Self::new(client))
+    }
+
+    /// Returns a reference to the underlying `IggyClient`.
+    #[must_use]
+    pub const fn client(&self) -> &IggyClient {
+        &self.client
+    }
+}
+
+impl MessageBus for IggyMessageBus {
+    async fn create_stream(&self, stream_id: &str) -> Result<(), MessageBusError> {
+        match self.client.create_stream(stream_id, None).await {
+            Ok(_) => Ok(()),
+            Err(IggyError::StreamNameAlreadyExists(_)) => {
+                Err(MessageBusError::StreamAlreadyExists(stream_id.to_string()))
+            }
+            Err(e) => Err(transport(e)),
+        }
+    }
+
+    async fn delete_stream(&self, stream_id: &str) -> Result<(), MessageBusError> {
+        let stream = identifier(stream_id)?;
+
+        self.client
+            .delete_stream(&stream)
+            .await
+            .map_err(|e| stream_error(e, stream_id))
+    }
+
+    async fn create_topic(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partitions_count: u32,
+    ) -> Result<(), MessageBusError> {
+        let stream = identifier(stream_id)?;
+
+        match self
+            .client
+            .create_topic(
+                &stream,
+                topic_id,
+                partitions_count,
+                CompressionAlgorithm::default(),
+                None,
+                None,
+                IggyExpiry::ServerDefault,
+                MaxTopicSize::ServerDefault,
+            )
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(IggyError::TopicNameAlreadyExists(_, _)) => {
+                Err(MessageBusError::TopicAlreadyExists {
+                    stream_id: stream_id.to_string(),
+                    topic_id: topic_id.to_string(),
+                })
+            }
+            Err(e) => Err(stream_error(e, stream_id)),
+        }
+    }
+
+    async fn delete_topic(&self, stream_id: &str, topic_id: &str) -> Result<(), MessageBusError> {
+        let stream = identifier(stream_id)?;
+        let topic = identifier(topic_id)?;
+
+        self.client
+            .delete_topic(&stream, &topic)
+            .await
+            .map_err(|e| topic_error(e, stream_id, topic_id))
+    }
+
+    async fn create_user(
+        &self,
+        stream_id: &str,
+        username: &str,
+        password: &str,
+    ) -> Result<(), MessageBusError> {
+        let stream = identifier(stream_id)?;
+
+        let stream_details = self
+            .client
+            .get_stream(&stream)
+            .await
+            .map_err(|e| stream_error(e, stream_id))?
+            .ok_or_else(|| MessageBusError::StreamNotFound(stream_id.to_string()))?;
+
+        let mut streams_permissions = AHashMap::new();
+        streams_permissions.insert(
+            stream_details.id,
+            StreamPermissions {
+                read_stream: true,
+                read_topics: true,
+                ..Default::default()
+            },
+        );
+
+        let permissions = Permissions {
+            streams: Some(streams_permissions),
+            ..Default::default()
+        };
+
+        match self
+            .client
+            .create_user(username, password, UserStatus::Active, Some(permissions))
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(IggyError::UserAlreadyExists) => {
+                Err(MessageBusError::UserAlreadyExists(username.to_string()))
+            }
+            Err(e) => Err(transport(e)),
+        }
+    }
+
+    async fn delete_user(&self, username: &str) -> Result<(), MessageBusError> {
+        let user = identifier(username)?;
+
+        match self.client.delete_user(&user).await {
+            Ok(()) => Ok(()),
+            Err(IggyError::ResourceNotFound(_)) => {
+                Err(MessageBusError::UserNotFound(username.to_string()))
+            }
+            Err(e) => Err(transport(e)),
+        }
+    }
+
+    async fn send(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partition_id: Option<u32>,
+        payloads: Vec<Vec<u8>>,
+    ) -> Result<(), MessageBusError> {
+        let stream = identifier(stream_id)?;
+        let topic = identifier(topic_id)?;
+
+        let partitioning =
+            partition_id.map_or_else(Partitioning::balanced, Partitioning::partition_id);
+
+        // Convert the SBE bytes into new messages with auto-generated ID, payload and no headers.
+        let mut messages: Vec<Message> = payloads
+            .into_iter()
+            .map(|payload| Message::new(None, Bytes::from(payload), None))
+            .collect();
+
+        self.client
+            .send_messages(&stream, &topic, &partitioning, &mut messages)
+            .await
+            .map_err(|e| topic_error(e, stream_id, topic_id))
+    }
+
+    async fn poll(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partition_id: u32,
+        consumer: &str,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<Vec<BusMessage>, MessageBusError> {
+        let stream = identifier(stream_id)?;
+        let topic = identifier(topic_id)?;
+        let consumer = Consumer::new(identifier(consumer)?);
+
+        let polled_messages = self
+            .client
+            .poll_messages(
+                &stream,
+                &topic,
+                Some(partition_id),
+                &consumer,
+                &PollingStrategy::next(),
+                count,
+                auto_commit,
+            )
+            .await
+            .map_err(|e| topic_error(e, stream_id, topic_id))?;
+
+        Ok(polled_messages
+            .messages
+            .into_iter()
+            .map(|message| {
+                BusMessage::new(
+                    polled_messages.partition_id,
+                    message.offset,
+                    message.timestamp,
+                    message.payload.to_vec(),
+                )
+            })
+            .collect())
+    }
+
+    async fn commit(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partition_id: u32,
+        consumer: &str,
+        offset: u64,
+    ) -> Result<(), MessageBusError> {
+        let stream = identifier(stream_id)?;
+        let topic = identifier(topic_id)?;
+        let consumer = Consumer::new(identifier(consumer)?);
+
+        self.client
+            .store_consumer_offset(&consumer, &stream, &topic, Some(partition_id), offset)
+            .await
+            .map_err(|e| topic_error(e, stream_id, topic_id))
+    }
+
+    async fn committed_offset(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partition_id: u32,
+        consumer: &str,
+    ) -> Result<Option<u64>, MessageBusError> {
+        let stream = identifier(stream_id)?;
+        let topic = identifier(topic_id)?;
+        let consumer = Consumer::new(identifier(consumer)?);
+
+        let offset_info = self
+            .client
+            .get_consumer_offset(&consumer, &stream, &topic, Some(partition_id))
+            .await
+            .map_err(|e| topic_error(e, stream_id, topic_id))?;
+
+        Ok(offset_info.map(|info| info.stored_offset))
+    }
+}
+
+fn identifier(id: &str) -> Result<Identifier, MessageBusError> {
+    Identifier::from_str_value(id).map_err(|_| MessageBusError::InvalidIdentifier(id.to_string()))
+}
+
+fn transport(e: IggyError) -> MessageBusError {
+    MessageBusError::Transport(e.to_string())
+}
+
+fn stream_error(e: IggyError, stream_id: &str) -> MessageBusError {
+    match e {
+        IggyError::StreamIdNotFound(_) | IggyError::StreamNameNotFound(_) => {
+            MessageBusError::StreamNotFound(stream_id.to_string())
+        }
+        e => transport(e),
+    }
+}
+
+fn topic_error(e: IggyError, stream_id: &str, topic_id: &str) -> MessageBusError {
+    match e {
+        IggyError::TopicIdNotFound(_, _) | IggyError::TopicNameNotFound(_, _) => {
+            MessageBusError::TopicNotFound {
+                stream_id: stream_id.to_string(),
+                topic_id: topic_id.to_string(),
+            }
+        }
+        IggyError::PartitionNotFound(partition_id, _, _) => MessageBusError::PartitionNotFound {
+            stream_id: stream_id.to_string(),
+            topic_id: topic_id.to_string(),
+            partition_id,
+        },
+        e => stream_error(e, stream_id),
+    }
+}
@@ -0,0 +1,12 @@
+mod error;
+mod iggy_bus;
+mod memory_bus;
+mod message;
+mod traits;
+
+// Re export
+pub use error::*;
+pub use iggy_bus::IggyMessageBus;
+pub use memory_bus::InMemoryMessageBus;
+pub use message::BusMessage;
+pub use traits::MessageBus;
@@ -0,0 +1,298 @@
+use crate::error::MessageBusError;
+use crate::message::BusMessage;
+use crate::traits::MessageBus;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::RwLock;
+
+/// In-process message bus with streams, topics, partitions, consumer offsets and users.
+///
+/// Clones share the same streams, so a producer and a consumer built from clones
+/// of one bus see each other's messages. Intended for tests that must run
+/// without an iggy server.
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryMessageBus {
+    streams: Arc<RwLock<HashMap<String, MemoryStream>>>,
+    users: Arc<RwLock<HashMap<String, MemoryUser>>>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryStream {
+    topics: HashMap<String, MemoryTopic>,
+}
+
+#[derive(Debug)]
+struct MemoryUser {
+    password: String,
+    stream_id: String,
+}
+
+#[derive(Debug, Default)]
+struct MemoryTopic {
+    partitions: Vec<Vec<BusMessage>>,
+    offsets: HashMap<(String, u32), u64>,
+    next_partition: usize,
+}
+
+impl InMemoryMessageBus {
+    /// Creates a new, empty `InMemoryMessageBus`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if a user with the given password may read the given stream.
+    ///
+    /// The in-memory bus does not enforce permissions; this lets tests check
+    /// the credentials handed out to clients.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream_id` - The stream to read.
+    /// * `username` - The name of the user.
+    /// * `password` - The password of the user.
+    ///
+    pub async fn can_read(&self, stream_id: &str, username: &str, password: &str) -> bool {
+        self.users
+            .read()
+            .await
+            .get(username)
+            .is_some_and(|user| user.password == password && user.stream_id == stream_id)
+    }
+}
+
+impl MessageBus for InMemoryMessageBus {
+    async fn create_stream(&self, stream_id: &str) -> Result<(), MessageBusError> {
+        let mut streams = self.streams.write().await;
+        if streams.contains_key(stream_id) {
+            return Err(MessageBusError::StreamAlreadyExists(stream_id.to_string()));
+        }
+
+        streams.insert(stream_id.to_string(), MemoryStream::default());
+        Ok(())
+    }
+
+    async fn delete_stream(&self, stream_id: &str) -> Result<(), MessageBusError> {
+        self.streams
+            .write()
+            .await
+            .remove(stream_id)
+            .map(|_| ())
+            .ok_or_else(|| MessageBusError::StreamNotFound(stream_id.to_string()))
+    }
+
+    async fn create_topic(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partitions_count: u32,
+    ) -> Result<(), MessageBusError> {
+        let mut streams = self.streams.write().await;
+        let stream = streams
+            .get_mut(stream_id)
+            .ok_or_else(|| MessageBusError::StreamNotFound(stream_id.to_string()))?;
+
+        if stream.topics.contains_key(topic_id) {
+            return Err(MessageBusError::TopicAlreadyExists {
+                stream_id: stream_id.to_string(),
+                topic_id: topic_id.to_string(),
+            });
+        }
+
+        let topic = MemoryTopic {
+            partitions: (0..partitions_count).map(|_| Vec::new()).collect(),
+            ..Default::default()
+        };
+        stream.topics.insert(topic_id.to_string(), topic);
+        Ok(())
+    }
+
+    async fn delete_topic(&self, stream_id: &str, topic_id: &str) -> Result<(), MessageBusError> {
+        let mut streams = self.streams.write().await;
+        let stream = streams
+            .get_mut(stream_id)
+            .ok_or_else(|| MessageBusError::StreamNotFound(stream_id.to_string()))?;
+
+        stream
+            .topics
+            .remove(topic_id)
+            .map(|_| ())
+            .ok_or_else(|| topic_not_found(stream_id, topic_id))
+    }
+
+    async fn create_user(
+        &self,
+        stream_id: &str,
+        username: &str,
+        password: &str,
+    ) -> Result<(), MessageBusError> {
+        if !self.streams.read().await.contains_key(stream_id) {
+            return Err(MessageBusError::StreamNotFound(stream_id.to_string()));
+        }
+
+        let mut users = self.users.write().await;
+        if users.contains_key(username) {
+            return Err(MessageBusError::UserAlreadyExists(username.to_string()));
+        }
+
+        let user = MemoryUser {
+            password: password.to_string(),
+            stream_id: stream_id.to_string(),
+        };
+        users.insert(username.to_string(), user);
+        Ok(())
+    }
+
+    async fn delete_user(&self, username: &str) -> Result<(), MessageBusError> {
+        self.users
+            .write()
+            .await
+            .remove(username)
+            .map(|_| ())
+            .ok_or_else(|| MessageBusError::UserNotFound(username.to_string()))
+    }
+
+    async fn send(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partition_id: Option<u32>,
+        payloads: Vec<Vec<u8>>,
+    ) -> Result<(), MessageBusError> {
+        let mut streams = self.streams.write().await;
+        let topic = get_topic_mut(&mut streams, stream_id, topic_id)?;
+
+        let partition_id = match partition_id {
+            Some(partition_id) => partition_id,
+            None => {
+                // Balanced partitioning: round-robin over all partitions, starting at 1.
+                let partitions_count = topic.partitions.len().max(1);
+                let index = topic.next_partition % partitions_count;
+                topic.next_partition = index + 1;
+                u32::try_from(index + 1).unwrap_or(u32::MAX)
+            }
+        };
+
+        let partition = get_partition_mut(topic, stream_id, topic_id, partition_id)?;
+        let timestamp = now_micros();
+        for payload in payloads {
+            let offset = partition.len() as u64;
+            partition.push(BusMessage::new(partition_id, offset, timestamp, payload));
+        }
+
+        Ok(())
+    }
+
+    async fn poll(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partition_id: u32,
+        consumer: &str,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<Vec<BusMessage>, MessageBusError> {
+        let mut streams = self.streams.write().await;
+        let topic = get_topic_mut(&mut streams, stream_id, topic_id)?;
+
+        let key = (consumer.to_string(), partition_id);
+        let start = topic.offsets.get(&key).map_or(0, |offset| offset + 1);
+        let partition = get_partition_mut(topic, stream_id, topic_id, partition_id)?;
+
+        let messages: Vec<BusMessage> = partition
+            .iter()
+            .skip(usize::try_from(start).unwrap_or(usize::MAX))
+            .take(count as usize)
+            .cloned()
+            .collect();
+
+        if auto_commit {
+            if let Some(last) = messages.last() {
+                topic.offsets.insert(key, last.offset());
+            }
+        }
+
+        Ok(messages)
+    }
+
+    async fn commit(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partition_id: u32,
+        consumer: &str,
+        offset: u64,
+    ) -> Result<(), MessageBusError> {
+        let mut streams = self.streams.write().await;
+        let topic = get_topic_mut(&mut streams, stream_id, topic_id)?;
+        get_partition_mut(topic, stream_id, topic_id, partition_id)?;
+
+        topic
+            .offsets
+            .insert((consumer.to_string(), partition_id), offset);
+        Ok(())
+    }
+
+    async fn committed_offset(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partition_id: u32,
+        consumer: &str,
+    ) -> Result<Option<u64>, MessageBusError> {
+        let mut streams = self.streams.write().await;
+        let topic = get_topic_mut(&mut streams, stream_id, topic_id)?;
+        get_partition_mut(topic, stream_id, topic_id, partition_id)?;
+
+        Ok(topic
+            .offsets
+            .get(&(consumer.to_string(), partition_id))
+            .copied())
+    }
+}
+
+fn get_topic_mut<'a>(
+    streams: &'a mut HashMap<String, MemoryStream>,
+    stream_id: &str,
+    topic_id: &str,
+) -> Result<&'a mut MemoryTopic, MessageBusError> {
+    streams
+        .get_mut(stream_id)
+        .ok_or_else(|| MessageBusError::StreamNotFound(stream_id.to_string()))?
+        .topics
+        .get_mut(topic_id)
+        .ok_or_else(|| topic_not_found(stream_id, topic_id))
+}
+
+fn get_partition_mut<'a>(
+    topic: &'a mut MemoryTopic,
+    stream_id: &str,
+    topic_id: &str,
+    partition_id: u32,
+) -> Result<&'a mut Vec<BusMessage>, MessageBusError> {
+    // Partitions are numbered from 1, like in iggy.
+    (partition_id as usize)
+        .checked_sub(1)
+        .and_then(|index| topic.partitions.get_mut(index))
+        .ok_or_else(|| MessageBusError::PartitionNotFound {
+            stream_id: stream_id.to_string(),
+            topic_id: topic_id.to_string(),
+            partition_id,
+        })
+}
+
+fn topic_not_found(stream_id: &str, topic_id: &str) -> MessageBusError {
+    MessageBusError::TopicNotFound {
+        stream_id: stream_id.to_string(),
+        topic_id: topic_id.to_string(),
+    }
+}
+
+fn now_micros() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| {
+            u64::try_from(duration.as_micros()).unwrap_or(u64::MAX)
+        })
+}
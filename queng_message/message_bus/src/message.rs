@@ -0,0 +1,61 @@
+/// A message polled from a partition of a topic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusMessage {
+    partition_id: u32,
+    offset: u64,
+    timestamp: u64,
+    payload: Vec<u8>,
+}
+
+impl BusMessage {
+    /// Creates a new `BusMessage`.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition_id` - The partition the message was stored in.
+    /// * `offset` - The offset of the message within the partition.
+    /// * `timestamp` - The time the message was stored, in microseconds since the Unix epoch.
+    /// * `payload` - The binary payload, i.e. an SBE encoded message.
+    ///
+    #[must_use]
+    pub const fn new(partition_id: u32, offset: u64, timestamp: u64, payload: Vec<u8>) -> Self {
+        Self {
+            partition_id,
+            offset,
+            timestamp,
+            payload,
+        }
+    }
+}
+
+impl BusMessage {
+    /// Returns the partition the message was stored in.
+    #[must_use]
+    pub const fn partition_id(&self) -> u32 {
+        self.partition_id
+    }
+
+    /// Returns the offset of the message within the partition.
+    #[must_use]
+    pub const fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the time the message was stored, in microseconds since the Unix epoch.
+    #[must_use]
+    pub const fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Returns a reference to the binary payload.
+    #[must_use]
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Consumes the message and returns the binary payload.
+    #[must_use]
+    pub fn into_payload(self) -> Vec<u8> {
+        self.payload
+    }
+}
@@ -0,0 +1,127 @@
+use crate::error::MessageBusError;
+use crate::message::BusMessage;
+
+// async fn in traits.
+// https://blog.rust-lang.org/2023/12/21/async-fn-rpit-in-traits.html
+#[allow(dead_code)]
+#[trait_variant::make(MessageBus: Send)]
+pub trait LocalMessageBus {
+    /// Creates a new stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StreamAlreadyExists` if the stream exists or an error if the stream cannot be created.
+    async fn create_stream(&self, stream_id: &str) -> Result<(), MessageBusError>;
+
+    /// Deletes a stream together with all its topics.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StreamNotFound` if the stream does not exist or an error if the stream cannot be deleted.
+    async fn delete_stream(&self, stream_id: &str) -> Result<(), MessageBusError>;
+
+    /// Creates a new topic with the given number of partitions in a stream.
+    ///
+    /// Partitions are numbered from 1 to `partitions_count`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StreamNotFound` if the stream does not exist, `TopicAlreadyExists` if the topic exists,
+    /// or an error if the topic cannot be created.
+    async fn create_topic(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partitions_count: u32,
+    ) -> Result<(), MessageBusError>;
+
+    /// Deletes a topic from a stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StreamNotFound` or `TopicNotFound` if the topic does not exist,
+    /// or an error if the topic cannot be deleted.
+    async fn delete_topic(&self, stream_id: &str, topic_id: &str) -> Result<(), MessageBusError>;
+
+    /// Creates a user that may read the given stream and its topics only.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StreamNotFound` if the stream does not exist, `UserAlreadyExists` if the user exists,
+    /// or an error if the user cannot be created.
+    async fn create_user(
+        &self,
+        stream_id: &str,
+        username: &str,
+        password: &str,
+    ) -> Result<(), MessageBusError>;
+
+    /// Deletes a user.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UserNotFound` if the user does not exist or an error if the user cannot be deleted.
+    async fn delete_user(&self, username: &str) -> Result<(), MessageBusError>;
+
+    /// Sends a batch of byte messages to a topic.
+    ///
+    /// The messages are stored in the given partition or, if `partition_id` is `None`,
+    /// balanced across all partitions of the topic.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream, topic or partition does not exist or the messages cannot be sent.
+    async fn send(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partition_id: Option<u32>,
+        payloads: Vec<Vec<u8>>,
+    ) -> Result<(), MessageBusError>;
+
+    /// Polls up to `count` messages from a partition, starting after the offset
+    /// last committed by the consumer, or from the first message if nothing was committed yet.
+    ///
+    /// If `auto_commit` is set, the offset of the last polled message is committed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream, topic or partition does not exist or the messages cannot be polled.
+    async fn poll(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partition_id: u32,
+        consumer: &str,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<Vec<BusMessage>, MessageBusError>;
+
+    /// Commits the offset of the last message processed by the consumer in a partition.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream, topic or partition does not exist or the offset cannot be stored.
+    async fn commit(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partition_id: u32,
+        consumer: &str,
+        offset: u64,
+    ) -> Result<(), MessageBusError>;
+
+    /// Returns the offset last committed by the consumer in a partition,
+    /// or `None` if the consumer has not committed yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream, topic or partition does not exist or the offset cannot be read.
+    async fn committed_offset(
+        &self,
+        stream_id: &str,
+        topic_id: &str,
+        partition_id: u32,
+        consumer: &str,
+    ) -> Result<Option<u64>, MessageBusError>;
+}
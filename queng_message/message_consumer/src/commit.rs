@@ -0,0 +1,152 @@
+use crate::{ConsumeError, ConsumedMessage};
+use iggy::client::ConsumerOffsetClient;
+use iggy::clients::client::IggyClient;
+use iggy::clients::consumer::{AutoCommit, AutoCommitWhen};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::utils::duration::IggyDuration;
+use message_shared::Args;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Policy for storing the offset of consumed messages on the server.
+///
+/// The default is `OnPoll`. `Manual` is opt-in and the only policy that guarantees
+/// at-least-once processing: the offset is stored after the handler has acknowledged
+/// a message through an `OffsetCommitter`, so a crash while handling a message
+/// re-delivers it after a restart. All other policies store the offset before the
+/// message has been handled.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum CommitPolicy {
+    /// The offset is only stored when acknowledged through an `OffsetCommitter`.
+    Manual,
+    /// The offset is stored by the server when messages are polled.
+    #[default]
+    OnPoll,
+    /// The offset is stored when each message is yielded by the stream.
+    EachMessage,
+    /// The offset is stored when every nth message is yielded by the stream.
+    EveryNthMessage(u32),
+    /// The offset of the last yielded message is stored at the given interval.
+    Interval(IggyDuration),
+}
+
+impl CommitPolicy {
+    /// Creates the `CommitPolicy` configured in the `Args`.
+    ///
+    /// Returns `OnPoll` if `args.auto_commit` is set and `Manual` otherwise.
+    /// As `Args::default` leaves `auto_commit` unset, this opts into `Manual`
+    /// unless auto commit was enabled explicitly.
+    #[must_use]
+    pub const fn from_args(args: &Args) -> Self {
+        if args.auto_commit {
+            Self::OnPoll
+        } else {
+            Self::Manual
+        }
+    }
+
+    /// Returns the iggy `AutoCommit` mode of the policy.
+    pub(crate) const fn auto_commit(self) -> AutoCommit {
+        match self {
+            Self::Manual => AutoCommit::Disabled,
+            Self::OnPoll => AutoCommit::When(AutoCommitWhen::PollingMessages),
+            Self::EachMessage => AutoCommit::When(AutoCommitWhen::ConsumingEachMessage),
+            Self::EveryNthMessage(n) => {
+                AutoCommit::When(AutoCommitWhen::ConsumingEveryNthMessage(n))
+            }
+            Self::Interval(interval) => AutoCommit::Interval(interval),
+        }
+    }
+}
+
+/// Stores the offsets of handled messages on the server.
+///
+/// Obtained from `MessageConsumer::committer` and independent of the consumer,
+/// so messages can be acknowledged while the SBE message stream is borrowed.
+/// Storing an offset acknowledges all messages up to and including that offset
+/// in the same partition.
+#[derive(Clone)]
+pub struct OffsetCommitter {
+    client: Arc<IggyClient>,
+    consumer: Consumer,
+    stream_id: Identifier,
+    topic_id: Identifier,
+}
+
+impl OffsetCommitter {
+    pub(crate) const fn new(
+        client: Arc<IggyClient>,
+        consumer: Consumer,
+        stream_id: Identifier,
+        topic_id: Identifier,
+    ) -> Self {
+        Self {
+            client,
+            consumer,
+            stream_id,
+            topic_id,
+        }
+    }
+
+    /// Acknowledges a handled message.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConsumeError::Commit` if the offset cannot be stored.
+    ///
+    pub async fn commit(&self, message: &ConsumedMessage) -> Result<(), ConsumeError> {
+        self.commit_offset(message.partition_id(), message.offset())
+            .await
+    }
+
+    /// Acknowledges a batch of handled messages.
+    ///
+    /// Stores the highest offset of each partition in the batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConsumeError::Commit` if any of the offsets cannot be stored.
+    ///
+    pub async fn commit_batch(&self, messages: &[ConsumedMessage]) -> Result<(), ConsumeError> {
+        let mut offsets: HashMap<u32, u64> = HashMap::new();
+        for message in messages {
+            let offset = offsets.entry(message.partition_id()).or_default();
+            *offset = (*offset).max(message.offset());
+        }
+
+        for (partition_id, offset) in offsets {
+            self.commit_offset(partition_id, offset).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Stores the offset of a partition, i.e. to skip a message that failed to decode.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition_id` - The partition of the handled message.
+    /// * `offset` - The offset of the handled message.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConsumeError::Commit` if the offset cannot be stored.
+    ///
+    pub async fn commit_offset(&self, partition_id: u32, offset: u64) -> Result<(), ConsumeError> {
+        self.client
+            .store_consumer_offset(
+                &self.consumer,
+                &self.stream_id,
+                &self.topic_id,
+                Some(partition_id),
+                offset,
+            )
+            .await
+            .map_err(|e| ConsumeError::Commit {
+                partition_id,
+                offset,
+                message: e.to_string(),
+            })
+    }
+}
@@ -0,0 +1,383 @@
+use crate::{ConsumeError, ConsumedMessage};
+use bytes::Bytes;
+use common_iggy::TopicConfig;
+use iggy::client::{Client, ConsumerOffsetClient, MessageClient, UserClient};
+use iggy::clients::client::IggyClient;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use message_shared::utils as shared_utils;
+use message_shared::{Args, MessageClientError};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::future::Future;
+use std::str::FromStr;
+
+// Header keys of a dead letter.
+const SOURCE_STREAM: &str = "source-stream";
+const SOURCE_TOPIC: &str = "source-topic";
+const SOURCE_PARTITION: &str = "source-partition";
+const SOURCE_OFFSET: &str = "source-offset";
+const ERROR: &str = "error";
+const ATTEMPTS: &str = "attempts";
+
+/// Maximum length of a header value.
+const MAX_HEADER_VALUE_LEN: usize = 255;
+
+/// Outcome of handling a message through a `DeadLetterQueue`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    /// The handler processed the message.
+    Handled,
+    /// The handler failed on every attempt and the message was moved to the dead letter topic.
+    DeadLettered,
+}
+
+/// Dead letter queue for messages that cannot be decoded or handled.
+///
+/// Poison messages are republished unchanged to the dead letter topic in the stream
+/// of their source, i.e. `IntegrationConfig::dead_letter_channel` of the integration
+/// stream, with headers recording the source stream, topic, partition and offset,
+/// the error and the number of attempts.
+/// Dead letters can be sent back to their source with `redrive` once the cause is fixed.
+pub struct DeadLetterQueue {
+    client: IggyClient,
+    source_stream: String,
+    source_topic: String,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    max_attempts: u32,
+}
+
+impl DeadLetterQueue {
+    /// Creates a new `DeadLetterQueue` and ensures its topic exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The `Args` of the consumer; `stream_id` and `topic_id` name the source of the messages.
+    ///   The username and password must be allowed to create and write the dead letter topic.
+    /// * `dead_letter_channel` - The name of the dead letter topic in the source stream.
+    /// * `max_attempts` - The number of times the handler is called before a message is dead-lettered.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `DeadLetterQueue` instance or a `MessageClientError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MessageClientError` if the client cannot connect or log in,
+    /// or if the dead letter topic cannot be provisioned.
+    ///
+    pub async fn build(
+        args: &Args,
+        dead_letter_channel: &str,
+        max_attempts: u32,
+    ) -> Result<Self, MessageClientError> {
+        let stream_id = Identifier::from_str_value(&args.stream_id)
+            .map_err(|e| MessageClientError::config(args, e))?;
+        let topic_id = Identifier::from_str_value(dead_letter_channel)
+            .map_err(|e| MessageClientError::config(args, e))?;
+
+        let client = shared_utils::build_client_from_args(args).await?;
+
+        client
+            .connect()
+            .await
+            .map_err(|e| MessageClientError::connect(args, e))?;
+
+        client
+            .login_user(&args.username, &args.password)
+            .await
+            .map_err(|e| MessageClientError::auth(args, &args.username, e))?;
+
+        let topics = [dead_letter_channel.to_string()];
+        common_iggy::provision_stream(&client, &args.stream_id, &topics, &TopicConfig::default())
+            .await
+            .map_err(|e| MessageClientError::provisioning(args, e))?;
+
+        Ok(Self {
+            client,
+            source_stream: args.stream_id.clone(),
+            source_topic: args.topic_id.clone(),
+            stream_id,
+            topic_id,
+            max_attempts: max_attempts.max(1),
+        })
+    }
+
+    /// Calls the handler with the message, retrying up to the maximum number of attempts.
+    ///
+    /// If the last attempt fails, the raw payload of the message is moved to the dead letter
+    /// topic together with the error of that attempt, so the consumer can continue with the
+    /// next message.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The consumed message.
+    /// * `handler` - The message handler.
+    ///
+    /// # Returns
+    ///
+    /// `Delivery::Handled` if an attempt succeeded, or `Delivery::DeadLettered`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConsumeError::DeadLetter` if the message cannot be dead-lettered.
+    ///
+    pub async fn handle<F, Fut, E>(
+        &self,
+        message: &ConsumedMessage,
+        mut handler: F,
+    ) -> Result<Delivery, ConsumeError>
+    where
+        F: FnMut(&ConsumedMessage) -> Fut,
+        Fut: Future<Output = Result<(), E>>,
+        E: Display,
+    {
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let error = match handler(message).await {
+                Ok(()) => return Ok(Delivery::Handled),
+                Err(e) => e.to_string(),
+            };
+
+            if attempts >= self.max_attempts {
+                self.publish(
+                    message.partition_id(),
+                    message.offset(),
+                    message.payload().clone(),
+                    &error,
+                    attempts,
+                )
+                .await?;
+
+                return Ok(Delivery::DeadLettered);
+            }
+        }
+    }
+
+    /// Moves a message that failed to decode to the dead letter topic.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error yielded by the SBE message stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns the given error if it is not a `ConsumeError::Decode`,
+    /// or `ConsumeError::DeadLetter` if the message cannot be dead-lettered.
+    ///
+    pub async fn dead_letter_decode_error(&self, error: ConsumeError) -> Result<(), ConsumeError> {
+        let ConsumeError::Decode {
+            partition_id,
+            offset,
+            payload,
+            error,
+            ..
+        } = error
+        else {
+            return Err(error);
+        };
+
+        self.publish(
+            partition_id,
+            offset,
+            Bytes::from(payload),
+            &error.to_string(),
+            1,
+        )
+        .await
+    }
+
+    /// Publishes a payload to the dead letter topic.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition_id` - The source partition of the message.
+    /// * `offset` - The source offset of the message.
+    /// * `payload` - The payload of the message.
+    /// * `error` - The error of the last attempt.
+    /// * `attempts` - The number of attempts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConsumeError::DeadLetter` if the message cannot be published.
+    ///
+    pub async fn publish(
+        &self,
+        partition_id: u32,
+        offset: u64,
+        payload: Bytes,
+        error: &str,
+        attempts: u32,
+    ) -> Result<(), ConsumeError> {
+        let headers = self
+            .headers(partition_id, offset, error, attempts)
+            .map_err(dead_letter_error)?;
+        let mut messages = vec![Message::new(None, payload, Some(headers))];
+
+        self.client
+            .send_messages(
+                &self.stream_id,
+                &self.topic_id,
+                &Partitioning::balanced(),
+                &mut messages,
+            )
+            .await
+            .map_err(dead_letter_error)
+    }
+
+    /// Sends dead letters back to their source stream, topic and partition.
+    ///
+    /// Dead letters are read in order by the consumer `{dead_letter_channel}-redrive`,
+    /// which stores its offset after each re-driven message, so no dead letter is re-driven twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The maximum number of dead letters to re-drive.
+    ///
+    /// # Returns
+    ///
+    /// The number of re-driven messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConsumeError::DeadLetter` if the dead letters cannot be read or sent.
+    ///
+    pub async fn redrive(&self, count: u32) -> Result<u32, ConsumeError> {
+        let consumer = Consumer::new(
+            Identifier::from_str_value(&format!("{}-redrive", self.topic_id))
+                .map_err(dead_letter_error)?,
+        );
+
+        let polled_messages = self
+            .client
+            .poll_messages(
+                &self.stream_id,
+                &self.topic_id,
+                Some(1),
+                &consumer,
+                &PollingStrategy::next(),
+                count,
+                false,
+            )
+            .await
+            .map_err(dead_letter_error)?;
+
+        for message in &polled_messages.messages {
+            let headers = message.headers.as_ref().ok_or_else(|| {
+                ConsumeError::DeadLetter(format!(
+                    "Dead letter at offset {} has no headers",
+                    message.offset
+                ))
+            })?;
+
+            let stream_id = header_identifier(headers, SOURCE_STREAM)?;
+            let topic_id = header_identifier(headers, SOURCE_TOPIC)?;
+            let partition_id = header(headers, SOURCE_PARTITION)?
+                .as_uint32()
+                .map_err(dead_letter_error)?;
+
+            let mut messages = vec![Message::new(None, message.payload.clone(), None)];
+            self.client
+                .send_messages(
+                    &stream_id,
+                    &topic_id,
+                    &Partitioning::partition_id(partition_id),
+                    &mut messages,
+                )
+                .await
+                .map_err(dead_letter_error)?;
+
+            self.client
+                .store_consumer_offset(
+                    &consumer,
+                    &self.stream_id,
+                    &self.topic_id,
+                    Some(1),
+                    message.offset,
+                )
+                .await
+                .map_err(dead_letter_error)?;
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(polled_messages.messages.len() as u32)
+    }
+
+    /// Returns the headers of a dead letter.
+    fn headers(
+        &self,
+        partition_id: u32,
+        offset: u64,
+        error: &str,
+        attempts: u32,
+    ) -> Result<HashMap<HeaderKey, HeaderValue>, IggyError> {
+        let error = truncate(if error.is_empty() { "unknown" } else { error });
+
+        Ok(HashMap::from([
+            (
+                HeaderKey::new(SOURCE_STREAM)?,
+                HeaderValue::from_str(&self.source_stream)?,
+            ),
+            (
+                HeaderKey::new(SOURCE_TOPIC)?,
+                HeaderValue::from_str(&self.source_topic)?,
+            ),
+            (
+                HeaderKey::new(SOURCE_PARTITION)?,
+                HeaderValue::from_uint32(partition_id)?,
+            ),
+            (
+                HeaderKey::new(SOURCE_OFFSET)?,
+                HeaderValue::from_uint64(offset)?,
+            ),
+            (HeaderKey::new(ERROR)?, HeaderValue::from_str(error)?),
+            (
+                HeaderKey::new(ATTEMPTS)?,
+                HeaderValue::from_uint32(attempts)?,
+            ),
+        ]))
+    }
+}
+
+/// Returns the header with the given key.
+fn header<'a>(
+    headers: &'a HashMap<HeaderKey, HeaderValue>,
+    key: &str,
+) -> Result<&'a HeaderValue, ConsumeError> {
+    HeaderKey::new(key)
+        .ok()
+        .and_then(|key| headers.get(&key))
+        .ok_or_else(|| ConsumeError::DeadLetter(format!("Dead letter header {key} is missing")))
+}
+
+/// Returns the header with the given key as `Identifier`.
+fn header_identifier(
+    headers: &HashMap<HeaderKey, HeaderValue>,
+    key: &str,
+) -> Result<Identifier, ConsumeError> {
+    let value = header(headers, key)?.as_str().map_err(dead_letter_error)?;
+    Identifier::from_str_value(value).map_err(dead_letter_error)
+}
+
+/// Truncates a header value to its maximum length at a char boundary.
+fn truncate(value: &str) -> &str {
+    if value.len() <= MAX_HEADER_VALUE_LEN {
+        return value;
+    }
+
+    let mut end = MAX_HEADER_VALUE_LEN;
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    &value[..end]
+}
+
+fn dead_letter_error(e: IggyError) -> ConsumeError {
+    ConsumeError::DeadLetter(e.to_string())
+}
@@ -0,0 +1,65 @@
+use sbe_messages::SbeDecodeError;
+use std::error::Error;
+use std::fmt;
+
+/// Error yielded by the SBE message stream of a `MessageConsumer`.
+///
+/// A `Decode` error refers to a single message and carries its position and raw payload,
+/// so the stream continues with the next message. A `Poll` error reports a failure to
+/// fetch messages from the server, a `Seek` error a failure to move
+/// the consumer, a `DeadLetter` error a failure of the dead letter queue and a `Commit`
+/// error a failure to store an offset.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConsumeError {
+    /// Polling messages from the server failed.
+    Poll(String),
+    /// A polled payload could not be decoded as SBE message.
+    Decode {
+        partition_id: u32,
+        offset: u64,
+        timestamp: u64,
+        payload: Vec<u8>,
+        error: SbeDecodeError,
+    },
+    /// Moving the consumer to a new position failed.
+    Seek(String),
+    /// Publishing to or re-driving from the dead letter topic failed.
+    DeadLetter(String),
+    /// Storing the offset of a handled message failed.
+    Commit {
+        partition_id: u32,
+        offset: u64,
+        message: String,
+    },
+}
+
+impl Error for ConsumeError {}
+
+impl fmt::Display for ConsumeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Poll(message) => write!(f, "ConsumeError: Failed to poll messages: {message}"),
+            Self::Decode {
+                partition_id,
+                offset,
+                error,
+                ..
+            } => write!(
+                f,
+                "ConsumeError: Failed to decode message at offset {offset} of partition {partition_id}: {error}"
+            ),
+            Self::Seek(message) => write!(f, "ConsumeError: Failed to seek: {message}"),
+            Self::DeadLetter(message) => {
+                write!(f, "ConsumeError: Dead letter queue failed: {message}")
+            }
+            Self::Commit {
+                partition_id,
+                offset,
+                message,
+            } => write!(
+                f,
+                "ConsumeError: Failed to commit offset {offset} of partition {partition_id}: {message}"
+            ),
+        }
+    }
+}
@@ -1,6 +1,8 @@
-use crate::MessageConsumer;
+use crate::{CommitPolicy, MessageConsumer, OffsetCommitter};
 use iggy::clients::consumer::IggyConsumer;
 use iggy::identifier::Identifier;
+use message_shared::ConnectionState;
+use tokio::sync::watch;
 
 impl MessageConsumer {
     /// Returns a reference to the user identifier.
@@ -32,4 +34,29 @@ impl MessageConsumer {
     pub fn consumer_mut(&mut self) -> &mut IggyConsumer {
         &mut self.consumer
     }
+
+    /// Returns the policy for storing the offset of consumed messages.
+    #[must_use]
+    pub const fn commit_policy(&self) -> CommitPolicy {
+        self.settings.commit_policy
+    }
+
+    /// Returns an `OffsetCommitter` to acknowledge handled messages.
+    #[must_use]
+    pub fn committer(&self) -> OffsetCommitter {
+        self.committer.clone()
+    }
+
+    /// Returns the current state of the connection to the server.
+    #[must_use]
+    pub fn connection_state(&self) -> ConnectionState {
+        self.supervisor.state()
+    }
+
+    /// Returns a receiver of connection state changes, i.e. to pause work while
+    /// the connection is re-established.
+    #[must_use]
+    pub fn connection_events(&self) -> watch::Receiver<ConnectionState> {
+        self.supervisor.subscribe()
+    }
 }
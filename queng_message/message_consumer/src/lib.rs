@@ -1,30 +1,49 @@
 use common_message::StreamUser;
-use iggy::client::{Client, UserClient};
+use iggy::client::{Client, ConsumerGroupClient, UserClient};
 use iggy::clients::client::IggyClient;
-use iggy::clients::consumer::{AutoCommit, AutoCommitWhen, IggyConsumer};
-use iggy::consumer::ConsumerKind;
-use iggy::error::IggyError;
+use iggy::clients::consumer::IggyConsumer;
+use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::PollingStrategy;
 use iggy::utils::duration::IggyDuration;
 use message_shared::utils as shared_utils;
-use message_shared::Args;
+use message_shared::{
+    Args, ConnectionSupervisor, MessageClientError, ReconnectHook, SupervisorConfig,
+};
+use seek::ConsumerSettings;
 use std::str::FromStr;
+use std::sync::Arc;
 
+mod commit;
+mod dead_letter;
+mod error;
 mod getters;
+mod seek;
 mod shutdown;
+mod stream;
+
+// Re export
+pub use commit::{CommitPolicy, OffsetCommitter};
+pub use dead_letter::{DeadLetterQueue, Delivery};
+pub use error::ConsumeError;
+pub use seek::StartPosition;
+pub use stream::ConsumedMessage;
 
 pub struct MessageConsumer {
     user_id: Identifier,
     stream_id: Identifier,
     topic_id: Identifier,
-    client: IggyClient,
+    client: Arc<IggyClient>,
     consumer: IggyConsumer,
+    settings: ConsumerSettings,
+    committer: OffsetCommitter,
+    supervisor: ConnectionSupervisor,
 }
 
 impl MessageConsumer {
     /// Creates a new `MessageConsumer` instance with the specified arguments.
     ///
+    /// Offsets are committed with the default `CommitPolicy::OnPoll`.
+    ///
     /// # Arguments
     ///
     /// * `consumer_name` - The name of the consumer.
@@ -34,20 +53,23 @@ impl MessageConsumer {
     ///
     /// # Returns
     ///
-    /// A `Result` wrapping the `MessageConsumer` instance or an `IggyError`.
+    /// A `Result` wrapping the `MessageConsumer` instance or a `MessageClientError`.
     ///
     pub async fn new(
         consumer_name: &str,
         stream_id: String,
         topic_id: String,
         stream_user: &StreamUser,
-    ) -> Result<Self, IggyError> {
+    ) -> Result<Self, MessageClientError> {
         let args = Args::new(stream_id, topic_id);
-        Self::build(args, None, consumer_name, stream_user).await
+        let commit_policy = CommitPolicy::default();
+        Self::build(args, None, consumer_name, stream_user, commit_policy).await
     }
 
     /// Creates a `MessageConsumer` instance using the provided `IggyClient` and configuration.
     ///
+    /// Offsets are committed with the default `CommitPolicy::OnPoll`.
+    ///
     /// # Arguments
     ///
     /// * `client` - The `IggyClient` to use for creating the consumer.
@@ -58,7 +80,7 @@ impl MessageConsumer {
     ///
     /// # Returns
     ///
-    /// A `Result` wrapping the `MessageConsumer` instance or an `IggyError`.
+    /// A `Result` wrapping the `MessageConsumer` instance or a `MessageClientError`.
     ///
     pub async fn with_client(
         client: IggyClient,
@@ -66,20 +88,65 @@ impl MessageConsumer {
         stream_id: String,
         topic_id: String,
         stream_user: &StreamUser,
-    ) -> Result<Self, IggyError> {
+    ) -> Result<Self, MessageClientError> {
         let args = Args::new(stream_id, topic_id);
-        Self::build(args, Some(client), consumer_name, stream_user).await
+        let commit_policy = CommitPolicy::default();
+        Self::build(
+            args,
+            Some(client),
+            consumer_name,
+            stream_user,
+            commit_policy,
+        )
+        .await
+    }
+
+    /// Creates a `MessageConsumer` instance from the provided `Args` and `CommitPolicy`.
+    ///
+    /// The consumer starts at `args.start_position`.
+    /// Use `CommitPolicy::Manual` to acknowledge each message through the `OffsetCommitter`
+    /// after it has been handled, or `CommitPolicy::from_args` to honour `args.auto_commit`.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The `Args` holding the server address, stream, topic and consumer settings.
+    /// * `consumer_name` - The name of the consumer.
+    /// * `stream_user` - The stream user for authentication.
+    /// * `commit_policy` - The policy for storing the offset of consumed messages.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `MessageConsumer` instance or a `MessageClientError`.
+    ///
+    pub async fn with_args(
+        args: Args,
+        consumer_name: &str,
+        stream_user: &StreamUser,
+        commit_policy: CommitPolicy,
+    ) -> Result<Self, MessageClientError> {
+        Self::build(args, None, consumer_name, stream_user, commit_policy).await
     }
 
     /// Creates a default `MessageConsumer` instance with default arguments.
     ///
+    /// Offsets are committed with the default `CommitPolicy::OnPoll`.
+    ///
     /// # Returns
     ///
-    /// A `Result` wrapping the `MessageConsumer` instance or an `IggyError`.
+    /// A `Result` wrapping the `MessageConsumer` instance or a `MessageClientError`.
     ///
-    pub async fn default() -> Result<Self, IggyError> {
+    pub async fn default() -> Result<Self, MessageClientError> {
+        let args = Args::default();
         let consumer_name = "default-message-consumer";
-        Self::build(Args::default(), None, consumer_name, &StreamUser::default()).await
+        let commit_policy = CommitPolicy::default();
+        Self::build(
+            args,
+            None,
+            consumer_name,
+            &StreamUser::default(),
+            commit_policy,
+        )
+        .await
     }
 }
 
@@ -89,60 +156,94 @@ impl MessageConsumer {
         client: Option<IggyClient>,
         consumer_name: &str,
         stream_user: &StreamUser,
-    ) -> Result<Self, IggyError> {
+        commit_policy: CommitPolicy,
+    ) -> Result<Self, MessageClientError> {
         dbg!("Creating identifiers");
-        let stream_id = Identifier::from_str_value(&args.stream_id).expect("Invalid stream id");
-        let topic_id = Identifier::from_str_value(&args.topic_id).expect("Invalid topic id");
-        let user_id = Identifier::from_str_value(&args.username).expect("Invalid user id");
+        let stream_id = Identifier::from_str_value(&args.stream_id)
+            .map_err(|e| MessageClientError::config(&args, e))?;
+        let topic_id = Identifier::from_str_value(&args.topic_id)
+            .map_err(|e| MessageClientError::config(&args, e))?;
+        let user_id = Identifier::from_str_value(&args.username)
+            .map_err(|e| MessageClientError::config(&args, e))?;
+        let consumer_id = Identifier::from_str_value(consumer_name)
+            .map_err(|e| MessageClientError::config(&args, e))?;
+        let consumer_kind = ConsumerKind::from_code(args.consumer_kind)
+            .map_err(|e| MessageClientError::config(&args, e))?;
+        let interval = IggyDuration::from_str(&args.interval)
+            .map_err(|e| MessageClientError::config(&args, e))?;
+        let start_position = StartPosition::from_args(&args)?;
+        if consumer_kind == ConsumerKind::ConsumerGroup && start_position != StartPosition::Next {
+            return Err(MessageClientError::config(
+                &args,
+                "Consumer groups can only start after the stored offset",
+            ));
+        }
 
         dbg!("Building client");
-        let client = if client.is_some() {
-            // Unwrap client from option
-            client.unwrap()
-        } else {
-            // Build new client
-            shared_utils::build_client_from_args(args.to_sdk_args())
-                .await
-                .expect("Failed to create client")
+        let client = match client {
+            Some(client) => client,
+            None => shared_utils::build_client_from_args(&args).await?,
         };
 
         dbg!("Connecting client");
-        client.connect().await.expect("Failed to connect");
+        client
+            .connect()
+            .await
+            .map_err(|e| MessageClientError::connect(&args, e))?;
 
         dbg!("Login admin user to stream");
         client
             .login_user(stream_user.username(), stream_user.password())
             .await
-            .expect("Failed to login user");
+            .map_err(|e| MessageClientError::auth(&args, stream_user.username(), e))?;
 
         dbg!("Building consumer");
-        let mut consumer =
-            match ConsumerKind::from_code(args.consumer_kind).expect("Invalid consumer kind") {
-                ConsumerKind::Consumer => client
-                    .consumer(
-                        consumer_name,
-                        &args.stream_id,
-                        &args.topic_id,
-                        args.partition_id,
-                    )
-                    .expect("Failed to create consumer"),
-                ConsumerKind::ConsumerGroup => client
-                    .consumer_group(consumer_name, &args.stream_id, &args.topic_id)
-                    .expect("Failed to create consumer group"),
-            }
-            .auto_commit(AutoCommit::When(AutoCommitWhen::PollingMessages))
-            .create_consumer_group_if_not_exists()
-            .auto_join_consumer_group()
-            .polling_strategy(PollingStrategy::next())
-            .poll_interval(IggyDuration::from_str(&args.interval).expect("Invalid interval format"))
-            .batch_size(args.messages_per_batch)
-            .build();
-
-        dbg!("Initializing consumer");
-        consumer
-            .init()
+        let settings = ConsumerSettings {
+            name: consumer_name.to_string(),
+            kind: consumer_kind,
+            stream_id: args.stream_id.clone(),
+            topic_id: args.topic_id.clone(),
+            partition_id: args.partition_id,
+            batch_size: args.messages_per_batch,
+            poll_interval: interval,
+            commit_policy,
+        };
+        let consumer = settings
+            .consumer(&client, start_position)
             .await
-            .expect("Failed to initialize consumer");
+            .map_err(|e| MessageClientError::init(&args, e))?;
+
+        // Re-login and re-join the consumer group after a reconnect. Polling then resumes
+        // from the offset committed on the server.
+        log::debug!("Starting connection supervisor");
+        let (offset_consumer, hook) = match consumer_kind {
+            ConsumerKind::Consumer => (Consumer::new(consumer_id), None),
+            ConsumerKind::ConsumerGroup => (
+                Consumer::group(consumer_id.clone()),
+                Some(rejoin_consumer_group(
+                    stream_id.clone(),
+                    topic_id.clone(),
+                    consumer_id,
+                )),
+            ),
+        };
+
+        let client = Arc::new(client);
+        let committer = OffsetCommitter::new(
+            client.clone(),
+            offset_consumer,
+            stream_id.clone(),
+            topic_id.clone(),
+        );
+
+        let supervisor = ConnectionSupervisor::start(
+            client.clone(),
+            stream_user.username().to_string(),
+            stream_user.password().to_string(),
+            SupervisorConfig::from_args(&args),
+            hook,
+        )
+        .await;
 
         Ok(Self {
             user_id,
@@ -150,6 +251,28 @@ impl MessageConsumer {
             topic_id,
             client,
             consumer,
+            settings,
+            committer,
+            supervisor,
         })
     }
 }
+
+/// Returns a `ReconnectHook` that joins the consumer group again after a re-login.
+fn rejoin_consumer_group(
+    stream_id: Identifier,
+    topic_id: Identifier,
+    group_id: Identifier,
+) -> ReconnectHook {
+    Box::new(move |client: Arc<IggyClient>| {
+        let stream_id = stream_id.clone();
+        let topic_id = topic_id.clone();
+        let group_id = group_id.clone();
+        Box::pin(async move {
+            client
+                .join_consumer_group(&stream_id, &topic_id, &group_id)
+                .await
+                .map_err(Into::into)
+        })
+    })
+}
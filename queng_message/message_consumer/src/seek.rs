@@ -0,0 +1,219 @@
+use crate::{CommitPolicy, ConsumeError, ConsumedMessage, MessageConsumer};
+use futures_util::Stream;
+use iggy::client::MessageClient;
+use iggy::clients::client::IggyClient;
+use iggy::clients::consumer::IggyConsumer;
+use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use message_shared::{Args, MessageClientError};
+
+/// Position in a partition from which a `MessageConsumer` starts consuming.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartPosition {
+    /// The message after the offset stored on the server for this consumer.
+    Next,
+    /// The first message in the partition.
+    First,
+    /// The last message in the partition.
+    Last,
+    /// The message at the given offset.
+    Offset(u64),
+    /// The first message stored at or after the given time, in microseconds since the Unix epoch.
+    Timestamp(u64),
+}
+
+impl StartPosition {
+    /// Creates the `StartPosition` configured in the `Args`.
+    ///
+    /// `args.start_position` is one of `next`, `first`, `last`, `offset` or `timestamp`.
+    /// The `offset` position starts at `args.offset` and the `timestamp` position
+    /// at `args.start_timestamp`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MessageClientError::Config` if the position is unknown.
+    ///
+    pub fn from_args(args: &Args) -> Result<Self, MessageClientError> {
+        match args.start_position.as_str() {
+            "next" => Ok(Self::Next),
+            "first" => Ok(Self::First),
+            "last" => Ok(Self::Last),
+            "offset" => Ok(Self::Offset(args.offset)),
+            "timestamp" => Ok(Self::Timestamp(args.start_timestamp)),
+            other => Err(MessageClientError::config(
+                args,
+                format!("Unknown start position: {other}"),
+            )),
+        }
+    }
+}
+
+/// Settings of the underlying `IggyConsumer`, kept to rebuild it when seeking.
+pub(crate) struct ConsumerSettings {
+    pub(crate) name: String,
+    pub(crate) kind: ConsumerKind,
+    pub(crate) stream_id: String,
+    pub(crate) topic_id: String,
+    pub(crate) partition_id: u32,
+    pub(crate) batch_size: u32,
+    pub(crate) poll_interval: IggyDuration,
+    pub(crate) commit_policy: CommitPolicy,
+}
+
+impl ConsumerSettings {
+    /// Builds and initializes an `IggyConsumer` starting at the given position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `IggyError` if the position cannot be resolved or the consumer fails to initialize.
+    ///
+    pub(crate) async fn consumer(
+        &self,
+        client: &IggyClient,
+        position: StartPosition,
+    ) -> Result<IggyConsumer, IggyError> {
+        let polling_strategy = self.polling_strategy(client, position).await?;
+
+        let mut consumer = match self.kind {
+            ConsumerKind::Consumer => client.consumer(
+                &self.name,
+                &self.stream_id,
+                &self.topic_id,
+                self.partition_id,
+            ),
+            ConsumerKind::ConsumerGroup => {
+                client.consumer_group(&self.name, &self.stream_id, &self.topic_id)
+            }
+        }?
+        .auto_commit(self.commit_policy.auto_commit())
+        .create_consumer_group_if_not_exists()
+        .auto_join_consumer_group()
+        .polling_strategy(polling_strategy)
+        .poll_interval(self.poll_interval)
+        .batch_size(self.batch_size)
+        .build();
+
+        consumer.init().await?;
+
+        Ok(consumer)
+    }
+
+    /// Resolves a start position into a polling strategy.
+    ///
+    /// The consumer only advances offset based polling, so the first, last and timestamp
+    /// positions are resolved into the offset of the message found at that position.
+    /// Consumer groups always continue after the stored offset.
+    ///
+    async fn polling_strategy(
+        &self,
+        client: &IggyClient,
+        position: StartPosition,
+    ) -> Result<PollingStrategy, IggyError> {
+        let strategy = match position {
+            StartPosition::Next => return Ok(PollingStrategy::next()),
+            StartPosition::Offset(offset) => return Ok(PollingStrategy::offset(offset)),
+            StartPosition::First => PollingStrategy::first(),
+            StartPosition::Last => PollingStrategy::last(),
+            StartPosition::Timestamp(timestamp) => {
+                PollingStrategy::timestamp(IggyTimestamp::from(timestamp))
+            }
+        };
+
+        if let Some(offset) = self.peek_offset(client, &strategy).await? {
+            return Ok(PollingStrategy::offset(offset));
+        }
+
+        // No message at the position; start after the last message in the partition.
+        let offset = self
+            .peek_offset(client, &PollingStrategy::last())
+            .await?
+            .map_or(0, |offset| offset + 1);
+
+        Ok(PollingStrategy::offset(offset))
+    }
+
+    /// Returns the offset of the first message polled with the given strategy, if any.
+    async fn peek_offset(
+        &self,
+        client: &IggyClient,
+        strategy: &PollingStrategy,
+    ) -> Result<Option<u64>, IggyError> {
+        let stream_id = Identifier::from_str_value(&self.stream_id)?;
+        let topic_id = Identifier::from_str_value(&self.topic_id)?;
+        let consumer = Consumer::new(Identifier::from_str_value(&self.name)?);
+
+        let polled_messages = client
+            .poll_messages(
+                &stream_id,
+                &topic_id,
+                Some(self.partition_id),
+                &consumer,
+                strategy,
+                1,
+                false,
+            )
+            .await?;
+
+        Ok(polled_messages
+            .messages
+            .first()
+            .map(|message| message.offset))
+    }
+}
+
+impl MessageConsumer {
+    /// Moves the consumer to the given position in its partition.
+    ///
+    /// The underlying consumer is rebuilt, so messages of the old position that were
+    /// polled but not yet yielded by the stream are discarded. Offsets stored on the
+    /// server are not changed; use the `OffsetCommitter` to store the new position.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position to continue consuming from.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConsumeError::Seek` if the consumer is part of a consumer group,
+    /// or if the position cannot be resolved.
+    ///
+    pub async fn seek(&mut self, position: StartPosition) -> Result<(), ConsumeError> {
+        if self.settings.kind == ConsumerKind::ConsumerGroup && position != StartPosition::Next {
+            return Err(ConsumeError::Seek(
+                "Consumer groups can only continue after the stored offset".to_string(),
+            ));
+        }
+
+        self.consumer = self
+            .settings
+            .consumer(&self.client, position)
+            .await
+            .map_err(|e| ConsumeError::Seek(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Moves the consumer to the given position and returns a stream of decoded SBE messages
+    /// starting at that position, i.e. to replay the bars of a past trading day.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position to start the stream at.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConsumeError::Seek` if the consumer cannot be moved to the position.
+    ///
+    pub async fn sbe_stream_from(
+        &mut self,
+        position: StartPosition,
+    ) -> Result<impl Stream<Item = Result<ConsumedMessage, ConsumeError>> + Unpin + '_, ConsumeError>
+    {
+        self.seek(position).await?;
+        Ok(self.sbe_stream())
+    }
+}
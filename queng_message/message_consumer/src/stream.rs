@@ -0,0 +1,98 @@
+use crate::{ConsumeError, MessageConsumer};
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt};
+use iggy::clients::consumer::ReceivedMessage;
+use sbe_messages::SbeMessage;
+
+/// A decoded SBE message together with its position in the topic and its raw payload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsumedMessage {
+    partition_id: u32,
+    offset: u64,
+    timestamp: u64,
+    payload: Bytes,
+    message: SbeMessage,
+}
+
+impl ConsumedMessage {
+    /// Returns the partition the message was polled from.
+    #[must_use]
+    pub const fn partition_id(&self) -> u32 {
+        self.partition_id
+    }
+
+    /// Returns the offset of the message within the partition.
+    #[must_use]
+    pub const fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the time the message was stored, in microseconds since the Unix epoch.
+    #[must_use]
+    pub const fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Returns the payload the message was decoded from, exactly as polled.
+    #[must_use]
+    pub const fn payload(&self) -> &Bytes {
+        &self.payload
+    }
+
+    /// Returns a reference to the decoded message.
+    #[must_use]
+    pub const fn message(&self) -> &SbeMessage {
+        &self.message
+    }
+
+    /// Consumes the `ConsumedMessage` and returns the decoded message.
+    #[must_use]
+    pub fn into_message(self) -> SbeMessage {
+        self.message
+    }
+}
+
+impl MessageConsumer {
+    /// Returns a stream of decoded SBE messages polled by the underlying consumer.
+    ///
+    /// Each payload is decoded with `sbe_messages::decode_any`. A payload that fails
+    /// to decode yields a `ConsumeError::Decode` with its raw bytes and the stream
+    /// continues with the next message, so the stream can be used directly in `select!`.
+    ///
+    /// # Returns
+    ///
+    /// A `Stream` of `Result<ConsumedMessage, ConsumeError>`.
+    ///
+    pub fn sbe_stream(
+        &mut self,
+    ) -> impl Stream<Item = Result<ConsumedMessage, ConsumeError>> + Unpin + '_ {
+        self.consumer.by_ref().map(|received| match received {
+            Ok(received) => decode_received(received),
+            Err(e) => Err(ConsumeError::Poll(e.to_string())),
+        })
+    }
+}
+
+/// Decodes the payload of a received message into a `ConsumedMessage`.
+fn decode_received(received: ReceivedMessage) -> Result<ConsumedMessage, ConsumeError> {
+    let partition_id = received.partition_id;
+    let offset = received.message.offset;
+    let timestamp = received.message.timestamp;
+
+    match sbe_messages::decode_any(&received.message.payload) {
+        Ok(message) => Ok(ConsumedMessage {
+            partition_id,
+            offset,
+            timestamp,
+            payload: received.message.payload,
+            message,
+        }),
+        Err(error) => Err(ConsumeError::Decode {
+            partition_id,
+            offset,
+            timestamp,
+            payload: received.message.payload.to_vec(),
+            error,
+        }),
+    }
+}
@@ -1,6 +1,8 @@
 use crate::MessageProducer;
 use iggy::clients::producer::IggyProducer;
 use iggy::identifier::Identifier;
+use message_shared::{ConnectionState, PartitioningStrategy};
+use tokio::sync::watch;
 
 impl MessageProducer {
     /// Returns a reference to the user identifier.
@@ -26,4 +28,23 @@ impl MessageProducer {
     pub const fn producer(&self) -> &IggyProducer {
         &self.producer
     }
+
+    /// Returns the partitioning strategy of the producer.
+    #[must_use]
+    pub const fn partitioning(&self) -> &PartitioningStrategy {
+        &self.partitioning
+    }
+
+    /// Returns the current state of the connection to the server.
+    #[must_use]
+    pub fn connection_state(&self) -> ConnectionState {
+        self.supervisor.state()
+    }
+
+    /// Returns a receiver of connection state changes, i.e. to pause work while
+    /// the connection is re-established.
+    #[must_use]
+    pub fn connection_events(&self) -> watch::Receiver<ConnectionState> {
+        self.supervisor.subscribe()
+    }
 }
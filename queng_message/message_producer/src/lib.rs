@@ -2,27 +2,31 @@ mod getters;
 mod send;
 mod shutdown;
 
-use ahash::AHashMap;
+use common_iggy::TopicConfig;
 use common_message::StreamUser;
-use iggy::client::{Client, StreamClient, UserClient};
+use iggy::client::{Client, UserClient};
 use iggy::clients::client::IggyClient;
 use iggy::clients::producer::IggyProducer;
-use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::messages::send_messages::Partitioning;
-use iggy::models::permissions::{Permissions, StreamPermissions};
-use iggy::models::user_status::UserStatus;
 use iggy::utils::duration::IggyDuration;
 use message_shared::utils as shared_utils;
-use message_shared::Args;
+use message_shared::{
+    Args, ConnectionSupervisor, MessageClientError, PartitioningStrategy, ReconnectHook,
+    SupervisorConfig,
+};
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 
 pub struct MessageProducer {
     user_id: Identifier,
     stream_id: Identifier,
     topic_id: Identifier,
-    client: IggyClient,
+    client: Arc<IggyClient>,
     producer: IggyProducer,
+    encode_buffer: Mutex<Vec<u8>>,
+    partitioning: PartitioningStrategy,
+    supervisor: ConnectionSupervisor,
 }
 
 impl MessageProducer {
@@ -39,7 +43,7 @@ impl MessageProducer {
     ///
     /// # Returns
     ///
-    /// A `Result` wrapping the `MessageProducer` instance or an `IggyError`.
+    /// A `Result` wrapping the `MessageProducer` instance or a `MessageClientError`.
     ///
     /// Creates a new `MessageProducer` instance using the provided credentials and identifiers.
     ///
@@ -51,13 +55,13 @@ impl MessageProducer {
     ///
     /// # Returns
     ///
-    /// A `Result` wrapping the `MessageProducer` instance or an `IggyError`.
+    /// A `Result` wrapping the `MessageProducer` instance or a `MessageClientError`.
     ///
     pub async fn new(
         stream_id: String,
         topic_id: String,
         stream_user: &StreamUser,
-    ) -> Result<Self, IggyError> {
+    ) -> Result<Self, MessageClientError> {
         let args = Args::new(stream_id, topic_id);
         Self::build(args, None, stream_user).await
     }
@@ -73,25 +77,46 @@ impl MessageProducer {
     ///
     /// # Returns
     ///
-    /// A `Result` wrapping the `MessageProducer` instance or an `IggyError`.
+    /// A `Result` wrapping the `MessageProducer` instance or a `MessageClientError`.
     ///
     pub async fn with_client(
         client: IggyClient,
         stream_id: String,
         topic_id: String,
         stream_user: &StreamUser,
-    ) -> Result<Self, IggyError> {
+    ) -> Result<Self, MessageClientError> {
         let args = Args::new(stream_id, topic_id);
         Self::build(args, Some(client), stream_user).await
     }
 
+    /// Creates a new `MessageProducer` instance from the provided `Args`.
+    ///
+    /// Use this constructor to configure the server address, batching or the
+    /// partitioning strategy (`args.partitioning`) of the producer.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The `Args` holding the server address, stream, topic and producer settings.
+    /// * `stream_user` - The `StreamUser` containing the username and password for stream authentication.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `MessageProducer` instance or a `MessageClientError`.
+    ///
+    pub async fn with_args(
+        args: Args,
+        stream_user: &StreamUser,
+    ) -> Result<Self, MessageClientError> {
+        Self::build(args, None, stream_user).await
+    }
+
     /// Creates a default `MessageProducer` instance using the default `Args` and `StreamUser`.
     ///
     /// # Returns
     ///
-    /// A `Result` wrapping the `MessageProducer` instance or an `IggyError`.
+    /// A `Result` wrapping the `MessageProducer` instance or a `MessageClientError`.
     ///
-    pub async fn default() -> Result<Self, IggyError> {
+    pub async fn default() -> Result<Self, MessageClientError> {
         Self::build(Args::default(), None, &StreamUser::default()).await
     }
 }
@@ -101,109 +126,92 @@ impl MessageProducer {
         args: Args,
         client: Option<IggyClient>,
         stream_user: &StreamUser,
-    ) -> Result<Self, IggyError> {
+    ) -> Result<Self, MessageClientError> {
         // Create identifiers for stream, topic, and user.
         dbg!("Creating identifiers");
-        let stream_id = Identifier::from_str_value(&args.stream_id).expect("Invalid stream id");
-        let topic_id = Identifier::from_str_value(&args.topic_id).expect("Invalid topic id");
-        let user_id = Identifier::from_str_value(&args.username).expect("Invalid user id");
+        let stream_id = Identifier::from_str_value(&args.stream_id)
+            .map_err(|e| MessageClientError::config(&args, e))?;
+        let topic_id = Identifier::from_str_value(&args.topic_id)
+            .map_err(|e| MessageClientError::config(&args, e))?;
+        let user_id = Identifier::from_str_value(&args.username)
+            .map_err(|e| MessageClientError::config(&args, e))?;
+        let interval = IggyDuration::from_str(&args.interval)
+            .map_err(|e| MessageClientError::config(&args, e))?;
+        let partitioning = PartitioningStrategy::from_args(&args)?;
 
         dbg!("Building client");
-        let client = if client.is_some() {
-            // Unwrap client from option
-            client.unwrap()
-        } else {
-            // Build new client
-            shared_utils::build_client_from_args(args.to_sdk_args())
-                .await
-                .expect("Failed to create client")
+        let client = match client {
+            Some(client) => client,
+            None => shared_utils::build_client_from_args(&args).await?,
         };
 
         dbg!("Connecting client");
-        client.connect().await.expect("Failed to connect");
+        client
+            .connect()
+            .await
+            .map_err(|e| MessageClientError::connect(&args, e))?;
 
         dbg!("Login admin user");
         client
             .login_user(&args.username, &args.password)
             .await
-            .expect("Failed to login user");
+            .map_err(|e| MessageClientError::auth(&args, &args.username, e))?;
 
         dbg!("Creating producer");
         let mut producer = client
             .producer(&args.stream_id, &args.topic_id)
-            .expect("Failed to create producer")
+            .map_err(|e| MessageClientError::init(&args, e))?
             .batch_size(args.messages_per_batch)
-            .send_interval(IggyDuration::from_str(&args.interval).expect("Invalid interval format"))
+            .send_interval(interval)
             .partitioning(Partitioning::balanced())
             .build();
 
-        dbg!("Creating stream");
-        let res = client.create_stream(&args.stream_id, None).await;
-        dbg!(&res);
-
-        let stream = if res.is_err() {
-            let code = res.as_ref().unwrap_err().as_code() as usize;
-            if code == IggyError::StreamIdAlreadyExists as usize {
-                // Stream already exists
-                dbg!("Stream already exists");
-                client
-                    .get_stream(&Identifier::from_str_value(&args.stream_id)?)
-                    .await
-                    .expect("Failed to get stream")
-                    .unwrap()
-            } else {
-                dbg!("Error creating stream");
-                return Err(res.unwrap_err());
-            }
-        } else {
-            res?
-        };
-
-        // Configure stream permissions
-        dbg!("Configuring stream permissions");
-        let mut streams_permissions = AHashMap::new();
-        streams_permissions.insert(
-            stream.id,
-            StreamPermissions {
-                read_stream: true,
-                read_topics: true,
-                ..Default::default()
-            },
-        );
+        dbg!("Provisioning stream and topic");
+        let topics = [args.topic_id.clone()];
+        let topic_config = TopicConfig::new(args.partitions_count);
+        let report =
+            common_iggy::provision_stream(&client, &args.stream_id, &topics, &topic_config)
+                .await
+                .map_err(|e| MessageClientError::provisioning(&args, e))?;
+        for drift in report.drift() {
+            log::warn!("{drift}");
+        }
 
-        let permissions = Permissions {
-            streams: Some(streams_permissions),
-            ..Default::default()
-        };
+        // Create custom stream user, allowed to read the stream only
+        dbg!("Provisioning custom stream user");
+        common_iggy::provision_stream_user(
+            &client,
+            &args.stream_id,
+            stream_user.username(),
+            stream_user.password(),
+        )
+        .await
+        .map_err(|e| MessageClientError::provisioning(&args, e))?;
 
-        // Create custom stream user
-        dbg!("Creating custom stream user");
-        match client
-            .create_user(
-                stream_user.username(),
-                stream_user.password(),
-                UserStatus::Active,
-                Some(permissions),
-            )
+        dbg!("Initializing producer");
+        producer
+            .init()
             .await
-        {
-            Ok(_) => {
-                // user crated
-                dbg!("User created");
-            }
-            Err(e) => {
-                // Error code 46 means user already exists; so we will not create it again.
-                if e.as_code() == 304 {
-                    // Do nothing
-                    dbg!("User already exists");
-                } else {
-                    return Err(e);
-                }
-            }
-        }
+            .map_err(|e| MessageClientError::init(&args, e))?;
 
-        dbg!("Initializing producer");
-        producer.init().await.expect("Failed to init producer");
+        // Re-login and re-provision after a reconnect, i.e. after a server restart lost
+        // the stream or the user. The producer resumes sending once signed in.
+        log::debug!("Starting connection supervisor");
+        let client = Arc::new(client);
+        let hook = reprovision_stream(
+            args.stream_id.clone(),
+            args.topic_id.clone(),
+            topic_config,
+            stream_user.clone(),
+        );
+        let supervisor = ConnectionSupervisor::start(
+            client.clone(),
+            args.username.clone(),
+            args.password.clone(),
+            SupervisorConfig::from_args(&args),
+            Some(hook),
+        )
+        .await;
 
         Ok(Self {
             user_id,
@@ -211,6 +219,50 @@ impl MessageProducer {
             topic_id,
             client,
             producer,
+            encode_buffer: Mutex::new(Vec::new()),
+            partitioning,
+            supervisor,
+        })
+    }
+}
+
+/// Returns a `ReconnectHook` that provisions the stream, the topic and the
+/// custom stream user again after a re-login.
+fn reprovision_stream(
+    stream: String,
+    topic: String,
+    topic_config: TopicConfig,
+    stream_user: StreamUser,
+) -> ReconnectHook {
+    Box::new(move |client: Arc<IggyClient>| {
+        let stream = stream.clone();
+        let topics = [topic.clone()];
+        let stream_user = stream_user.clone();
+        Box::pin(async move {
+            common_iggy::provision_stream(&client, &stream, &topics, &topic_config).await?;
+            common_iggy::provision_stream_user(
+                &client,
+                &stream,
+                stream_user.username(),
+                stream_user.password(),
+            )
+            .await?;
+            Ok(())
         })
+    })
+}
+
+impl MessageProducer {
+    /// Replaces the partitioning strategy of the producer, i.e. with a `Custom` closure
+    /// that cannot be configured through `Args`.
+    ///
+    /// # Arguments
+    ///
+    /// * `partitioning` - The strategy used to select the partition of each message.
+    ///
+    #[must_use]
+    pub fn with_partitioning(mut self, partitioning: PartitioningStrategy) -> Self {
+        self.partitioning = partitioning;
+        self
     }
 }
@@ -1,7 +1,10 @@
 use crate::MessageProducer;
 use bytes::Bytes;
-use iggy::messages::send_messages::Message;
-use message_shared::{SendMessage, SendMessageError};
+use iggy::error::IggyError;
+use iggy::messages::send_messages::{Message, Partitioning};
+use message_shared::{SendMessage, SendMessageError, SendSbeError};
+use sbe_messages::{PartitionKey, SbeEncode, SbeEncodeError};
+use std::sync::{Arc, MutexGuard, PoisonError};
 
 impl SendMessage for MessageProducer {
     /// Send a single byte message.
@@ -19,10 +22,11 @@ impl SendMessage for MessageProducer {
 
         // Convert the SBE bytes into a new message with auto-generated ID, payload and no headers.
         // The SBE headers from the paylod are used instead.
+        let partitioning = self.partitioning.payload_partitioning(&bytes);
         let message = Message::new(None, Bytes::from(bytes), None);
 
         // Send the message
-        match self.producer.send_one(message).await {
+        match self.send_partitioned(vec![(partitioning, message)]).await {
             Ok(()) => Ok(()),
             Err(e) => Err(SendMessageError {
                 message: e.to_string(),
@@ -43,18 +47,157 @@ impl SendMessage for MessageProducer {
         // represents a collection of SBE messages
 
         // Convert a byte array into a vector of messages
-        let messages: Vec<Message> = bytes_batch
+        let messages: Vec<(Partitioning, Message)> = bytes_batch
             .iter()
             // Convert the SBE bytes into a new message with auto-generated ID, payload, and no headers.
-            .map(|bytes| Message::new(None, Bytes::from(bytes.to_owned()), None))
+            .map(|bytes| {
+                let partitioning = self.partitioning.payload_partitioning(bytes);
+                let message = Message::new(None, Bytes::from(bytes.to_owned()), None);
+                (partitioning, message)
+            })
             .collect();
 
         // Send the message batch
-        match self.producer.send(messages).await {
+        match self.send_partitioned(messages).await {
             Ok(()) => Ok(()),
             Err(e) => Err(SendMessageError {
                 message: e.to_string(),
             }),
         }
     }
+
+    /// Encode and send a single SBE message.
+    ///
+    /// The message is encoded into the reusable encode buffer of the producer
+    /// and copied into the payload of a new message.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SendSbeError::Encode` if the message cannot be encoded,
+    /// or `SendSbeError::Send` if the message cannot be sent.
+    ///
+    async fn send_sbe<M: SbeEncode + PartitionKey + Sync>(
+        &self,
+        message: &M,
+    ) -> Result<(), SendSbeError> {
+        let payload = {
+            // The lock is never held across an await point.
+            let mut buffer = self.lock_encode_buffer();
+            let size = message.encode_into(&mut buffer)?;
+            Bytes::copy_from_slice(&buffer[..size])
+        };
+        let partitioning = self.partitioning.partitioning(message, &payload);
+        let message = Message::new(None, payload, None);
+
+        self.send_partitioned(vec![(partitioning, message)])
+            .await
+            .map_err(|e| {
+                SendSbeError::Send(SendMessageError {
+                    message: e.to_string(),
+                })
+            })
+    }
+
+    /// Encode and send a batch of SBE messages.
+    ///
+    /// All messages are encoded before anything is sent,
+    /// so nothing is sent if any of the messages cannot be encoded.
+    /// Messages are grouped by partition, keeping their order within each group.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SendSbeError::Encode` if any of the messages cannot be encoded,
+    /// or `SendSbeError::Send` if the messages cannot be sent.
+    ///
+    async fn send_sbe_batch<M: SbeEncode + PartitionKey + Sync>(
+        &self,
+        messages: &[M],
+    ) -> Result<(), SendSbeError> {
+        let messages: Vec<(Partitioning, Message)> = self
+            .encode_payloads(messages)?
+            .into_iter()
+            .zip(messages)
+            .map(|(bytes, message)| {
+                let partitioning = self.partitioning.partitioning(message, &bytes);
+                (partitioning, Message::new(None, bytes, None))
+            })
+            .collect();
+
+        self.send_partitioned(messages).await.map_err(|e| {
+            SendSbeError::Send(SendMessageError {
+                message: e.to_string(),
+            })
+        })
+    }
+}
+
+impl MessageProducer {
+    /// Sends messages to the partitions selected by the partitioning strategy.
+    ///
+    /// Messages are grouped by their `Partitioning` and each group is sent as one batch.
+    /// Groups are sent in the order of their first message, so messages of the same
+    /// group, i.e. of the same symbol, keep their relative order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IggyError` if any of the groups cannot be sent.
+    ///
+    async fn send_partitioned(
+        &self,
+        messages: Vec<(Partitioning, Message)>,
+    ) -> Result<(), IggyError> {
+        let mut groups: Vec<(Partitioning, Vec<Message>)> = Vec::new();
+        for (partitioning, message) in messages {
+            match groups.iter_mut().find(|(group, _)| *group == partitioning) {
+                Some((_, group)) => group.push(message),
+                None => groups.push((partitioning, vec![message])),
+            }
+        }
+
+        for (partitioning, messages) in groups {
+            self.producer
+                .send_with_partitioning(messages, Some(Arc::new(partitioning)))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Encodes SBE messages into payloads that share a single allocation.
+    ///
+    /// Each message is encoded into the reusable encode buffer of the producer and
+    /// appended to one contiguous byte vector, from which the payloads are sliced
+    /// without copying.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeEncodeError` if any of the messages cannot be encoded.
+    ///
+    fn encode_payloads<M: SbeEncode>(&self, messages: &[M]) -> Result<Vec<Bytes>, SbeEncodeError> {
+        let mut buffer = self.lock_encode_buffer();
+
+        let mut encoded = Vec::new();
+        let mut ranges = Vec::with_capacity(messages.len());
+        for message in messages {
+            let size = message.encode_into(&mut buffer)?;
+            let start = encoded.len();
+            encoded.extend_from_slice(&buffer[..size]);
+            ranges.push(start..encoded.len());
+        }
+
+        let encoded = Bytes::from(encoded);
+        Ok(ranges
+            .into_iter()
+            .map(|range| encoded.slice(range))
+            .collect())
+    }
+
+    /// Locks the reusable encode buffer of the producer.
+    ///
+    /// The buffer is overwritten by every encode, so a poisoned lock is recovered.
+    fn lock_encode_buffer(&self) -> MutexGuard<'_, Vec<u8>> {
+        self.encode_buffer
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
 }
@@ -11,25 +11,18 @@ impl MessageProducer {
     ///
     pub async fn clean_up(&self) -> Result<(), IggyError> {
         // Connect client
-        self.client.connect().await.expect("Failed to connect");
+        self.client.connect().await?;
 
         // Delete the topic
         self.client
             .delete_topic(&self.stream_id, &self.topic_id)
-            .await
-            .expect("Failed to delete topic");
+            .await?;
 
         // Delete the stream
-        self.client
-            .delete_stream(&self.stream_id)
-            .await
-            .expect("Failed to delete stream");
+        self.client.delete_stream(&self.stream_id).await?;
 
         // Delete the user
-        self.client
-            .delete_user(&self.user_id)
-            .await
-            .expect("Failed to delete user");
+        self.client.delete_user(&self.user_id).await?;
 
         Ok(())
     }
@@ -42,7 +35,7 @@ impl MessageProducer {
     ///
     pub async fn shutdown(&self) -> Result<(), IggyError> {
         // Connect client
-        self.client.connect().await.expect("Failed to connect");
+        self.client.connect().await?;
 
         // Shutdown
         self.client.shutdown().await
@@ -56,11 +49,9 @@ impl MessageProducer {
     ///
     pub async fn clean_up_and_shutdown(&self) -> Result<(), IggyError> {
         // Clean up
-        self.clean_up().await.expect("Failed to clean up");
+        self.clean_up().await?;
 
         // Shutdown
-        self.client.shutdown().await.expect("Failed to shutdown");
-
-        Ok(())
+        self.client.shutdown().await
     }
 }
@@ -1,3 +1,5 @@
+use crate::Args;
+use sbe_messages::SbeEncodeError;
 use std::fmt::Debug;
 
 #[derive(Debug)]
@@ -12,3 +14,181 @@ impl std::fmt::Display for SendMessageError {
 }
 
 impl std::error::Error for SendMessageError {}
+
+/// Error returned when sending an SBE message.
+///
+/// Separates a message that cannot be encoded from a failure
+/// of the message bus, so callers can decide whether a retry makes sense.
+#[derive(Debug)]
+pub enum SendSbeError {
+    /// The message could not be encoded.
+    Encode(SbeEncodeError),
+    /// The encoded message could not be sent.
+    Send(SendMessageError),
+}
+
+impl std::fmt::Display for SendSbeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Encode(e) => write!(f, "SendSbeError: {e}"),
+            Self::Send(e) => write!(f, "SendSbeError: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for SendSbeError {}
+
+impl From<SbeEncodeError> for SendSbeError {
+    fn from(e: SbeEncodeError) -> Self {
+        Self::Encode(e)
+    }
+}
+
+impl From<SendMessageError> for SendSbeError {
+    fn from(e: SendMessageError) -> Self {
+        Self::Send(e)
+    }
+}
+
+/// Error returned when building a message producer, consumer or client.
+///
+/// Every variant carries the stream and topic the client was built for,
+/// so services can log, retry or degrade gracefully instead of crashing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageClientError {
+    /// The configuration, i.e. an identifier or interval of the `Args`, is invalid.
+    Config {
+        stream_id: String,
+        topic_id: String,
+        message: String,
+    },
+    /// The client failed to connect to the server.
+    Connect {
+        stream_id: String,
+        topic_id: String,
+        message: String,
+    },
+    /// The user failed to log in.
+    Auth {
+        stream_id: String,
+        topic_id: String,
+        username: String,
+        message: String,
+    },
+    /// Creating the stream, topic, user or permissions failed.
+    Provisioning {
+        stream_id: String,
+        topic_id: String,
+        message: String,
+    },
+    /// Creating or initializing the producer or consumer failed.
+    Init {
+        stream_id: String,
+        topic_id: String,
+        message: String,
+    },
+}
+
+impl MessageClientError {
+    /// Creates a `Config` error for the stream and topic of the given `Args`.
+    pub fn config(args: &Args, e: impl std::fmt::Display) -> Self {
+        Self::Config {
+            stream_id: args.stream_id.clone(),
+            topic_id: args.topic_id.clone(),
+            message: e.to_string(),
+        }
+    }
+
+    /// Creates a `Connect` error for the stream and topic of the given `Args`.
+    pub fn connect(args: &Args, e: impl std::fmt::Display) -> Self {
+        Self::Connect {
+            stream_id: args.stream_id.clone(),
+            topic_id: args.topic_id.clone(),
+            message: e.to_string(),
+        }
+    }
+
+    /// Creates an `Auth` error for the stream and topic of the given `Args`.
+    pub fn auth(args: &Args, username: &str, e: impl std::fmt::Display) -> Self {
+        Self::Auth {
+            stream_id: args.stream_id.clone(),
+            topic_id: args.topic_id.clone(),
+            username: username.to_string(),
+            message: e.to_string(),
+        }
+    }
+
+    /// Creates a `Provisioning` error for the stream and topic of the given `Args`.
+    pub fn provisioning(args: &Args, e: impl std::fmt::Display) -> Self {
+        Self::Provisioning {
+            stream_id: args.stream_id.clone(),
+            topic_id: args.topic_id.clone(),
+            message: e.to_string(),
+        }
+    }
+
+    /// Creates an `Init` error for the stream and topic of the given `Args`.
+    pub fn init(args: &Args, e: impl std::fmt::Display) -> Self {
+        Self::Init {
+            stream_id: args.stream_id.clone(),
+            topic_id: args.topic_id.clone(),
+            message: e.to_string(),
+        }
+    }
+
+    /// Returns the stream the client was built for.
+    #[must_use]
+    pub fn stream_id(&self) -> &str {
+        match self {
+            Self::Config { stream_id, .. }
+            | Self::Connect { stream_id, .. }
+            | Self::Auth { stream_id, .. }
+            | Self::Provisioning { stream_id, .. }
+            | Self::Init { stream_id, .. } => stream_id,
+        }
+    }
+
+    /// Returns the topic the client was built for.
+    #[must_use]
+    pub fn topic_id(&self) -> &str {
+        match self {
+            Self::Config { topic_id, .. }
+            | Self::Connect { topic_id, .. }
+            | Self::Auth { topic_id, .. }
+            | Self::Provisioning { topic_id, .. }
+            | Self::Init { topic_id, .. } => topic_id,
+        }
+    }
+}
+
+impl std::fmt::Display for MessageClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (stream_id, topic_id) = (self.stream_id(), self.topic_id());
+        match self {
+            Self::Config { message, .. } => write!(
+                f,
+                "MessageClientError: Invalid configuration for stream {stream_id} topic {topic_id}: {message}"
+            ),
+            Self::Connect { message, .. } => write!(
+                f,
+                "MessageClientError: Failed to connect for stream {stream_id} topic {topic_id}: {message}"
+            ),
+            Self::Auth {
+                username, message, ..
+            } => write!(
+                f,
+                "MessageClientError: Failed to log in user {username} for stream {stream_id} topic {topic_id}: {message}"
+            ),
+            Self::Provisioning { message, .. } => write!(
+                f,
+                "MessageClientError: Failed to provision stream {stream_id} topic {topic_id}: {message}"
+            ),
+            Self::Init { message, .. } => write!(
+                f,
+                "MessageClientError: Failed to initialize client for stream {stream_id} topic {topic_id}: {message}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MessageClientError {}
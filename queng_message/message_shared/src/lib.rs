@@ -1,4 +1,6 @@
 mod error;
+mod partitioning;
+mod supervisor;
 mod traits;
 pub mod utils;
 
@@ -8,6 +10,8 @@ use std::str::FromStr;
 
 // Re export
 pub use error::*;
+pub use partitioning::*;
+pub use supervisor::*;
 pub use traits::*;
 
 #[derive(Debug)]
@@ -20,11 +24,14 @@ pub struct Args {
     pub topic_id: String,
     pub partition_id: u32,
     pub partitions_count: u32,
+    pub partitioning: String,
     pub compression_algorithm: u8,
     pub consumer_kind: u8,
     pub consumer_id: u32,
     pub messages_per_batch: u32,
     pub offset: u64,
+    pub start_position: String,
+    pub start_timestamp: u64,
     pub auto_commit: bool,
     pub transport: String,
     pub encryption_key: String,
@@ -35,6 +42,7 @@ pub struct Args {
     pub tcp_reconnection_interval: String,
     pub tcp_reconnection_reestablish_after: String,
     pub tcp_heartbeat_interval: String,
+    pub tcp_nodelay: bool,
     pub tcp_server_address: String,
     pub tcp_tls_enabled: bool,
     pub tcp_tls_domain: String,
@@ -109,11 +117,14 @@ impl Default for Args {
             topic_id: "example-topic".to_string(),
             partition_id: 1,
             partitions_count: 1,
+            partitioning: "balanced".to_string(),
             compression_algorithm: 1,
             consumer_kind: 1,
             consumer_id: 1,
             messages_per_batch: 1,
             offset: 0,
+            start_position: "next".to_string(),
+            start_timestamp: 0,
             auto_commit: false,
             transport: "tcp".to_string(),
             encryption_key: String::new(),
@@ -124,6 +135,7 @@ impl Default for Args {
             tcp_reconnection_interval: "1s".to_string(),
             tcp_reconnection_reestablish_after: "5s".to_string(),
             tcp_heartbeat_interval: "5s".to_string(),
+            tcp_nodelay: false,
             tcp_server_address: "127.0.0.1:8090".to_string(),
             tcp_tls_enabled: false,
             tcp_tls_domain: "localhost".to_string(),
@@ -165,6 +177,7 @@ impl Args {
             tcp_reconnection_interval: self.tcp_reconnection_interval.clone(),
             tcp_reconnection_reestablish_after: self.tcp_reconnection_reestablish_after.clone(),
             tcp_heartbeat_interval: self.tcp_heartbeat_interval.clone(),
+            tcp_nodelay: self.tcp_nodelay,
             tcp_tls_enabled: self.tcp_tls_enabled,
             tcp_tls_domain: self.tcp_tls_domain.clone(),
             tcp_tls_ca_file: self.tcp_tls_ca_file.clone(),
@@ -0,0 +1,112 @@
+use crate::{Args, MessageClientError};
+use iggy::messages::send_messages::Partitioning;
+use sbe_messages::PartitionKey;
+use std::fmt;
+use std::sync::Arc;
+
+/// Closure calculating the partition id of a message from its payload.
+pub type PartitionFn = Arc<dyn Fn(&[u8]) -> u32 + Send + Sync>;
+
+/// Strategy used by a producer to select the partition of each message.
+///
+/// Keyed strategies send all messages with the same key to the same partition,
+/// which guarantees their order across a multi-partition topic. Messages without
+/// the key, i.e. a `ClientLogin` when keyed by symbol, fall back to `Balanced`.
+#[derive(Clone, Default)]
+pub enum PartitioningStrategy {
+    /// The server distributes messages round-robin across all partitions.
+    #[default]
+    Balanced,
+    /// All messages are sent to the given partition.
+    Fixed(u32),
+    /// Messages are keyed by their symbol id.
+    SymbolId,
+    /// Messages are keyed by their client id.
+    ClientId,
+    /// The partition is calculated by the given closure from the message payload.
+    Custom(PartitionFn),
+}
+
+impl PartitioningStrategy {
+    /// Creates the `PartitioningStrategy` configured in the `Args`.
+    ///
+    /// `args.partitioning` is one of `balanced`, `fixed`, `symbol_id` or `client_id`.
+    /// The `fixed` strategy sends to `args.partition_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MessageClientError::Config` if the strategy is unknown.
+    ///
+    pub fn from_args(args: &Args) -> Result<Self, MessageClientError> {
+        match args.partitioning.as_str() {
+            "balanced" => Ok(Self::Balanced),
+            "fixed" => Ok(Self::Fixed(args.partition_id)),
+            "symbol_id" => Ok(Self::SymbolId),
+            "client_id" => Ok(Self::ClientId),
+            other => Err(MessageClientError::config(
+                args,
+                format!("Unknown partitioning strategy: {other}"),
+            )),
+        }
+    }
+
+    /// Returns the `Partitioning` of a message.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message providing the symbol and client keys.
+    /// * `payload` - The encoded message, passed to a `Custom` closure.
+    ///
+    #[must_use]
+    pub fn partitioning<M: PartitionKey>(&self, message: &M, payload: &[u8]) -> Partitioning {
+        match self {
+            Self::Balanced => Partitioning::balanced(),
+            Self::Fixed(partition_id) => Partitioning::partition_id(*partition_id),
+            Self::SymbolId => message
+                .symbol_key()
+                .map_or_else(Partitioning::balanced, |key| {
+                    Partitioning::messages_key_u32(u32::from(key))
+                }),
+            Self::ClientId => message
+                .client_key()
+                .map_or_else(Partitioning::balanced, |key| {
+                    Partitioning::messages_key_u32(u32::from(key))
+                }),
+            Self::Custom(partition_fn) => Partitioning::partition_id(partition_fn(payload)),
+        }
+    }
+
+    /// Returns the `Partitioning` of an encoded message.
+    ///
+    /// Keyed strategies decode the payload to read its key; a payload that
+    /// cannot be decoded falls back to `Balanced`.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - The encoded message.
+    ///
+    #[must_use]
+    pub fn payload_partitioning(&self, payload: &[u8]) -> Partitioning {
+        match self {
+            Self::SymbolId | Self::ClientId => sbe_messages::decode_any(payload).map_or_else(
+                |_| Partitioning::balanced(),
+                |message| self.partitioning(&message, payload),
+            ),
+            Self::Balanced => Partitioning::balanced(),
+            Self::Fixed(partition_id) => Partitioning::partition_id(*partition_id),
+            Self::Custom(partition_fn) => Partitioning::partition_id(partition_fn(payload)),
+        }
+    }
+}
+
+impl fmt::Debug for PartitioningStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Balanced => write!(f, "Balanced"),
+            Self::Fixed(partition_id) => write!(f, "Fixed({partition_id})"),
+            Self::SymbolId => write!(f, "SymbolId"),
+            Self::ClientId => write!(f, "ClientId"),
+            Self::Custom(_) => write!(f, "Custom"),
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use crate::Args;
+use futures_util::future::BoxFuture;
+use iggy::client::{Client, UserClient};
+use iggy::clients::client::IggyClient;
+use iggy::diagnostic::DiagnosticEvent;
+use iggy::utils::duration::IggyDuration;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+/// Error returned by a `ReconnectHook`.
+pub type ReconnectHookError = Box<dyn Error + Send + Sync>;
+
+/// Hook called after every re-login of a supervised client, i.e. to re-create
+/// streams and users or to re-join a consumer group.
+pub type ReconnectHook = Box<
+    dyn Fn(Arc<IggyClient>) -> BoxFuture<'static, Result<(), ReconnectHookError>> + Send + Sync,
+>;
+
+/// Connection state of a supervised client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// Connected to the server, but not yet logged in.
+    Connected,
+    /// Connected and logged in; the client is ready for use.
+    SignedIn,
+    /// The connection to the server was lost.
+    Disconnected,
+    /// The supervisor is trying to re-establish the connection.
+    Reconnecting,
+    /// The client was shut down; the supervisor has stopped.
+    Shutdown,
+}
+
+impl fmt::Display for ConnectionState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Connected => write!(f, "Connected"),
+            Self::SignedIn => write!(f, "SignedIn"),
+            Self::Disconnected => write!(f, "Disconnected"),
+            Self::Reconnecting => write!(f, "Reconnecting"),
+            Self::Shutdown => write!(f, "Shutdown"),
+        }
+    }
+}
+
+/// Reconnect settings of a `ConnectionSupervisor`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SupervisorConfig {
+    /// Time to wait for the SDK to re-establish a lost connection before the supervisor reconnects.
+    pub reestablish_after: Duration,
+    /// Time between two reconnect attempts of the supervisor.
+    pub reconnect_interval: Duration,
+    /// Maximum number of reconnect attempts per disconnect, or `None` to retry forever.
+    pub max_retries: Option<u32>,
+}
+
+impl SupervisorConfig {
+    /// Creates a `SupervisorConfig` from the tcp reconnection settings of the `Args`.
+    ///
+    /// Intervals that cannot be parsed fall back to the defaults.
+    #[must_use]
+    pub fn from_args(args: &Args) -> Self {
+        let default = Self::default();
+        Self {
+            reestablish_after: parse_duration(&args.tcp_reconnection_reestablish_after)
+                .unwrap_or(default.reestablish_after),
+            reconnect_interval: parse_duration(&args.tcp_reconnection_interval)
+                .unwrap_or(default.reconnect_interval),
+            max_retries: args.tcp_reconnection_max_retries,
+        }
+    }
+}
+
+impl Default for SupervisorConfig {
+    fn default() -> Self {
+        Self {
+            reestablish_after: Duration::from_secs(5),
+            reconnect_interval: Duration::from_secs(1),
+            max_retries: None,
+        }
+    }
+}
+
+/// Supervises the connection of an `IggyClient`.
+///
+/// The supervisor listens to the diagnostic events of the client and
+///
+/// - logs in again with the stored credentials whenever the connection is re-established
+///   or the user was signed out,
+/// - runs the `ReconnectHook` after each re-login, i.e. to re-create streams, users
+///   and permissions, or to re-join a consumer group so polling resumes from the
+///   last committed offset,
+/// - reconnects by itself if the SDK did not re-establish the connection in time,
+/// - publishes every state change through a `watch` channel.
+///
+/// The supervisor task stops when the client shuts down or the supervisor is dropped.
+pub struct ConnectionSupervisor {
+    state: watch::Receiver<ConnectionState>,
+    handle: JoinHandle<()>,
+}
+
+impl ConnectionSupervisor {
+    /// Starts supervising a connected and logged in client.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to supervise.
+    /// * `username` - The username to log in with after a reconnect.
+    /// * `password` - The password to log in with after a reconnect.
+    /// * `config` - The reconnect settings.
+    /// * `hook` - Optional hook called after every re-login.
+    ///
+    /// # Returns
+    ///
+    /// The `ConnectionSupervisor`, starting in state `SignedIn`.
+    ///
+    pub async fn start(
+        client: Arc<IggyClient>,
+        username: String,
+        password: String,
+        config: SupervisorConfig,
+        hook: Option<ReconnectHook>,
+    ) -> Self {
+        let events = client.subscribe_events().await;
+        let (sender, state) = watch::channel(ConnectionState::SignedIn);
+
+        let supervisor = Supervisor {
+            client,
+            username,
+            password,
+            config,
+            hook,
+            sender,
+        };
+        let handle = tokio::spawn(supervisor.run(events));
+
+        Self { state, handle }
+    }
+
+    /// Returns the current connection state.
+    #[must_use]
+    pub fn state(&self) -> ConnectionState {
+        *self.state.borrow()
+    }
+
+    /// Returns a receiver of connection state changes.
+    ///
+    /// Use `changed().await` on the receiver to wait for the next state change.
+    #[must_use]
+    pub fn subscribe(&self) -> watch::Receiver<ConnectionState> {
+        self.state.clone()
+    }
+}
+
+impl Drop for ConnectionSupervisor {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+struct Supervisor {
+    client: Arc<IggyClient>,
+    username: String,
+    password: String,
+    config: SupervisorConfig,
+    hook: Option<ReconnectHook>,
+    sender: watch::Sender<ConnectionState>,
+}
+
+impl Supervisor {
+    async fn run(self, mut events: async_broadcast::Receiver<DiagnosticEvent>) {
+        let mut retries = 0;
+
+        loop {
+            let timeout = match *self.sender.borrow() {
+                ConnectionState::Disconnected => self.config.reestablish_after,
+                ConnectionState::Reconnecting => self.config.reconnect_interval,
+                _ => Duration::MAX,
+            };
+
+            let event = match tokio::time::timeout(timeout, events.recv()).await {
+                Ok(Ok(event)) => event,
+                // Missed events are superseded by the next one.
+                Ok(Err(async_broadcast::RecvError::Overflowed(_))) => continue,
+                Ok(Err(async_broadcast::RecvError::Closed)) => break,
+                Err(_) => {
+                    // The connection was not re-established in time.
+                    if self.config.max_retries.is_some_and(|max| retries >= max) {
+                        // Give up until the SDK reports a new connection.
+                        self.set_state(ConnectionState::Disconnected);
+                        continue;
+                    }
+                    retries += 1;
+                    self.set_state(ConnectionState::Reconnecting);
+                    if let Err(e) = self.client.connect().await {
+                        log::warn!("Reconnect attempt {retries} failed: {e}");
+                    }
+                    continue;
+                }
+            };
+
+            match event {
+                DiagnosticEvent::Shutdown => {
+                    self.set_state(ConnectionState::Shutdown);
+                    break;
+                }
+                DiagnosticEvent::Disconnected => {
+                    retries = 0;
+                    self.set_state(ConnectionState::Disconnected);
+                }
+                DiagnosticEvent::Connected | DiagnosticEvent::SignedOut => {
+                    retries = 0;
+                    self.set_state(ConnectionState::Connected);
+                    self.sign_in().await;
+                }
+                DiagnosticEvent::SignedIn => {}
+            }
+        }
+    }
+
+    /// Logs in with the stored credentials and runs the reconnect hook.
+    async fn sign_in(&self) {
+        if let Err(e) = self.client.login_user(&self.username, &self.password).await {
+            log::warn!("Failed to log in {}: {e}", self.username);
+            return;
+        }
+
+        if let Some(hook) = &self.hook {
+            if let Err(e) = hook(self.client.clone()).await {
+                log::warn!("Reconnect hook failed: {e}");
+                return;
+            }
+        }
+
+        self.set_state(ConnectionState::SignedIn);
+    }
+
+    fn set_state(&self, state: ConnectionState) {
+        self.sender.send_if_modified(|current| {
+            let modified = *current != state;
+            *current = state;
+            modified
+        });
+    }
+}
+
+fn parse_duration(value: &str) -> Option<Duration> {
+    IggyDuration::from_str(value)
+        .ok()
+        .map(|duration| duration.get_duration())
+}
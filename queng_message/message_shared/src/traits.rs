@@ -1,4 +1,5 @@
-use crate::error::SendMessageError;
+use crate::error::{SendMessageError, SendSbeError};
+use sbe_messages::{PartitionKey, SbeEncode};
 
 // async fn in traits.
 // https://blog.rust-lang.org/2023/12/21/async-fn-rpit-in-traits.html
@@ -21,4 +22,30 @@ pub trait LocalSendMessage {
     ///
     /// Returns an error if any of the messages cannot be sent.
     async fn send_batch_messages(&self, bytes_batch: &[Vec<u8>]) -> Result<(), SendMessageError>;
+    /// Encode and send a single SBE message.
+    ///
+    /// The message can be any type implementing `SbeEncode`, i.e. all `sbe_messages` types.
+    /// Its partition is selected by the partitioning strategy of the producer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SendSbeError::Encode` if the message cannot be encoded,
+    /// or `SendSbeError::Send` if the message cannot be sent.
+    async fn send_sbe<M: SbeEncode + PartitionKey + Sync>(
+        &self,
+        message: &M,
+    ) -> Result<(), SendSbeError>;
+    /// Encode and send a batch of SBE messages.
+    ///
+    /// Nothing is sent if any of the messages cannot be encoded.
+    /// Messages with the same partition key keep their relative order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SendSbeError::Encode` if any of the messages cannot be encoded,
+    /// or `SendSbeError::Send` if the messages cannot be sent.
+    async fn send_sbe_batch<M: SbeEncode + PartitionKey + Sync>(
+        &self,
+        messages: &[M],
+    ) -> Result<(), SendSbeError>;
 }
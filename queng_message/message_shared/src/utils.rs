@@ -6,43 +6,68 @@ use std::sync::Arc;
 
 use ahash::AHashMap;
 
-use crate::Args;
+use crate::{Args, MessageClientError};
 use iggy::client::{StreamClient, UserClient};
 use iggy::models::permissions::{Permissions, StreamPermissions};
 use iggy::models::user_status::UserStatus;
 
-pub async fn build_client(stream_id: String, topic_id: String) -> Result<IggyClient, IggyError> {
+/// Builds an Iggy client for the given stream and topic using the default `Args`.
+///
+/// # Arguments
+///
+/// * `stream_id` - The identifier of the stream.
+/// * `topic_id` - The identifier of the topic.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `IggyClient` instance or a `MessageClientError`.
+///
+/// # Errors
+///
+/// Returns `MessageClientError::Config` if the client cannot be configured or created.
+///
+pub async fn build_client(
+    stream_id: String,
+    topic_id: String,
+) -> Result<IggyClient, MessageClientError> {
     let args = Args::new(stream_id, topic_id);
 
-    build_client_from_args(args.to_sdk_args()).await
+    build_client_from_args(&args).await
 }
 
 /// Builds an Iggy client using the provided `Args`.
 ///
+/// The client is not connected; call `connect` and `login_user` before use.
+///
 /// # Arguments
 ///
 /// * `args` - The `Args` to use to build the client.
 ///
 /// # Returns
 ///
-/// A `Result` wrapping the `IggyClient` instance or an `IggyError`.
+/// A `Result` wrapping the `IggyClient` instance or a `MessageClientError`.
+///
+/// # Errors
 ///
-pub async fn build_client_from_args(args: iggy::args::Args) -> Result<IggyClient, IggyError> {
+/// Returns `MessageClientError::Config` if the client cannot be configured or created.
+///
+pub async fn build_client_from_args(args: &Args) -> Result<IggyClient, MessageClientError> {
     // Build client provider configuration
     let client_provider_config = Arc::new(
-        ClientProviderConfig::from_args(args).expect("Failed to create client provider config"),
+        ClientProviderConfig::from_args(args.to_sdk_args())
+            .map_err(|e| MessageClientError::config(args, e))?,
     );
 
     // Build client_provider
     let client = client_provider::get_raw_client(client_provider_config, false)
         .await
-        .expect("Failed to create client");
+        .map_err(|e| MessageClientError::config(args, e))?;
 
     // Build client
     let client = IggyClient::builder()
         .with_client(client)
         .build()
-        .expect("Failed to create client");
+        .map_err(|e| MessageClientError::config(args, e))?;
 
     Ok(client)
 }
@@ -67,10 +92,7 @@ pub async fn create_stream_and_user(
     client: &IggyClient,
 ) -> Result<(), IggyError> {
     // Create stream
-    let stream = client
-        .create_stream(stream_name, None)
-        .await
-        .expect("Failed to create stream");
+    let stream = client.create_stream(stream_name, None).await?;
 
     let mut streams_permissions = AHashMap::new();
 
@@ -95,8 +117,7 @@ pub async fn create_stream_and_user(
             UserStatus::Active,
             Some(permissions),
         )
-        .await
-        .expect("Failed to create user");
+        .await?;
 
     Ok(())
 }
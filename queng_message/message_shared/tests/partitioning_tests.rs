@@ -0,0 +1,144 @@
+use iggy::messages::send_messages::Partitioning;
+use message_shared::{Args, PartitioningStrategy};
+use sbe_messages::{
+    ClientLoginMessage, ClientLogoutMessage, DataType, ExchangeID, HeartbeatMessage, SbeEncode,
+    SbeMessage, StartDataMessage, StopAllDataMessage, StopDataMessage, TimeResolution,
+};
+use std::sync::Arc;
+
+fn start_data(client_id: u16, symbol_id: u16) -> StartDataMessage {
+    StartDataMessage::new(
+        client_id,
+        ExchangeID::BNB,
+        symbol_id,
+        TimeResolution::OneMin,
+        DataType::TradeData,
+    )
+}
+
+fn stop_data(client_id: u16, symbol_id: u16) -> StopDataMessage {
+    StopDataMessage::new(client_id, ExchangeID::BNB, symbol_id, DataType::TradeData)
+}
+
+/// Returns the partitioning of the message and asserts that the encoded
+/// message maps to the same partitioning.
+fn partitioning(strategy: &PartitioningStrategy, message: SbeMessage) -> Partitioning {
+    let mut payload = Vec::new();
+    message.encode_into(&mut payload).unwrap();
+    let partitioning = strategy.partitioning(&message, &payload);
+    assert_eq!(strategy.payload_partitioning(&payload), partitioning);
+    partitioning
+}
+
+#[test]
+fn test_from_args() {
+    let mut args = Args::default();
+    let cases = [
+        ("balanced", Partitioning::balanced()),
+        ("fixed", Partitioning::partition_id(3)),
+        ("symbol_id", Partitioning::messages_key_u32(2)),
+        ("client_id", Partitioning::messages_key_u32(7)),
+    ];
+
+    args.partition_id = 3;
+    for (name, expected) in cases {
+        args.partitioning = name.to_string();
+        let strategy = PartitioningStrategy::from_args(&args).unwrap();
+        let message = SbeMessage::StartData(start_data(7, 2));
+        assert_eq!(partitioning(&strategy, message), expected, "{name}");
+    }
+
+    args.partitioning = "round_robin".to_string();
+    assert!(PartitioningStrategy::from_args(&args).is_err());
+}
+
+#[test]
+fn test_symbol_id_maps_each_symbol_to_one_key() {
+    let strategy = PartitioningStrategy::SymbolId;
+
+    for symbol_id in [0, 1, 2, u16::MAX] {
+        let expected = Partitioning::messages_key_u32(u32::from(symbol_id));
+
+        // Same symbol, different clients and message types
+        let messages = [
+            SbeMessage::StartData(start_data(1, symbol_id)),
+            SbeMessage::StartData(start_data(2, symbol_id)),
+            SbeMessage::StopData(stop_data(3, symbol_id)),
+        ];
+        for message in messages {
+            assert_eq!(partitioning(&strategy, message), expected);
+        }
+    }
+
+    assert_ne!(
+        partitioning(&strategy, SbeMessage::StartData(start_data(1, 1))),
+        partitioning(&strategy, SbeMessage::StartData(start_data(1, 2)))
+    );
+}
+
+#[test]
+fn test_client_id_maps_each_client_to_one_key() {
+    let strategy = PartitioningStrategy::ClientId;
+
+    for client_id in [0, 7, u16::MAX] {
+        let expected = Partitioning::messages_key_u32(u32::from(client_id));
+
+        // Same client, different symbols and message types
+        let messages = [
+            SbeMessage::ClientLogin(ClientLoginMessage::new(client_id)),
+            SbeMessage::Heartbeat(HeartbeatMessage::new(client_id)),
+            SbeMessage::StartData(start_data(client_id, 1)),
+            SbeMessage::StopData(stop_data(client_id, 2)),
+            SbeMessage::StopAllData(StopAllDataMessage::new(client_id, ExchangeID::BNB)),
+            SbeMessage::ClientLogout(ClientLogoutMessage::new(client_id)),
+        ];
+        for message in messages {
+            assert_eq!(partitioning(&strategy, message), expected);
+        }
+    }
+}
+
+#[test]
+fn test_keyed_strategies_fall_back_to_balanced() {
+    // A login carries no symbol
+    let login = SbeMessage::ClientLogin(ClientLoginMessage::new(7));
+    assert_eq!(
+        partitioning(&PartitioningStrategy::SymbolId, login),
+        Partitioning::balanced()
+    );
+
+    // A payload that does not decode carries no key
+    for strategy in [
+        PartitioningStrategy::SymbolId,
+        PartitioningStrategy::ClientId,
+    ] {
+        assert_eq!(strategy.payload_partitioning(&[]), Partitioning::balanced());
+        assert_eq!(
+            strategy.payload_partitioning(&[0xff; 16]),
+            Partitioning::balanced()
+        );
+    }
+}
+
+#[test]
+fn test_fixed_and_custom_ignore_keys() {
+    let fixed = PartitioningStrategy::Fixed(5);
+    let custom = PartitioningStrategy::Custom(Arc::new(|payload: &[u8]| {
+        u32::from(payload[payload.len() - 1]) % 3 + 1
+    }));
+
+    for client_id in [1, 2] {
+        let message = SbeMessage::StartData(start_data(client_id, 1));
+        assert_eq!(
+            partitioning(&fixed, message.clone()),
+            Partitioning::partition_id(5)
+        );
+
+        // The last byte of the payload is the data type id, the same for both clients
+        let data_type = DataType::TradeData as u32;
+        assert_eq!(
+            partitioning(&custom, message),
+            Partitioning::partition_id(data_type % 3 + 1)
+        );
+    }
+}
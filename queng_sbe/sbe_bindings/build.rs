@@ -0,0 +1,22 @@
+use std::path::PathBuf;
+
+/// Fails the build if the committed bindings do not match the SBE schema.
+///
+/// Regenerate the bindings with `cargo run -p sbe_codegen -- generate`.
+fn main() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let schema_path = manifest_dir.join("schema/sbe_schema.xml");
+    let src_dir = manifest_dir.join("src");
+
+    println!("cargo:rerun-if-changed=schema/sbe_schema.xml");
+    println!("cargo:rerun-if-changed=src");
+
+    let outdated = sbe_codegen::check_bindings(&schema_path, &src_dir)
+        .unwrap_or_else(|e| panic!("[sbe_bindings/build]: {e}"));
+
+    assert!(
+        outdated.is_empty(),
+        "[sbe_bindings/build]: Bindings do not match schema/sbe_schema.xml: {outdated:?}. \
+         Run `cargo run -p sbe_codegen -- generate`"
+    );
+}
@@ -6,6 +6,7 @@ pub enum ClientErrorType {
     ClientLogInError = 0x2_u8,
     ClientNotLoggedIn = 0x3_u8,
     ClientLogOutError = 0x4_u8,
+    ServiceShutdown = 0x5_u8,
     #[default]
     NullVal = 0xff_u8,
 }
@@ -18,6 +19,7 @@ impl From<u8> for ClientErrorType {
             0x2_u8 => Self::ClientLogInError,
             0x3_u8 => Self::ClientNotLoggedIn,
             0x4_u8 => Self::ClientLogOutError,
+            0x5_u8 => Self::ServiceShutdown,
             _ => Self::NullVal,
         }
     }
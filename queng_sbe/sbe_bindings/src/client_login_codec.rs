@@ -6,10 +6,11 @@ use crate::{
 pub use decoder::ClientLoginDecoder;
 pub use encoder::ClientLoginEncoder;
 
-pub const SBE_BLOCK_LENGTH: u16 = 4;
+pub const SBE_BLOCK_LENGTH: u16 = 20;
+pub const SBE_MIN_BLOCK_LENGTH: u16 = 4;
 pub const SBE_TEMPLATE_ID: u16 = 101;
 pub const SBE_SCHEMA_ID: u16 = 1;
-pub const SBE_SCHEMA_VERSION: u16 = 1;
+pub const SBE_SCHEMA_VERSION: u16 = 8;
 pub const SBE_SEMANTIC_VERSION: &str = "5.2";
 
 pub mod encoder {
@@ -92,6 +93,36 @@ pub mod encoder {
             let offset = self.offset + 2;
             self.get_buf_mut().put_u16_at(offset, value);
         }
+
+        /// primitive field 'secretHigh'
+        /// - min value: 0
+        /// - max value: 18446744073709551614
+        /// - null value: 18446744073709551615
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 4
+        /// - encodedLength: 8
+        /// - sinceVersion: 8
+        #[inline]
+        pub fn secret_high(&mut self, value: u64) {
+            let offset = self.offset + 4;
+            self.get_buf_mut().put_u64_at(offset, value);
+        }
+
+        /// primitive field 'secretLow'
+        /// - min value: 0
+        /// - max value: 18446744073709551614
+        /// - null value: 18446744073709551615
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 12
+        /// - encodedLength: 8
+        /// - sinceVersion: 8
+        #[inline]
+        pub fn secret_low(&mut self, value: u64) {
+            let offset = self.offset + 12;
+            self.get_buf_mut().put_u64_at(offset, value);
+        }
     }
 } // end encoder
 
@@ -182,5 +213,27 @@ pub mod decoder {
         pub fn client_id(&self) -> u16 {
             self.get_buf().get_u16_at(self.offset + 2)
         }
+
+        /// primitive field - 'REQUIRED'
+        /// - sinceVersion: 8
+        #[inline]
+        #[must_use]
+        pub fn secret_high(&self) -> u64 {
+            if self.acting_version < 8 || (self.acting_block_length as usize) < 12 {
+                return 0xffffffffffffffff_u64;
+            }
+            self.get_buf().get_u64_at(self.offset + 4)
+        }
+
+        /// primitive field - 'REQUIRED'
+        /// - sinceVersion: 8
+        #[inline]
+        #[must_use]
+        pub fn secret_low(&self) -> u64 {
+            if self.acting_version < 8 || (self.acting_block_length as usize) < 20 {
+                return 0xffffffffffffffff_u64;
+            }
+            self.get_buf().get_u64_at(self.offset + 12)
+        }
     }
 } // end decoder
@@ -7,9 +7,10 @@ pub use decoder::ClientLogoutDecoder;
 pub use encoder::ClientLogoutEncoder;
 
 pub const SBE_BLOCK_LENGTH: u16 = 4;
+pub const SBE_MIN_BLOCK_LENGTH: u16 = 4;
 pub const SBE_TEMPLATE_ID: u16 = 102;
 pub const SBE_SCHEMA_ID: u16 = 1;
-pub const SBE_SCHEMA_VERSION: u16 = 1;
+pub const SBE_SCHEMA_VERSION: u16 = 8;
 pub const SBE_SEMANTIC_VERSION: &str = "5.2";
 
 pub mod encoder {
@@ -0,0 +1,122 @@
+use crate::{ReadBuf, Reader, SbeErr, SbeResult, WriteBuf, Writer};
+
+pub use decoder::DecimalDecoder;
+pub use encoder::DecimalEncoder;
+
+pub const ENCODED_LENGTH: usize = 9;
+
+pub mod encoder {
+    use super::{SbeErr, SbeResult, WriteBuf, Writer};
+
+    #[derive(Debug, Default)]
+    pub struct DecimalEncoder<P> {
+        parent: Option<P>,
+        offset: usize,
+    }
+
+    impl<'a, P> Writer<'a> for DecimalEncoder<P>
+    where
+        P: Writer<'a> + Default,
+    {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            if let Some(parent) = self.parent.as_mut() {
+                parent.get_buf_mut()
+            } else {
+                panic!("parent was None")
+            }
+        }
+    }
+
+    impl<'a, P> DecimalEncoder<P>
+    where
+        P: Writer<'a> + Default,
+    {
+        pub fn wrap(mut self, parent: P, offset: usize) -> Self {
+            self.parent = Some(parent);
+            self.offset = offset;
+            self
+        }
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }
+
+        /// primitive field 'mantissa'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 0
+        /// - encodedLength: 8
+        #[inline]
+        pub fn mantissa(&mut self, value: i64) {
+            let offset = self.offset;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'exponent'
+        /// - min value: -127
+        /// - max value: 127
+        /// - null value: -128
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 8
+        /// - encodedLength: 1
+        #[inline]
+        pub fn exponent(&mut self, value: i8) {
+            let offset = self.offset + 8;
+            self.get_buf_mut().put_i8_at(offset, value);
+        }
+    }
+} // end encoder mod
+
+pub mod decoder {
+    use super::{ReadBuf, Reader, SbeErr, SbeResult};
+
+    #[derive(Debug, Default)]
+    pub struct DecimalDecoder<P> {
+        parent: Option<P>,
+        offset: usize,
+    }
+
+    impl<'a, P> Reader<'a> for DecimalDecoder<P>
+    where
+        P: Reader<'a> + Default,
+    {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            self.parent.as_ref().expect("parent missing").get_buf()
+        }
+    }
+
+    impl<'a, P> DecimalDecoder<P>
+    where
+        P: Reader<'a> + Default,
+    {
+        pub fn wrap(mut self, parent: P, offset: usize) -> Self {
+            self.parent = Some(parent);
+            self.offset = offset;
+            self
+        }
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn mantissa(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        pub fn exponent(&self) -> i8 {
+            self.get_buf().get_i8_at(self.offset + 8)
+        }
+    }
+} // end decoder mod
@@ -3,13 +3,14 @@ use crate::{
     MessageType, ReadBuf, Reader, WriteBuf, Writer,
 };
 
-pub use decoder::FirstDataBarDecoder;
-pub use encoder::FirstDataBarEncoder;
+pub use decoder::FirstOHLCVBarDecoder;
+pub use encoder::FirstOHLCVBarEncoder;
 
 pub const SBE_BLOCK_LENGTH: u16 = 4;
+pub const SBE_MIN_BLOCK_LENGTH: u16 = 4;
 pub const SBE_TEMPLATE_ID: u16 = 205;
 pub const SBE_SCHEMA_ID: u16 = 1;
-pub const SBE_SCHEMA_VERSION: u16 = 1;
+pub const SBE_SCHEMA_VERSION: u16 = 8;
 pub const SBE_SEMANTIC_VERSION: &str = "5.2";
 
 pub mod encoder {
@@ -19,21 +20,21 @@ pub mod encoder {
     };
 
     #[derive(Debug, Default)]
-    pub struct FirstDataBarEncoder<'a> {
+    pub struct FirstOHLCVBarEncoder<'a> {
         buf: WriteBuf<'a>,
         initial_offset: usize,
         offset: usize,
         limit: usize,
     }
 
-    impl<'a> Writer<'a> for FirstDataBarEncoder<'a> {
+    impl<'a> Writer<'a> for FirstOHLCVBarEncoder<'a> {
         #[inline]
         fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
             &mut self.buf
         }
     }
 
-    impl<'a> Encoder<'a> for FirstDataBarEncoder<'a> {
+    impl<'a> Encoder<'a> for FirstOHLCVBarEncoder<'a> {
         #[inline]
         fn get_limit(&self) -> usize {
             self.limit
@@ -45,7 +46,7 @@ pub mod encoder {
         }
     }
 
-    impl<'a> FirstDataBarEncoder<'a> {
+    impl<'a> FirstOHLCVBarEncoder<'a> {
         #[must_use]
         pub const fn wrap(mut self, buf: WriteBuf<'a>, offset: usize) -> Self {
             let limit = offset + SBE_BLOCK_LENGTH as usize;
@@ -102,7 +103,7 @@ pub mod decoder {
     };
 
     #[derive(Clone, Copy, Debug, Default)]
-    pub struct FirstDataBarDecoder<'a> {
+    pub struct FirstOHLCVBarDecoder<'a> {
         buf: ReadBuf<'a>,
         initial_offset: usize,
         offset: usize,
@@ -111,14 +112,14 @@ pub mod decoder {
         pub acting_version: u16,
     }
 
-    impl<'a> Reader<'a> for FirstDataBarDecoder<'a> {
+    impl<'a> Reader<'a> for FirstOHLCVBarDecoder<'a> {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
             &self.buf
         }
     }
 
-    impl<'a> Decoder<'a> for FirstDataBarDecoder<'a> {
+    impl<'a> Decoder<'a> for FirstOHLCVBarDecoder<'a> {
         #[inline]
         fn get_limit(&self) -> usize {
             self.limit
@@ -130,7 +131,7 @@ pub mod decoder {
         }
     }
 
-    impl<'a> FirstDataBarDecoder<'a> {
+    impl<'a> FirstOHLCVBarDecoder<'a> {
         #[must_use]
         pub const fn wrap(
             mut self,
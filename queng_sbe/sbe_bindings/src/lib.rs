@@ -8,16 +8,23 @@ use ::core::convert::TryInto;
 pub mod client_error_codec;
 pub mod client_error_type;
 pub mod client_login_codec;
+pub mod client_login_reply_codec;
 pub mod client_logout_codec;
-pub mod data_bar_codec;
 pub mod data_error_codec;
+pub mod decimal_codec;
 pub mod exchange_id;
-pub mod first_data_bar_codec;
+pub mod first_ohlcv_bar_codec;
 pub mod first_trade_bar_codec;
-pub mod last_data_bar_codec;
+pub mod group_size_encoding_codec;
+pub mod heartbeat_codec;
+pub mod last_ohlcv_bar_codec;
 pub mod last_trade_bar_codec;
 pub mod message_header_codec;
 pub mod message_type;
+pub mod ohlcv_bar_codec;
+pub mod order_book_snapshot_codec;
+pub mod order_book_update_codec;
+pub mod quote_codec;
 pub mod start_data_msg_codec;
 pub mod stop_all_data_msg_codec;
 pub mod stop_data_msg_codec;
@@ -26,16 +33,23 @@ pub mod trade_bar_codec;
 pub use crate::client_error_codec::*;
 pub use crate::client_error_type::*;
 pub use crate::client_login_codec::*;
+pub use crate::client_login_reply_codec::*;
 pub use crate::client_logout_codec::*;
-pub use crate::data_bar_codec::*;
 pub use crate::data_error_codec::*;
+pub use crate::decimal_codec::*;
 pub use crate::exchange_id::*;
-pub use crate::first_data_bar_codec::*;
+pub use crate::first_ohlcv_bar_codec::*;
 pub use crate::first_trade_bar_codec::*;
-pub use crate::last_data_bar_codec::*;
+pub use crate::group_size_encoding_codec::*;
+pub use crate::heartbeat_codec::*;
+pub use crate::last_ohlcv_bar_codec::*;
 pub use crate::last_trade_bar_codec::*;
 pub use crate::message_header_codec::*;
 pub use crate::message_type::*;
+pub use crate::ohlcv_bar_codec::*;
+pub use crate::order_book_snapshot_codec::*;
+pub use crate::order_book_update_codec::*;
+pub use crate::quote_codec::*;
 pub use crate::start_data_msg_codec::*;
 pub use crate::stop_all_data_msg_codec::*;
 pub use crate::stop_data_msg_codec::*;
@@ -4,15 +4,20 @@ pub enum MessageType {
     UnknownMessageType = 0x0_u16,
     ClientLogin = 0x65_u16,
     ClientLogout = 0x66_u16,
+    Heartbeat = 0x67_u16,
+    ClientLoginReply = 0x68_u16,
     StartData = 0xc9_u16,
     StopData = 0xca_u16,
     StopAllData = 0xcb_u16,
-    DataBar = 0xcc_u16,
-    FirstDataBar = 0xcd_u16,
-    LastDataBar = 0xce_u16,
+    OHLCVBar = 0xcc_u16,
+    FirstOHLCVBar = 0xcd_u16,
+    LastOHLCVBar = 0xce_u16,
     TradeBar = 0xcf_u16,
     FirstTradeBar = 0xd0_u16,
     LastTradeBar = 0xd1_u16,
+    OrderBookSnapshot = 0xd2_u16,
+    OrderBookUpdate = 0xd3_u16,
+    Quote = 0xd4_u16,
     ClientError = 0x321_u16,
     DataError = 0x322_u16,
     #[default]
@@ -25,15 +30,20 @@ impl From<u16> for MessageType {
             0x0_u16 => Self::UnknownMessageType,
             0x65_u16 => Self::ClientLogin,
             0x66_u16 => Self::ClientLogout,
+            0x67_u16 => Self::Heartbeat,
+            0x68_u16 => Self::ClientLoginReply,
             0xc9_u16 => Self::StartData,
             0xca_u16 => Self::StopData,
             0xcb_u16 => Self::StopAllData,
-            0xcc_u16 => Self::DataBar,
-            0xcd_u16 => Self::FirstDataBar,
-            0xce_u16 => Self::LastDataBar,
+            0xcc_u16 => Self::OHLCVBar,
+            0xcd_u16 => Self::FirstOHLCVBar,
+            0xce_u16 => Self::LastOHLCVBar,
             0xcf_u16 => Self::TradeBar,
             0xd0_u16 => Self::FirstTradeBar,
             0xd1_u16 => Self::LastTradeBar,
+            0xd2_u16 => Self::OrderBookSnapshot,
+            0xd3_u16 => Self::OrderBookUpdate,
+            0xd4_u16 => Self::Quote,
             0x321_u16 => Self::ClientError,
             0x322_u16 => Self::DataError,
             _ => Self::NullVal,
@@ -1,39 +1,40 @@
 use crate::{
-    message_header_codec, Decoder, Encoder, MessageHeaderDecoder, MessageHeaderEncoder,
-    MessageType, ReadBuf, Reader, WriteBuf, Writer,
+    message_header_codec, DecimalDecoder, DecimalEncoder, Decoder, Encoder, MessageHeaderDecoder,
+    MessageHeaderEncoder, MessageType, ReadBuf, Reader, WriteBuf, Writer,
 };
 
-pub use decoder::DataBarDecoder;
-pub use encoder::DataBarEncoder;
+pub use decoder::OHLCVBarDecoder;
+pub use encoder::OHLCVBarEncoder;
 
-pub const SBE_BLOCK_LENGTH: u16 = 32;
+pub const SBE_BLOCK_LENGTH: u16 = 57;
+pub const SBE_MIN_BLOCK_LENGTH: u16 = 57;
 pub const SBE_TEMPLATE_ID: u16 = 204;
 pub const SBE_SCHEMA_ID: u16 = 1;
-pub const SBE_SCHEMA_VERSION: u16 = 1;
+pub const SBE_SCHEMA_VERSION: u16 = 8;
 pub const SBE_SEMANTIC_VERSION: &str = "5.2";
 
 pub mod encoder {
     use super::{
-        Encoder, MessageHeaderEncoder, MessageType, WriteBuf, Writer, SBE_BLOCK_LENGTH,
-        SBE_SCHEMA_ID, SBE_SCHEMA_VERSION, SBE_TEMPLATE_ID,
+        DecimalEncoder, Encoder, MessageHeaderEncoder, MessageType, WriteBuf, Writer,
+        SBE_BLOCK_LENGTH, SBE_SCHEMA_ID, SBE_SCHEMA_VERSION, SBE_TEMPLATE_ID,
     };
 
     #[derive(Debug, Default)]
-    pub struct DataBarEncoder<'a> {
+    pub struct OHLCVBarEncoder<'a> {
         buf: WriteBuf<'a>,
         initial_offset: usize,
         offset: usize,
         limit: usize,
     }
 
-    impl<'a> Writer<'a> for DataBarEncoder<'a> {
+    impl<'a> Writer<'a> for OHLCVBarEncoder<'a> {
         #[inline]
         fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
             &mut self.buf
         }
     }
 
-    impl<'a> Encoder<'a> for DataBarEncoder<'a> {
+    impl<'a> Encoder<'a> for OHLCVBarEncoder<'a> {
         #[inline]
         fn get_limit(&self) -> usize {
             self.limit
@@ -45,7 +46,7 @@ pub mod encoder {
         }
     }
 
-    impl<'a> DataBarEncoder<'a> {
+    impl<'a> OHLCVBarEncoder<'a> {
         #[must_use]
         pub const fn wrap(mut self, buf: WriteBuf<'a>, offset: usize) -> Self {
             let limit = offset + SBE_BLOCK_LENGTH as usize;
@@ -107,86 +108,51 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
-        /// primitive field 'openPrice'
-        /// - min value: 1.401298464324817E-45
-        /// - max value: 3.4028234663852886E38
-        /// - null value: NaN
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 12
-        /// - encodedLength: 4
+        /// COMPOSITE ENCODER
         #[inline]
-        pub fn open_price(&mut self, value: f32) {
+        pub fn open_price_encoder(self) -> DecimalEncoder<Self> {
             let offset = self.offset + 12;
-            self.get_buf_mut().put_f32_at(offset, value);
+            DecimalEncoder::default().wrap(self, offset)
         }
 
-        /// primitive field 'highPrice'
-        /// - min value: 1.401298464324817E-45
-        /// - max value: 3.4028234663852886E38
-        /// - null value: NaN
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 16
-        /// - encodedLength: 4
+        /// COMPOSITE ENCODER
         #[inline]
-        pub fn high_price(&mut self, value: f32) {
-            let offset = self.offset + 16;
-            self.get_buf_mut().put_f32_at(offset, value);
+        pub fn high_price_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset + 21;
+            DecimalEncoder::default().wrap(self, offset)
         }
 
-        /// primitive field 'lowPrice'
-        /// - min value: 1.401298464324817E-45
-        /// - max value: 3.4028234663852886E38
-        /// - null value: NaN
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 20
-        /// - encodedLength: 4
+        /// COMPOSITE ENCODER
         #[inline]
-        pub fn low_price(&mut self, value: f32) {
-            let offset = self.offset + 20;
-            self.get_buf_mut().put_f32_at(offset, value);
+        pub fn low_price_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset + 30;
+            DecimalEncoder::default().wrap(self, offset)
         }
 
-        /// primitive field 'closePrice'
-        /// - min value: 1.401298464324817E-45
-        /// - max value: 3.4028234663852886E38
-        /// - null value: NaN
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 24
-        /// - encodedLength: 4
+        /// COMPOSITE ENCODER
         #[inline]
-        pub fn close_price(&mut self, value: f32) {
-            let offset = self.offset + 24;
-            self.get_buf_mut().put_f32_at(offset, value);
+        pub fn close_price_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset + 39;
+            DecimalEncoder::default().wrap(self, offset)
         }
 
-        /// primitive field 'volume'
-        /// - min value: 1.401298464324817E-45
-        /// - max value: 3.4028234663852886E38
-        /// - null value: NaN
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 28
-        /// - encodedLength: 4
+        /// COMPOSITE ENCODER
         #[inline]
-        pub fn volume(&mut self, value: f32) {
-            let offset = self.offset + 28;
-            self.get_buf_mut().put_f32_at(offset, value);
+        pub fn volume_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset + 48;
+            DecimalEncoder::default().wrap(self, offset)
         }
     }
 } // end encoder
 
 pub mod decoder {
     use super::{
-        message_header_codec, Decoder, MessageHeaderDecoder, MessageType, ReadBuf, Reader,
-        SBE_TEMPLATE_ID,
+        message_header_codec, DecimalDecoder, Decoder, MessageHeaderDecoder, MessageType, ReadBuf,
+        Reader, SBE_TEMPLATE_ID,
     };
 
     #[derive(Clone, Copy, Debug, Default)]
-    pub struct DataBarDecoder<'a> {
+    pub struct OHLCVBarDecoder<'a> {
         buf: ReadBuf<'a>,
         initial_offset: usize,
         offset: usize,
@@ -195,14 +161,14 @@ pub mod decoder {
         pub acting_version: u16,
     }
 
-    impl<'a> Reader<'a> for DataBarDecoder<'a> {
+    impl<'a> Reader<'a> for OHLCVBarDecoder<'a> {
         #[inline]
         fn get_buf(&self) -> &ReadBuf<'a> {
             &self.buf
         }
     }
 
-    impl<'a> Decoder<'a> for DataBarDecoder<'a> {
+    impl<'a> Decoder<'a> for OHLCVBarDecoder<'a> {
         #[inline]
         fn get_limit(&self) -> usize {
             self.limit
@@ -214,7 +180,7 @@ pub mod decoder {
         }
     }
 
-    impl<'a> DataBarDecoder<'a> {
+    impl<'a> OHLCVBarDecoder<'a> {
         #[must_use]
         pub const fn wrap(
             mut self,
@@ -274,39 +240,44 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 4)
         }
 
-        /// primitive field - 'REQUIRED'
+        /// COMPOSITE DECODER
         #[inline]
         #[must_use]
-        pub fn open_price(&self) -> f32 {
-            self.get_buf().get_f32_at(self.offset + 12)
+        pub fn open_price_decoder(self) -> DecimalDecoder<Self> {
+            let offset = self.offset + 12;
+            DecimalDecoder::default().wrap(self, offset)
         }
 
-        /// primitive field - 'REQUIRED'
+        /// COMPOSITE DECODER
         #[inline]
         #[must_use]
-        pub fn high_price(&self) -> f32 {
-            self.get_buf().get_f32_at(self.offset + 16)
+        pub fn high_price_decoder(self) -> DecimalDecoder<Self> {
+            let offset = self.offset + 21;
+            DecimalDecoder::default().wrap(self, offset)
         }
 
-        /// primitive field - 'REQUIRED'
+        /// COMPOSITE DECODER
         #[inline]
         #[must_use]
-        pub fn low_price(&self) -> f32 {
-            self.get_buf().get_f32_at(self.offset + 20)
+        pub fn low_price_decoder(self) -> DecimalDecoder<Self> {
+            let offset = self.offset + 30;
+            DecimalDecoder::default().wrap(self, offset)
         }
 
-        /// primitive field - 'REQUIRED'
+        /// COMPOSITE DECODER
         #[inline]
         #[must_use]
-        pub fn close_price(&self) -> f32 {
-            self.get_buf().get_f32_at(self.offset + 24)
+        pub fn close_price_decoder(self) -> DecimalDecoder<Self> {
+            let offset = self.offset + 39;
+            DecimalDecoder::default().wrap(self, offset)
         }
 
-        /// primitive field - 'REQUIRED'
+        /// COMPOSITE DECODER
         #[inline]
         #[must_use]
-        pub fn volume(&self) -> f32 {
-            self.get_buf().get_f32_at(self.offset + 28)
+        pub fn volume_decoder(self) -> DecimalDecoder<Self> {
+            let offset = self.offset + 48;
+            DecimalDecoder::default().wrap(self, offset)
         }
     }
 } // end decoder
@@ -0,0 +1,695 @@
+use crate::{
+    message_header_codec, DecimalDecoder, DecimalEncoder, Decoder, Encoder, MessageHeaderDecoder,
+    MessageHeaderEncoder, MessageType, ReadBuf, Reader, SbeErr, SbeResult, WriteBuf, Writer,
+};
+
+pub use decoder::OrderBookSnapshotDecoder;
+pub use encoder::OrderBookSnapshotEncoder;
+
+pub const SBE_BLOCK_LENGTH: u16 = 20;
+pub const SBE_MIN_BLOCK_LENGTH: u16 = 20;
+pub const SBE_TEMPLATE_ID: u16 = 210;
+pub const SBE_SCHEMA_ID: u16 = 1;
+pub const SBE_SCHEMA_VERSION: u16 = 8;
+pub const SBE_SEMANTIC_VERSION: &str = "5.2";
+pub const SBE_BIDS_BLOCK_LENGTH: u16 = 18;
+pub const SBE_ASKS_BLOCK_LENGTH: u16 = 18;
+
+pub mod encoder {
+    use super::{
+        DecimalEncoder, Encoder, MessageHeaderEncoder, MessageType, SbeErr, SbeResult, WriteBuf,
+        Writer, SBE_BLOCK_LENGTH, SBE_SCHEMA_ID, SBE_SCHEMA_VERSION, SBE_TEMPLATE_ID,
+    };
+
+    #[derive(Debug, Default)]
+    pub struct OrderBookSnapshotEncoder<'a> {
+        buf: WriteBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+    }
+
+    impl<'a> Writer<'a> for OrderBookSnapshotEncoder<'a> {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            &mut self.buf
+        }
+    }
+
+    impl<'a> Encoder<'a> for OrderBookSnapshotEncoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+    }
+
+    impl<'a> OrderBookSnapshotEncoder<'a> {
+        #[must_use]
+        pub const fn wrap(mut self, buf: WriteBuf<'a>, offset: usize) -> Self {
+            let limit = offset + SBE_BLOCK_LENGTH as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self
+        }
+
+        #[inline]
+        #[must_use]
+        pub const fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        #[must_use]
+        pub fn header(self, offset: usize) -> MessageHeaderEncoder<Self> {
+            let mut header = MessageHeaderEncoder::default().wrap(self, offset);
+            header.block_length(SBE_BLOCK_LENGTH);
+            header.template_id(SBE_TEMPLATE_ID);
+            header.schema_id(SBE_SCHEMA_ID);
+            header.version(SBE_SCHEMA_VERSION);
+            header
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        pub fn message_type(&mut self, value: MessageType) {
+            let offset = self.offset;
+            self.get_buf_mut().put_u16_at(offset, value as u16);
+        }
+
+        /// primitive field 'symbolID'
+        /// - min value: 0
+        /// - max value: 65534
+        /// - null value: 65535
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 2
+        /// - encodedLength: 2
+        #[inline]
+        pub fn symbol_id(&mut self, value: u16) {
+            let offset = self.offset + 2;
+            self.get_buf_mut().put_u16_at(offset, value);
+        }
+
+        /// primitive field 'dateTime'
+        /// - min value: -9223372036854775807
+        /// - max value: 9223372036854775807
+        /// - null value: -9223372036854775808
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 4
+        /// - encodedLength: 8
+        #[inline]
+        pub fn date_time(&mut self, value: i64) {
+            let offset = self.offset + 4;
+            self.get_buf_mut().put_i64_at(offset, value);
+        }
+
+        /// primitive field 'sequenceNumber'
+        /// - min value: 0
+        /// - max value: 18446744073709551614
+        /// - null value: 18446744073709551615
+        /// - characterEncoding: null
+        /// - semanticType: null
+        /// - encodedOffset: 12
+        /// - encodedLength: 8
+        #[inline]
+        pub fn sequence_number(&mut self, value: u64) {
+            let offset = self.offset + 12;
+            self.get_buf_mut().put_u64_at(offset, value);
+        }
+
+        /// GROUP ENCODER (id=5)
+        #[inline]
+        #[must_use]
+        pub fn bids_encoder(self, count: u16) -> BidsEncoder<Self> {
+            BidsEncoder::default().wrap(self, count)
+        }
+
+        /// GROUP ENCODER (id=8)
+        #[inline]
+        #[must_use]
+        pub fn asks_encoder(self, count: u16) -> AsksEncoder<Self> {
+            AsksEncoder::default().wrap(self, count)
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct BidsEncoder<P> {
+        parent: Option<P>,
+        count: u16,
+        index: usize,
+        offset: usize,
+        initial_limit: usize,
+    }
+
+    impl<'a, P> Writer<'a> for BidsEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            if let Some(parent) = self.parent.as_mut() {
+                parent.get_buf_mut()
+            } else {
+                panic!("parent was None")
+            }
+        }
+    }
+
+    impl<'a, P> Encoder<'a> for BidsEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.parent.as_ref().expect("parent missing").get_limit()
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.parent
+                .as_mut()
+                .expect("parent missing")
+                .set_limit(limit);
+        }
+    }
+
+    impl<'a, P> BidsEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        #[must_use]
+        pub fn wrap(mut self, mut parent: P, count: u16) -> Self {
+            let initial_limit = parent.get_limit();
+            parent.set_limit(initial_limit + 4);
+            parent
+                .get_buf_mut()
+                .put_u16_at(initial_limit, Self::block_length());
+            parent.get_buf_mut().put_u16_at(initial_limit + 2, count);
+            self.parent = Some(parent);
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = usize::MAX;
+            self.initial_limit = initial_limit;
+            self
+        }
+
+        #[inline]
+        #[must_use]
+        pub const fn block_length() -> u16 {
+            18
+        }
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }
+
+        /// Moves to the next entry of the group.
+        ///
+        /// Returns the index of the entry, or `None` once all entries are written.
+        #[inline]
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+
+            if let Some(parent) = self.parent.as_mut() {
+                self.offset = parent.get_limit();
+                parent.set_limit(self.offset + Self::block_length() as usize);
+                self.index = index;
+                Ok(Some(index))
+            } else {
+                Err(SbeErr::ParentNotSet)
+            }
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn price_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset;
+            DecimalEncoder::default().wrap(self, offset)
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn size_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset + 9;
+            DecimalEncoder::default().wrap(self, offset)
+        }
+    }
+
+    #[derive(Debug, Default)]
+    pub struct AsksEncoder<P> {
+        parent: Option<P>,
+        count: u16,
+        index: usize,
+        offset: usize,
+        initial_limit: usize,
+    }
+
+    impl<'a, P> Writer<'a> for AsksEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {
+            if let Some(parent) = self.parent.as_mut() {
+                parent.get_buf_mut()
+            } else {
+                panic!("parent was None")
+            }
+        }
+    }
+
+    impl<'a, P> Encoder<'a> for AsksEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.parent.as_ref().expect("parent missing").get_limit()
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.parent
+                .as_mut()
+                .expect("parent missing")
+                .set_limit(limit);
+        }
+    }
+
+    impl<'a, P> AsksEncoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {
+        #[inline]
+        #[must_use]
+        pub fn wrap(mut self, mut parent: P, count: u16) -> Self {
+            let initial_limit = parent.get_limit();
+            parent.set_limit(initial_limit + 4);
+            parent
+                .get_buf_mut()
+                .put_u16_at(initial_limit, Self::block_length());
+            parent.get_buf_mut().put_u16_at(initial_limit + 2, count);
+            self.parent = Some(parent);
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = usize::MAX;
+            self.initial_limit = initial_limit;
+            self
+        }
+
+        #[inline]
+        #[must_use]
+        pub const fn block_length() -> u16 {
+            18
+        }
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }
+
+        /// Moves to the next entry of the group.
+        ///
+        /// Returns the index of the entry, or `None` once all entries are written.
+        #[inline]
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+
+            if let Some(parent) = self.parent.as_mut() {
+                self.offset = parent.get_limit();
+                parent.set_limit(self.offset + Self::block_length() as usize);
+                self.index = index;
+                Ok(Some(index))
+            } else {
+                Err(SbeErr::ParentNotSet)
+            }
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn price_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset;
+            DecimalEncoder::default().wrap(self, offset)
+        }
+
+        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn size_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset + 9;
+            DecimalEncoder::default().wrap(self, offset)
+        }
+    }
+} // end encoder
+
+pub mod decoder {
+    use super::{
+        message_header_codec, DecimalDecoder, Decoder, MessageHeaderDecoder, MessageType, ReadBuf,
+        Reader, SbeErr, SbeResult, SBE_TEMPLATE_ID,
+    };
+
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct OrderBookSnapshotDecoder<'a> {
+        buf: ReadBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+        pub acting_block_length: u16,
+        pub acting_version: u16,
+    }
+
+    impl<'a> Reader<'a> for OrderBookSnapshotDecoder<'a> {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            &self.buf
+        }
+    }
+
+    impl<'a> Decoder<'a> for OrderBookSnapshotDecoder<'a> {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.limit
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.limit = limit;
+        }
+    }
+
+    impl<'a> OrderBookSnapshotDecoder<'a> {
+        #[must_use]
+        pub const fn wrap(
+            mut self,
+            buf: ReadBuf<'a>,
+            offset: usize,
+            acting_block_length: u16,
+            acting_version: u16,
+        ) -> Self {
+            let limit = offset + acting_block_length as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self.acting_block_length = acting_block_length;
+            self.acting_version = acting_version;
+            self
+        }
+
+        #[inline]
+        #[must_use]
+        pub const fn encoded_length(&self) -> usize {
+            self.limit - self.offset
+        }
+
+        #[must_use]
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {
+            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+            let acting_block_length = header.block_length();
+            let acting_version = header.version();
+
+            self.wrap(
+                header.parent().unwrap(),
+                message_header_codec::ENCODED_LENGTH,
+                acting_block_length,
+                acting_version,
+            )
+        }
+
+        /// REQUIRED enum
+        #[inline]
+        #[must_use]
+        pub fn message_type(&self) -> MessageType {
+            self.get_buf().get_u16_at(self.offset).into()
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        #[must_use]
+        pub fn symbol_id(&self) -> u16 {
+            self.get_buf().get_u16_at(self.offset + 2)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        #[must_use]
+        pub fn date_time(&self) -> i64 {
+            self.get_buf().get_i64_at(self.offset + 4)
+        }
+
+        /// primitive field - 'REQUIRED'
+        #[inline]
+        #[must_use]
+        pub fn sequence_number(&self) -> u64 {
+            self.get_buf().get_u64_at(self.offset + 12)
+        }
+
+        /// GROUP DECODER (id=5)
+        #[inline]
+        #[must_use]
+        pub fn bids_decoder(self) -> BidsDecoder<Self> {
+            BidsDecoder::default().wrap(self)
+        }
+
+        /// GROUP DECODER (id=8)
+        #[inline]
+        #[must_use]
+        pub fn asks_decoder(self) -> AsksDecoder<Self> {
+            AsksDecoder::default().wrap(self)
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct BidsDecoder<P> {
+        parent: Option<P>,
+        block_length: u16,
+        count: u16,
+        index: usize,
+        offset: usize,
+    }
+
+    impl<'a, P> Reader<'a> for BidsDecoder<P>
+    where
+        P: Reader<'a> + Default,
+    {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            self.parent.as_ref().expect("parent missing").get_buf()
+        }
+    }
+
+    impl<'a, P> Decoder<'a> for BidsDecoder<P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.parent.as_ref().expect("parent missing").get_limit()
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.parent
+                .as_mut()
+                .expect("parent missing")
+                .set_limit(limit);
+        }
+    }
+
+    impl<'a, P> BidsDecoder<P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        #[inline]
+        #[must_use]
+        pub fn wrap(mut self, mut parent: P) -> Self {
+            let initial_limit = parent.get_limit();
+            let block_length = parent.get_buf().get_u16_at(initial_limit);
+            let count = parent.get_buf().get_u16_at(initial_limit + 2);
+            parent.set_limit(initial_limit + 4);
+            self.parent = Some(parent);
+            self.block_length = block_length;
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = 0;
+            self
+        }
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }
+
+        #[inline]
+        #[must_use]
+        pub const fn acting_block_length(&self) -> u16 {
+            self.block_length
+        }
+
+        #[inline]
+        #[must_use]
+        pub const fn count(&self) -> u16 {
+            self.count
+        }
+
+        /// Moves to the next entry of the group.
+        ///
+        /// Returns the index of the entry, or `None` once all entries are read.
+        #[inline]
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+
+            if let Some(parent) = self.parent.as_mut() {
+                self.offset = parent.get_limit();
+                parent.set_limit(self.offset + self.block_length as usize);
+                self.index = index;
+                Ok(Some(index))
+            } else {
+                Err(SbeErr::ParentNotSet)
+            }
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        #[must_use]
+        pub fn price_decoder(self) -> DecimalDecoder<Self> {
+            let offset = self.offset;
+            DecimalDecoder::default().wrap(self, offset)
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        #[must_use]
+        pub fn size_decoder(self) -> DecimalDecoder<Self> {
+            let offset = self.offset + 9;
+            DecimalDecoder::default().wrap(self, offset)
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct AsksDecoder<P> {
+        parent: Option<P>,
+        block_length: u16,
+        count: u16,
+        index: usize,
+        offset: usize,
+    }
+
+    impl<'a, P> Reader<'a> for AsksDecoder<P>
+    where
+        P: Reader<'a> + Default,
+    {
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {
+            self.parent.as_ref().expect("parent missing").get_buf()
+        }
+    }
+
+    impl<'a, P> Decoder<'a> for AsksDecoder<P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        #[inline]
+        fn get_limit(&self) -> usize {
+            self.parent.as_ref().expect("parent missing").get_limit()
+        }
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {
+            self.parent
+                .as_mut()
+                .expect("parent missing")
+                .set_limit(limit);
+        }
+    }
+
+    impl<'a, P> AsksDecoder<P>
+    where
+        P: Decoder<'a> + Default,
+    {
+        #[inline]
+        #[must_use]
+        pub fn wrap(mut self, mut parent: P) -> Self {
+            let initial_limit = parent.get_limit();
+            let block_length = parent.get_buf().get_u16_at(initial_limit);
+            let count = parent.get_buf().get_u16_at(initial_limit + 2);
+            parent.set_limit(initial_limit + 4);
+            self.parent = Some(parent);
+            self.block_length = block_length;
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = 0;
+            self
+        }
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }
+
+        #[inline]
+        #[must_use]
+        pub const fn acting_block_length(&self) -> u16 {
+            self.block_length
+        }
+
+        #[inline]
+        #[must_use]
+        pub const fn count(&self) -> u16 {
+            self.count
+        }
+
+        /// Moves to the next entry of the group.
+        ///
+        /// Returns the index of the entry, or `None` once all entries are read.
+        #[inline]
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {
+                return Ok(None);
+            }
+
+            if let Some(parent) = self.parent.as_mut() {
+                self.offset = parent.get_limit();
+                parent.set_limit(self.offset + self.block_length as usize);
+                self.index = index;
+                Ok(Some(index))
+            } else {
+                Err(SbeErr::ParentNotSet)
+            }
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        #[must_use]
+        pub fn price_decoder(self) -> DecimalDecoder<Self> {
+            let offset = self.offset;
+            DecimalDecoder::default().wrap(self, offset)
+        }
+
+        /// COMPOSITE DECODER
+        #[inline]
+        #[must_use]
+        pub fn size_decoder(self) -> DecimalDecoder<Self> {
+            let offset = self.offset + 9;
+            DecimalDecoder::default().wrap(self, offset)
+        }
+    }
+} // end decoder
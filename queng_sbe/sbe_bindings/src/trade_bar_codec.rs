@@ -1,21 +1,22 @@
 use crate::{
-    message_header_codec, Decoder, Encoder, MessageHeaderDecoder, MessageHeaderEncoder,
-    MessageType, ReadBuf, Reader, WriteBuf, Writer,
+    message_header_codec, DecimalDecoder, DecimalEncoder, Decoder, Encoder, MessageHeaderDecoder,
+    MessageHeaderEncoder, MessageType, ReadBuf, Reader, WriteBuf, Writer,
 };
 
 pub use decoder::TradeBarDecoder;
 pub use encoder::TradeBarEncoder;
 
-pub const SBE_BLOCK_LENGTH: u16 = 20;
+pub const SBE_BLOCK_LENGTH: u16 = 30;
+pub const SBE_MIN_BLOCK_LENGTH: u16 = 30;
 pub const SBE_TEMPLATE_ID: u16 = 207;
 pub const SBE_SCHEMA_ID: u16 = 1;
-pub const SBE_SCHEMA_VERSION: u16 = 1;
+pub const SBE_SCHEMA_VERSION: u16 = 8;
 pub const SBE_SEMANTIC_VERSION: &str = "5.2";
 
 pub mod encoder {
     use super::{
-        Encoder, MessageHeaderEncoder, MessageType, WriteBuf, Writer, SBE_BLOCK_LENGTH,
-        SBE_SCHEMA_ID, SBE_SCHEMA_VERSION, SBE_TEMPLATE_ID,
+        DecimalEncoder, Encoder, MessageHeaderEncoder, MessageType, WriteBuf, Writer,
+        SBE_BLOCK_LENGTH, SBE_SCHEMA_ID, SBE_SCHEMA_VERSION, SBE_TEMPLATE_ID,
     };
 
     #[derive(Debug, Default)]
@@ -107,40 +108,26 @@ pub mod encoder {
             self.get_buf_mut().put_i64_at(offset, value);
         }
 
-        /// primitive field 'price'
-        /// - min value: 1.401298464324817E-45
-        /// - max value: 3.4028234663852886E38
-        /// - null value: NaN
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 12
-        /// - encodedLength: 4
+        /// COMPOSITE ENCODER
         #[inline]
-        pub fn price(&mut self, value: f32) {
+        pub fn price_encoder(self) -> DecimalEncoder<Self> {
             let offset = self.offset + 12;
-            self.get_buf_mut().put_f32_at(offset, value);
+            DecimalEncoder::default().wrap(self, offset)
         }
 
-        /// primitive field 'volume'
-        /// - min value: 1.401298464324817E-45
-        /// - max value: 3.4028234663852886E38
-        /// - null value: NaN
-        /// - characterEncoding: null
-        /// - semanticType: null
-        /// - encodedOffset: 16
-        /// - encodedLength: 4
+        /// COMPOSITE ENCODER
         #[inline]
-        pub fn volume(&mut self, value: f32) {
-            let offset = self.offset + 16;
-            self.get_buf_mut().put_f32_at(offset, value);
+        pub fn volume_encoder(self) -> DecimalEncoder<Self> {
+            let offset = self.offset + 21;
+            DecimalEncoder::default().wrap(self, offset)
         }
     }
 } // end encoder
 
 pub mod decoder {
     use super::{
-        message_header_codec, Decoder, MessageHeaderDecoder, MessageType, ReadBuf, Reader,
-        SBE_TEMPLATE_ID,
+        message_header_codec, DecimalDecoder, Decoder, MessageHeaderDecoder, MessageType, ReadBuf,
+        Reader, SBE_TEMPLATE_ID,
     };
 
     #[derive(Clone, Copy, Debug, Default)]
@@ -232,18 +219,20 @@ pub mod decoder {
             self.get_buf().get_i64_at(self.offset + 4)
         }
 
-        /// primitive field - 'REQUIRED'
+        /// COMPOSITE DECODER
         #[inline]
         #[must_use]
-        pub fn price(&self) -> f32 {
-            self.get_buf().get_f32_at(self.offset + 12)
+        pub fn price_decoder(self) -> DecimalDecoder<Self> {
+            let offset = self.offset + 12;
+            DecimalDecoder::default().wrap(self, offset)
         }
 
-        /// primitive field - 'REQUIRED'
+        /// COMPOSITE DECODER
         #[inline]
         #[must_use]
-        pub fn volume(&self) -> f32 {
-            self.get_buf().get_f32_at(self.offset + 16)
+        pub fn volume_decoder(self) -> DecimalDecoder<Self> {
+            let offset = self.offset + 21;
+            DecimalDecoder::default().wrap(self, offset)
         }
     }
 } // end decoder
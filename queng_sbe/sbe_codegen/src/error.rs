@@ -0,0 +1,26 @@
+use std::fmt;
+
+/// `CodegenError` struct definition.
+///
+/// Used to represent errors while parsing the SBE schema or generating the bindings.
+///
+/// # Fields
+///
+/// `0` - Error message string
+///
+/// # Implements
+///
+/// `Clone`, `Debug`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Hash` - Rust defaults
+/// `fmt::Display` - Custom Display implementation to print error messages
+/// `std::error::Error` - Implements `std::error::Error` trait
+///
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CodegenError(pub String);
+
+impl fmt::Display for CodegenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CodegenError: {}", self.0)
+    }
+}
+
+impl std::error::Error for CodegenError {}
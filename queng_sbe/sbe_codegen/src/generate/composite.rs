@@ -0,0 +1,123 @@
+use crate::generate::fields::{decoder_fn, encoder_fn, enum_names};
+use crate::generate::names::{to_pascal_case, use_statement};
+use crate::schema::CompositeType;
+
+/// Generates the codec of a composite type such as the message header.
+///
+/// Composite codecs wrap a parent encoder or decoder and write to its buffer.
+pub(crate) fn generate_composite(composite: &CompositeType) -> String {
+    let name = to_pascal_case(&composite.name);
+    let enums = enum_names(&composite.fields);
+
+    let mut crate_items = vec![
+        "ReadBuf",
+        "Reader",
+        "SbeErr",
+        "SbeResult",
+        "WriteBuf",
+        "Writer",
+    ];
+    crate_items.extend(&enums);
+    let mut encoder_items = vec!["SbeErr", "SbeResult", "WriteBuf", "Writer"];
+    encoder_items.extend(&enums);
+    let mut decoder_items = vec!["ReadBuf", "Reader", "SbeErr", "SbeResult"];
+    decoder_items.extend(&enums);
+
+    let encoder_fns: String = composite
+        .fields
+        .iter()
+        .map(|field| format!("\n{}", encoder_fn(field)))
+        .collect();
+    let decoder_fns: String = composite
+        .fields
+        .iter()
+        .map(|field| format!("\n{}", decoder_fn(field, false)))
+        .collect();
+
+    format!(
+        "{crate_use}
+pub use decoder::{name}Decoder;
+pub use encoder::{name}Encoder;
+
+pub const ENCODED_LENGTH: usize = {encoded_length};
+
+pub mod encoder {{
+{encoder_use}
+    #[derive(Debug, Default)]
+    pub struct {name}Encoder<P> {{
+        parent: Option<P>,
+        offset: usize,
+    }}
+
+    impl<'a, P> Writer<'a> for {name}Encoder<P>
+    where
+        P: Writer<'a> + Default,
+    {{
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {{
+            if let Some(parent) = self.parent.as_mut() {{
+                parent.get_buf_mut()
+            }} else {{
+                panic!(\"parent was None\")
+            }}
+        }}
+    }}
+
+    impl<'a, P> {name}Encoder<P>
+    where
+        P: Writer<'a> + Default,
+    {{
+        pub fn wrap(mut self, parent: P, offset: usize) -> Self {{
+            self.parent = Some(parent);
+            self.offset = offset;
+            self
+        }}
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {{
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }}
+{encoder_fns}    }}
+}} // end encoder mod
+
+pub mod decoder {{
+{decoder_use}
+    #[derive(Debug, Default)]
+    pub struct {name}Decoder<P> {{
+        parent: Option<P>,
+        offset: usize,
+    }}
+
+    impl<'a, P> Reader<'a> for {name}Decoder<P>
+    where
+        P: Reader<'a> + Default,
+    {{
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {{
+            self.parent.as_ref().expect(\"parent missing\").get_buf()
+        }}
+    }}
+
+    impl<'a, P> {name}Decoder<P>
+    where
+        P: Reader<'a> + Default,
+    {{
+        pub fn wrap(mut self, parent: P, offset: usize) -> Self {{
+            self.parent = Some(parent);
+            self.offset = offset;
+            self
+        }}
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {{
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }}
+{decoder_fns}    }}
+}} // end decoder mod
+",
+        crate_use = use_statement(0, "crate", &crate_items),
+        encoder_use = use_statement(4, "super", &encoder_items),
+        decoder_use = use_statement(4, "super", &decoder_items),
+        encoded_length = composite.encoded_length(),
+    )
+}
@@ -0,0 +1,41 @@
+use crate::schema::EnumType;
+
+/// Generates the Rust enum of an SBE enum type.
+pub(crate) fn generate_enum(enum_type: &EnumType) -> String {
+    let name = &enum_type.name;
+    let rust_type = enum_type.encoding.rust_type();
+    let null_literal = enum_type
+        .encoding
+        .null_literal()
+        .unwrap_or_else(|| format!("0_{rust_type}"));
+
+    let mut out = String::new();
+    out.push_str("#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]\n");
+    out.push_str(&format!("#[repr({rust_type})]\n"));
+    out.push_str(&format!("pub enum {name} {{\n"));
+    for valid_value in &enum_type.values {
+        out.push_str(&format!(
+            "    {} = 0x{:x}_{rust_type},\n",
+            valid_value.name, valid_value.value
+        ));
+    }
+    out.push_str("    #[default]\n");
+    out.push_str(&format!("    NullVal = {null_literal},\n"));
+    out.push_str("}\n");
+
+    out.push_str(&format!("impl From<{rust_type}> for {name} {{\n"));
+    out.push_str("    #[inline]\n");
+    out.push_str(&format!("    fn from(v: {rust_type}) -> Self {{\n"));
+    out.push_str("        match v {\n");
+    for valid_value in &enum_type.values {
+        out.push_str(&format!(
+            "            0x{:x}_{rust_type} => Self::{},\n",
+            valid_value.value, valid_value.name
+        ));
+    }
+    out.push_str("            _ => Self::NullVal,\n");
+    out.push_str("        }\n");
+    out.push_str("    }\n");
+    out.push_str("}\n");
+    out
+}
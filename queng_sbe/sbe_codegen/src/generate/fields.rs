@@ -0,0 +1,207 @@
+use crate::generate::names::{to_pascal_case, to_snake_case};
+use crate::schema::{Field, FieldType, Presence};
+
+/// Returns the offset expression of a field relative to `self.offset`.
+fn offset_expr(field: &Field) -> String {
+    if field.offset == 0 {
+        "self.offset".to_string()
+    } else {
+        format!("self.offset + {}", field.offset)
+    }
+}
+
+/// Returns the doc line of a field added in a later schema version.
+fn since_version_doc(field: &Field) -> String {
+    if field.since_version == 0 {
+        String::new()
+    } else {
+        format!("        /// - sinceVersion: {}\n", field.since_version)
+    }
+}
+
+/// Returns the guard of a field added in a later schema version.
+///
+/// Messages from older producers carry an older version and a shorter block,
+/// so the field is not read and the null value is returned instead.
+fn since_version_guard(field: &Field, null_expr: &str) -> String {
+    if field.since_version == 0 {
+        String::new()
+    } else {
+        format!(
+            "            if self.acting_version < {} || (self.acting_block_length as usize) < {} {{
+                return {null_expr};
+            }}
+",
+            field.since_version,
+            field.end()
+        )
+    }
+}
+
+/// Generates the encoder setter of a field.
+pub(crate) fn encoder_fn(field: &Field) -> String {
+    let fn_name = to_snake_case(&field.name);
+    let offset = offset_expr(field);
+
+    match &field.field_type {
+        FieldType::Enum { name, encoding } => {
+            let presence = match field.presence {
+                Presence::Required => "REQUIRED",
+                Presence::Optional => "OPTIONAL",
+            };
+            let rust_type = encoding.rust_type();
+            let since_version_doc = since_version_doc(field);
+            format!(
+                "        /// {presence} enum
+{since_version_doc}        #[inline]
+        pub fn {fn_name}(&mut self, value: {name}) {{
+            let offset = {offset};
+            self.get_buf_mut().put_{rust_type}_at(offset, value as {rust_type});
+        }}
+"
+            )
+        }
+        FieldType::Primitive(primitive) => {
+            let rust_type = primitive.rust_type();
+            let semantic_type = field.semantic_type.as_deref().unwrap_or("null");
+            format!(
+                "        /// primitive field '{name}'
+        /// - min value: {min}
+        /// - max value: {max}
+        /// - null value: {null}
+        /// - characterEncoding: null
+        /// - semanticType: {semantic_type}
+        /// - encodedOffset: {encoded_offset}
+        /// - encodedLength: {encoded_length}
+{since_version_doc}        #[inline]
+        pub fn {fn_name}(&mut self, value: {rust_type}) {{
+            let offset = {offset};
+            self.get_buf_mut().put_{rust_type}_at(offset, value);
+        }}
+",
+                name = field.name,
+                min = primitive.min_value(),
+                max = primitive.max_value(),
+                null = primitive.null_value(),
+                encoded_offset = field.offset,
+                encoded_length = primitive.size(),
+                since_version_doc = since_version_doc(field),
+            )
+        }
+        FieldType::Composite { name, .. } => {
+            let name = to_pascal_case(name);
+            format!(
+                "        /// COMPOSITE ENCODER
+        #[inline]
+        pub fn {fn_name}_encoder(self) -> {name}Encoder<Self> {{
+            let offset = {offset};
+            {name}Encoder::default().wrap(self, offset)
+        }}
+"
+            )
+        }
+    }
+}
+
+/// Generates the decoder getter of a field.
+///
+/// Message decoders mark getters as `#[must_use]`, composite decoders do not.
+pub(crate) fn decoder_fn(field: &Field, must_use: bool) -> String {
+    let fn_name = to_snake_case(&field.name);
+    let offset = offset_expr(field);
+    let must_use = if must_use {
+        "        #[must_use]\n"
+    } else {
+        ""
+    };
+    let since_version_doc = since_version_doc(field);
+
+    match &field.field_type {
+        FieldType::Enum { name, encoding } => {
+            let presence = match field.presence {
+                Presence::Required => "REQUIRED",
+                Presence::Optional => "OPTIONAL",
+            };
+            let rust_type = encoding.rust_type();
+            let guard = since_version_guard(field, &format!("{name}::NullVal"));
+            format!(
+                "        /// {presence} enum
+{since_version_doc}        #[inline]
+{must_use}        pub fn {fn_name}(&self) -> {name} {{
+{guard}            self.get_buf().get_{rust_type}_at({offset}).into()
+        }}
+"
+            )
+        }
+        FieldType::Primitive(primitive) => {
+            let rust_type = primitive.rust_type();
+            match field.presence {
+                Presence::Required => format!(
+                    "        /// primitive field - 'REQUIRED'
+{since_version_doc}        #[inline]
+{must_use}        pub fn {fn_name}(&self) -> {rust_type} {{
+{guard}            self.get_buf().get_{rust_type}_at({offset})
+        }}
+",
+                    guard = since_version_guard(field, &primitive.null_expr()),
+                ),
+                Presence::Optional => {
+                    let is_null = primitive.null_literal().map_or_else(
+                        || "value.is_nan()".to_string(),
+                        |null_literal| format!("value == {null_literal}"),
+                    );
+                    format!(
+                        "        /// primitive field - 'OPTIONAL' {{ `null_value`: '{null}' }}
+{since_version_doc}        #[inline]
+{must_use}        pub fn {fn_name}(&self) -> Option<{rust_type}> {{
+{guard}            let value = self.get_buf().get_{rust_type}_at({offset});
+            if {is_null} {{
+                None
+            }} else {{
+                Some(value)
+            }}
+        }}
+",
+                        null = primitive.null_value(),
+                        guard = since_version_guard(field, "None"),
+                    )
+                }
+            }
+        }
+        FieldType::Composite { name, .. } => {
+            let name = to_pascal_case(name);
+            format!(
+                "        /// COMPOSITE DECODER
+        #[inline]
+{must_use}        pub fn {fn_name}_decoder(self) -> {name}Decoder<Self> {{
+            let offset = {offset};
+            {name}Decoder::default().wrap(self, offset)
+        }}
+"
+            )
+        }
+    }
+}
+
+/// Returns the names of all enums referenced by the fields.
+pub(crate) fn enum_names(fields: &[Field]) -> Vec<&str> {
+    fields
+        .iter()
+        .filter_map(|field| match &field.field_type {
+            FieldType::Enum { name, .. } => Some(name.as_str()),
+            FieldType::Primitive(_) | FieldType::Composite { .. } => None,
+        })
+        .collect()
+}
+
+/// Returns the codec type names of all composites referenced by the fields,
+/// such as `DecimalEncoder`, using the given suffix.
+pub(crate) fn composite_names(fields: &[Field], suffix: &str) -> Vec<String> {
+    fields
+        .iter()
+        .filter_map(|field| match &field.field_type {
+            FieldType::Composite { name, .. } => Some(format!("{}{suffix}", to_pascal_case(name))),
+            FieldType::Primitive(_) | FieldType::Enum { .. } => None,
+        })
+        .collect()
+}
@@ -0,0 +1,263 @@
+use crate::generate::fields::{decoder_fn, encoder_fn};
+use crate::generate::names::{to_pascal_case, to_snake_case};
+use crate::schema::Group;
+
+/// Encoded length of the dimension composite: `blockLength` and `numInGroup` as u16.
+const DIMENSION_LENGTH: usize = 4;
+
+/// Generates the message encoder function that starts a group.
+pub(crate) fn group_encoder_fn(group: &Group) -> String {
+    let fn_name = to_snake_case(&group.name);
+    let name = to_pascal_case(&group.name);
+    format!(
+        "        /// GROUP ENCODER (id={id})
+        #[inline]
+        #[must_use]
+        pub fn {fn_name}_encoder(self, count: u16) -> {name}Encoder<Self> {{
+            {name}Encoder::default().wrap(self, count)
+        }}
+",
+        id = group.id,
+    )
+}
+
+/// Generates the message decoder function that starts a group.
+pub(crate) fn group_decoder_fn(group: &Group) -> String {
+    let fn_name = to_snake_case(&group.name);
+    let name = to_pascal_case(&group.name);
+    format!(
+        "        /// GROUP DECODER (id={id})
+        #[inline]
+        #[must_use]
+        pub fn {fn_name}_decoder(self) -> {name}Decoder<Self> {{
+            {name}Decoder::default().wrap(self)
+        }}
+",
+        id = group.id,
+    )
+}
+
+/// Generates the encoder of a group.
+///
+/// The group encoder writes the dimension at the limit of its parent and moves
+/// the limit of the parent by one block per entry on `advance`.
+pub(crate) fn generate_group_encoder(group: &Group) -> String {
+    let name = to_pascal_case(&group.name);
+    let encoder_fns: String = group
+        .fields
+        .iter()
+        .map(|field| format!("\n{}", encoder_fn(field)))
+        .collect();
+
+    format!(
+        "
+    #[derive(Debug, Default)]
+    pub struct {name}Encoder<P> {{
+        parent: Option<P>,
+        count: u16,
+        index: usize,
+        offset: usize,
+        initial_limit: usize,
+    }}
+
+    impl<'a, P> Writer<'a> for {name}Encoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {{
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {{
+            if let Some(parent) = self.parent.as_mut() {{
+                parent.get_buf_mut()
+            }} else {{
+                panic!(\"parent was None\")
+            }}
+        }}
+    }}
+
+    impl<'a, P> Encoder<'a> for {name}Encoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {{
+        #[inline]
+        fn get_limit(&self) -> usize {{
+            self.parent.as_ref().expect(\"parent missing\").get_limit()
+        }}
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {{
+            self.parent
+                .as_mut()
+                .expect(\"parent missing\")
+                .set_limit(limit);
+        }}
+    }}
+
+    impl<'a, P> {name}Encoder<P>
+    where
+        P: Encoder<'a> + Default,
+    {{
+        #[inline]
+        #[must_use]
+        pub fn wrap(mut self, mut parent: P, count: u16) -> Self {{
+            let initial_limit = parent.get_limit();
+            parent.set_limit(initial_limit + {dimension_length});
+            parent
+                .get_buf_mut()
+                .put_u16_at(initial_limit, Self::block_length());
+            parent.get_buf_mut().put_u16_at(initial_limit + 2, count);
+            self.parent = Some(parent);
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = usize::MAX;
+            self.initial_limit = initial_limit;
+            self
+        }}
+
+        #[inline]
+        #[must_use]
+        pub const fn block_length() -> u16 {{
+            {block_length}
+        }}
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {{
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }}
+
+        /// Moves to the next entry of the group.
+        ///
+        /// Returns the index of the entry, or `None` once all entries are written.
+        #[inline]
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {{
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {{
+                return Ok(None);
+            }}
+
+            if let Some(parent) = self.parent.as_mut() {{
+                self.offset = parent.get_limit();
+                parent.set_limit(self.offset + Self::block_length() as usize);
+                self.index = index;
+                Ok(Some(index))
+            }} else {{
+                Err(SbeErr::ParentNotSet)
+            }}
+        }}
+{encoder_fns}    }}
+",
+        dimension_length = DIMENSION_LENGTH,
+        block_length = group.block_length,
+    )
+}
+
+/// Generates the decoder of a group.
+///
+/// The group decoder reads the dimension at the limit of its parent and moves
+/// the limit of the parent by the acting block length per entry on `advance`.
+pub(crate) fn generate_group_decoder(group: &Group) -> String {
+    let name = to_pascal_case(&group.name);
+    let decoder_fns: String = group
+        .fields
+        .iter()
+        .map(|field| format!("\n{}", decoder_fn(field, true)))
+        .collect();
+
+    format!(
+        "
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct {name}Decoder<P> {{
+        parent: Option<P>,
+        block_length: u16,
+        count: u16,
+        index: usize,
+        offset: usize,
+    }}
+
+    impl<'a, P> Reader<'a> for {name}Decoder<P>
+    where
+        P: Reader<'a> + Default,
+    {{
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {{
+            self.parent.as_ref().expect(\"parent missing\").get_buf()
+        }}
+    }}
+
+    impl<'a, P> Decoder<'a> for {name}Decoder<P>
+    where
+        P: Decoder<'a> + Default,
+    {{
+        #[inline]
+        fn get_limit(&self) -> usize {{
+            self.parent.as_ref().expect(\"parent missing\").get_limit()
+        }}
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {{
+            self.parent
+                .as_mut()
+                .expect(\"parent missing\")
+                .set_limit(limit);
+        }}
+    }}
+
+    impl<'a, P> {name}Decoder<P>
+    where
+        P: Decoder<'a> + Default,
+    {{
+        #[inline]
+        #[must_use]
+        pub fn wrap(mut self, mut parent: P) -> Self {{
+            let initial_limit = parent.get_limit();
+            let block_length = parent.get_buf().get_u16_at(initial_limit);
+            let count = parent.get_buf().get_u16_at(initial_limit + 2);
+            parent.set_limit(initial_limit + {dimension_length});
+            self.parent = Some(parent);
+            self.block_length = block_length;
+            self.count = count;
+            self.index = usize::MAX;
+            self.offset = 0;
+            self
+        }}
+
+        #[inline]
+        pub fn parent(&mut self) -> SbeResult<P> {{
+            self.parent.take().ok_or(SbeErr::ParentNotSet)
+        }}
+
+        #[inline]
+        #[must_use]
+        pub const fn acting_block_length(&self) -> u16 {{
+            self.block_length
+        }}
+
+        #[inline]
+        #[must_use]
+        pub const fn count(&self) -> u16 {{
+            self.count
+        }}
+
+        /// Moves to the next entry of the group.
+        ///
+        /// Returns the index of the entry, or `None` once all entries are read.
+        #[inline]
+        pub fn advance(&mut self) -> SbeResult<Option<usize>> {{
+            let index = self.index.wrapping_add(1);
+            if index >= self.count as usize {{
+                return Ok(None);
+            }}
+
+            if let Some(parent) = self.parent.as_mut() {{
+                self.offset = parent.get_limit();
+                parent.set_limit(self.offset + self.block_length as usize);
+                self.index = index;
+                Ok(Some(index))
+            }} else {{
+                Err(SbeErr::ParentNotSet)
+            }}
+        }}
+{decoder_fns}    }}
+",
+        dimension_length = DIMENSION_LENGTH,
+    )
+}
@@ -0,0 +1,256 @@
+use crate::generate::fields::{composite_names, decoder_fn, encoder_fn, enum_names};
+use crate::generate::group::{
+    generate_group_decoder, generate_group_encoder, group_decoder_fn, group_encoder_fn,
+};
+use crate::generate::names::{to_snake_case, use_statement};
+use crate::schema::{Field, Message, Schema};
+
+/// Generates the encoder and decoder of a message.
+pub(crate) fn generate_message(schema: &Schema, message: &Message) -> String {
+    let name = &message.name;
+    let all_fields: Vec<Field> = message
+        .fields
+        .iter()
+        .chain(message.groups.iter().flat_map(|group| &group.fields))
+        .cloned()
+        .collect();
+    let enums = enum_names(&all_fields);
+    let composite_encoders = composite_names(&all_fields, "Encoder");
+    let composite_decoders = composite_names(&all_fields, "Decoder");
+    let group_items: &[&str] = if message.groups.is_empty() {
+        &[]
+    } else {
+        &["SbeErr", "SbeResult"]
+    };
+
+    let mut crate_items = vec![
+        "message_header_codec",
+        "Decoder",
+        "Encoder",
+        "MessageHeaderDecoder",
+        "MessageHeaderEncoder",
+        "ReadBuf",
+        "Reader",
+        "WriteBuf",
+        "Writer",
+    ];
+    crate_items.extend(&enums);
+    crate_items.extend(composite_encoders.iter().map(String::as_str));
+    crate_items.extend(composite_decoders.iter().map(String::as_str));
+    crate_items.extend(group_items);
+
+    let mut encoder_items = vec![
+        "Encoder",
+        "MessageHeaderEncoder",
+        "WriteBuf",
+        "Writer",
+        "SBE_BLOCK_LENGTH",
+        "SBE_SCHEMA_ID",
+        "SBE_SCHEMA_VERSION",
+        "SBE_TEMPLATE_ID",
+    ];
+    encoder_items.extend(&enums);
+    encoder_items.extend(composite_encoders.iter().map(String::as_str));
+    encoder_items.extend(group_items);
+
+    let mut decoder_items = vec![
+        "message_header_codec",
+        "Decoder",
+        "MessageHeaderDecoder",
+        "ReadBuf",
+        "Reader",
+        "SBE_TEMPLATE_ID",
+    ];
+    decoder_items.extend(&enums);
+    decoder_items.extend(composite_decoders.iter().map(String::as_str));
+    decoder_items.extend(group_items);
+
+    let encoder_fns: String = message
+        .fields
+        .iter()
+        .map(|field| format!("\n{}", encoder_fn(field)))
+        .chain(
+            message
+                .groups
+                .iter()
+                .map(|group| format!("\n{}", group_encoder_fn(group))),
+        )
+        .collect();
+    let decoder_fns: String = message
+        .fields
+        .iter()
+        .map(|field| format!("\n{}", decoder_fn(field, true)))
+        .chain(
+            message
+                .groups
+                .iter()
+                .map(|group| format!("\n{}", group_decoder_fn(group))),
+        )
+        .collect();
+    let group_block_lengths: String = message
+        .groups
+        .iter()
+        .map(|group| {
+            format!(
+                "pub const SBE_{}_BLOCK_LENGTH: u16 = {};\n",
+                to_snake_case(&group.name).to_uppercase(),
+                group.block_length
+            )
+        })
+        .collect();
+    let group_encoders: String = message.groups.iter().map(generate_group_encoder).collect();
+    let group_decoders: String = message.groups.iter().map(generate_group_decoder).collect();
+
+    format!(
+        "{crate_use}
+pub use decoder::{name}Decoder;
+pub use encoder::{name}Encoder;
+
+pub const SBE_BLOCK_LENGTH: u16 = {block_length};
+pub const SBE_MIN_BLOCK_LENGTH: u16 = {min_block_length};
+pub const SBE_TEMPLATE_ID: u16 = {template_id};
+pub const SBE_SCHEMA_ID: u16 = {schema_id};
+pub const SBE_SCHEMA_VERSION: u16 = {schema_version};
+pub const SBE_SEMANTIC_VERSION: &str = \"{semantic_version}\";
+{group_block_lengths}
+pub mod encoder {{
+{encoder_use}
+    #[derive(Debug, Default)]
+    pub struct {name}Encoder<'a> {{
+        buf: WriteBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+    }}
+
+    impl<'a> Writer<'a> for {name}Encoder<'a> {{
+        #[inline]
+        fn get_buf_mut(&mut self) -> &mut WriteBuf<'a> {{
+            &mut self.buf
+        }}
+    }}
+
+    impl<'a> Encoder<'a> for {name}Encoder<'a> {{
+        #[inline]
+        fn get_limit(&self) -> usize {{
+            self.limit
+        }}
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {{
+            self.limit = limit;
+        }}
+    }}
+
+    impl<'a> {name}Encoder<'a> {{
+        #[must_use]
+        pub const fn wrap(mut self, buf: WriteBuf<'a>, offset: usize) -> Self {{
+            let limit = offset + SBE_BLOCK_LENGTH as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self
+        }}
+
+        #[inline]
+        #[must_use]
+        pub const fn encoded_length(&self) -> usize {{
+            self.limit - self.offset
+        }}
+
+        #[must_use]
+        pub fn header(self, offset: usize) -> MessageHeaderEncoder<Self> {{
+            let mut header = MessageHeaderEncoder::default().wrap(self, offset);
+            header.block_length(SBE_BLOCK_LENGTH);
+            header.template_id(SBE_TEMPLATE_ID);
+            header.schema_id(SBE_SCHEMA_ID);
+            header.version(SBE_SCHEMA_VERSION);
+            header
+        }}
+{encoder_fns}    }}
+{group_encoders}}} // end encoder
+
+pub mod decoder {{
+{decoder_use}
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct {name}Decoder<'a> {{
+        buf: ReadBuf<'a>,
+        initial_offset: usize,
+        offset: usize,
+        limit: usize,
+        pub acting_block_length: u16,
+        pub acting_version: u16,
+    }}
+
+    impl<'a> Reader<'a> for {name}Decoder<'a> {{
+        #[inline]
+        fn get_buf(&self) -> &ReadBuf<'a> {{
+            &self.buf
+        }}
+    }}
+
+    impl<'a> Decoder<'a> for {name}Decoder<'a> {{
+        #[inline]
+        fn get_limit(&self) -> usize {{
+            self.limit
+        }}
+
+        #[inline]
+        fn set_limit(&mut self, limit: usize) {{
+            self.limit = limit;
+        }}
+    }}
+
+    impl<'a> {name}Decoder<'a> {{
+        #[must_use]
+        pub const fn wrap(
+            mut self,
+            buf: ReadBuf<'a>,
+            offset: usize,
+            acting_block_length: u16,
+            acting_version: u16,
+        ) -> Self {{
+            let limit = offset + acting_block_length as usize;
+            self.buf = buf;
+            self.initial_offset = offset;
+            self.offset = offset;
+            self.limit = limit;
+            self.acting_block_length = acting_block_length;
+            self.acting_version = acting_version;
+            self
+        }}
+
+        #[inline]
+        #[must_use]
+        pub const fn encoded_length(&self) -> usize {{
+            self.limit - self.offset
+        }}
+
+        #[must_use]
+        pub fn header(self, mut header: MessageHeaderDecoder<ReadBuf<'a>>) -> Self {{
+            debug_assert_eq!(SBE_TEMPLATE_ID, header.template_id());
+            let acting_block_length = header.block_length();
+            let acting_version = header.version();
+
+            self.wrap(
+                header.parent().unwrap(),
+                message_header_codec::ENCODED_LENGTH,
+                acting_block_length,
+                acting_version,
+            )
+        }}
+{decoder_fns}    }}
+{group_decoders}}} // end decoder
+",
+        crate_use = use_statement(0, "crate", &crate_items),
+        encoder_use = use_statement(4, "super", &encoder_items),
+        decoder_use = use_statement(4, "super", &decoder_items),
+        block_length = message.block_length,
+        min_block_length = message.min_block_length(),
+        template_id = message.id,
+        schema_id = schema.id,
+        schema_version = schema.version,
+        semantic_version = schema.semantic_version,
+    )
+}
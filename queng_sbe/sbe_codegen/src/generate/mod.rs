@@ -0,0 +1,69 @@
+use crate::schema::Schema;
+
+mod composite;
+mod enums;
+mod fields;
+mod group;
+mod lib_rs;
+mod message;
+mod names;
+
+use names::to_snake_case;
+
+/// A generated source file of the bindings crate.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GeneratedFile {
+    /// File name relative to the `src` directory of the bindings crate.
+    pub name: String,
+    /// Generated source code.
+    pub content: String,
+}
+
+/// Generates all source files of the bindings crate from a parsed schema.
+///
+/// # Returns
+///
+/// One file per enum, one codec per composite and message, and the `lib.rs`
+/// declaring all of them.
+#[must_use]
+pub fn generate(schema: &Schema) -> Vec<GeneratedFile> {
+    let mut files = Vec::new();
+
+    files.push(GeneratedFile {
+        name: format!("{}_codec.rs", to_snake_case(&schema.header.name)),
+        content: composite::generate_composite(&schema.header),
+    });
+
+    for enum_type in &schema.enums {
+        files.push(GeneratedFile {
+            name: format!("{}.rs", to_snake_case(&enum_type.name)),
+            content: enums::generate_enum(enum_type),
+        });
+    }
+
+    for composite in &schema.composites {
+        files.push(GeneratedFile {
+            name: format!("{}_codec.rs", to_snake_case(&composite.name)),
+            content: composite::generate_composite(composite),
+        });
+    }
+
+    for message in &schema.messages {
+        files.push(GeneratedFile {
+            name: format!("{}_codec.rs", to_snake_case(&message.name)),
+            content: message::generate_message(schema, message),
+        });
+    }
+
+    let modules: Vec<String> = files
+        .iter()
+        .map(|file| file.name.trim_end_matches(".rs").to_string())
+        .collect();
+
+    files.push(GeneratedFile {
+        name: "lib.rs".to_string(),
+        content: lib_rs::generate_lib(&modules),
+    });
+
+    files
+}
@@ -0,0 +1,82 @@
+/// Maximum line width used by rustfmt.
+const MAX_WIDTH: usize = 100;
+
+/// Converts an SBE name such as `clientID` or `StartDataMsg` into `snake_case`.
+pub(crate) fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::with_capacity(name.len() + 4);
+
+    for (index, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && index > 0 {
+            let prev = chars[index - 1];
+            let next_is_lower = chars.get(index + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+
+    snake
+}
+
+/// Converts an SBE name such as `messageHeader` into `PascalCase`.
+pub(crate) fn to_pascal_case(name: &str) -> String {
+    let mut chars = name.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+/// Orders use items the way rustfmt does: `snake_case`, then `CamelCase`, then `UPPER_CASE`.
+fn use_item_order(item: &str) -> (u8, &str) {
+    let group = if item.chars().all(|c| !c.is_uppercase()) {
+        0
+    } else if item.chars().all(|c| !c.is_lowercase()) {
+        2
+    } else {
+        1
+    };
+    (group, item)
+}
+
+/// Formats a `use` statement with a braced item list as rustfmt would.
+pub(crate) fn use_statement(indent: usize, path: &str, items: &[&str]) -> String {
+    let mut items = items.to_vec();
+    items.sort_by_key(|item| use_item_order(item));
+    items.dedup();
+
+    let pad = " ".repeat(indent);
+    if let [item] = items.as_slice() {
+        return format!("{pad}use {path}::{item};\n");
+    }
+
+    let single_line = format!("{pad}use {path}::{{{}}};", items.join(", "));
+    if single_line.len() <= MAX_WIDTH {
+        return format!("{single_line}\n");
+    }
+
+    let item_pad = " ".repeat(indent + 4);
+    let mut out = format!("{pad}use {path}::{{\n");
+    let mut line = String::new();
+    for item in items {
+        if line.is_empty() {
+            line = format!("{item_pad}{item},");
+        } else if line.len() + 1 + item.len() + 1 < MAX_WIDTH {
+            line.push(' ');
+            line.push_str(item);
+            line.push(',');
+        } else {
+            out.push_str(&line);
+            out.push('\n');
+            line = format!("{item_pad}{item},");
+        }
+    }
+    out.push_str(&line);
+    out.push('\n');
+    out.push_str(&format!("{pad}}};\n"));
+    out
+}
@@ -0,0 +1,81 @@
+//! Generates the `sbe_bindings` crate from the SBE XML schema.
+//!
+//! The generator is written in pure Rust so the bindings can be regenerated
+//! and checked without the Java based SBE tool.
+use std::path::Path;
+
+mod error;
+mod generate;
+pub mod schema;
+pub mod xml;
+
+pub use crate::error::CodegenError;
+pub use crate::generate::{generate, GeneratedFile};
+pub use crate::schema::Schema;
+
+/// Parses the schema file and generates the bindings.
+///
+/// # Errors
+///
+/// Returns `CodegenError` if the schema cannot be read or parsed.
+pub fn generate_from_file(schema_path: &Path) -> Result<Vec<GeneratedFile>, CodegenError> {
+    let schema = Schema::from_file(schema_path)?;
+    Ok(generate(&schema))
+}
+
+/// Generates the bindings and writes them into the given `src` directory.
+///
+/// # Returns
+///
+/// The names of all written files.
+///
+/// # Errors
+///
+/// Returns `CodegenError` if the schema is invalid or a file cannot be written.
+pub fn write_bindings(schema_path: &Path, src_dir: &Path) -> Result<Vec<String>, CodegenError> {
+    let files = generate_from_file(schema_path)?;
+
+    for file in &files {
+        let path = src_dir.join(&file.name);
+        std::fs::write(&path, &file.content)
+            .map_err(|e| CodegenError(format!("Failed to write {}: {e}", path.display())))?;
+    }
+
+    Ok(files.into_iter().map(|file| file.name).collect())
+}
+
+/// Checks that the committed bindings match the schema.
+///
+/// # Returns
+///
+/// The names of all files that are missing, differ from the generated code,
+/// or are not generated from the schema. Empty if the bindings are up to date.
+///
+/// # Errors
+///
+/// Returns `CodegenError` if the schema is invalid or the `src` directory cannot be read.
+pub fn check_bindings(schema_path: &Path, src_dir: &Path) -> Result<Vec<String>, CodegenError> {
+    let files = generate_from_file(schema_path)?;
+
+    let mut outdated: Vec<String> = files
+        .iter()
+        .filter(|file| {
+            std::fs::read_to_string(src_dir.join(&file.name))
+                .map_or(true, |committed| committed != file.content)
+        })
+        .map(|file| file.name.clone())
+        .collect();
+
+    let entries = std::fs::read_dir(src_dir)
+        .map_err(|e| CodegenError(format!("Failed to read {}: {e}", src_dir.display())))?;
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let is_rust_file = Path::new(&name).extension().is_some_and(|ext| ext == "rs");
+        if is_rust_file && !files.iter().any(|file| file.name == name) {
+            outdated.push(name);
+        }
+    }
+
+    outdated.sort();
+    Ok(outdated)
+}
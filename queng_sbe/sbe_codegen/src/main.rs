@@ -0,0 +1,43 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const SCHEMA_PATH: &str = "../sbe_bindings/schema/sbe_schema.xml";
+const BINDINGS_SRC_DIR: &str = "../sbe_bindings/src";
+
+fn main() -> ExitCode {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let schema_path = manifest_dir.join(SCHEMA_PATH);
+    let src_dir = manifest_dir.join(BINDINGS_SRC_DIR);
+
+    match std::env::args().nth(1).as_deref() {
+        Some("generate") => match sbe_codegen::write_bindings(&schema_path, &src_dir) {
+            Ok(files) => {
+                println!("[sbe_codegen]: Generated {} files", files.len());
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("[sbe_codegen]: {e}");
+                ExitCode::FAILURE
+            }
+        },
+        Some("check") => match sbe_codegen::check_bindings(&schema_path, &src_dir) {
+            Ok(outdated) if outdated.is_empty() => {
+                println!("[sbe_codegen]: Bindings match the schema");
+                ExitCode::SUCCESS
+            }
+            Ok(outdated) => {
+                eprintln!("[sbe_codegen]: Bindings do not match the schema: {outdated:?}");
+                eprintln!("[sbe_codegen]: Run `cargo run -p sbe_codegen -- generate`");
+                ExitCode::FAILURE
+            }
+            Err(e) => {
+                eprintln!("[sbe_codegen]: {e}");
+                ExitCode::FAILURE
+            }
+        },
+        _ => {
+            eprintln!("Usage: cargo run -p sbe_codegen -- <generate|check>");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,605 @@
+use crate::xml::{self, Element};
+use crate::CodegenError;
+use std::path::Path;
+
+/// SBE primitive types supported by the generator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PrimitiveType {
+    Char,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    Float,
+    Double,
+}
+
+impl PrimitiveType {
+    /// Returns the primitive type for an SBE type name such as `uint16`.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "char" => Some(Self::Char),
+            "int8" => Some(Self::Int8),
+            "int16" => Some(Self::Int16),
+            "int32" => Some(Self::Int32),
+            "int64" => Some(Self::Int64),
+            "uint8" => Some(Self::UInt8),
+            "uint16" => Some(Self::UInt16),
+            "uint32" => Some(Self::UInt32),
+            "uint64" => Some(Self::UInt64),
+            "float" => Some(Self::Float),
+            "double" => Some(Self::Double),
+            _ => None,
+        }
+    }
+
+    /// Returns the encoded size in bytes.
+    #[must_use]
+    pub const fn size(self) -> usize {
+        match self {
+            Self::Char | Self::Int8 | Self::UInt8 => 1,
+            Self::Int16 | Self::UInt16 => 2,
+            Self::Int32 | Self::UInt32 | Self::Float => 4,
+            Self::Int64 | Self::UInt64 | Self::Double => 8,
+        }
+    }
+
+    /// Returns the Rust type the primitive type maps to.
+    #[must_use]
+    pub const fn rust_type(self) -> &'static str {
+        match self {
+            Self::Char | Self::UInt8 => "u8",
+            Self::Int8 => "i8",
+            Self::Int16 => "i16",
+            Self::Int32 => "i32",
+            Self::Int64 => "i64",
+            Self::UInt16 => "u16",
+            Self::UInt32 => "u32",
+            Self::UInt64 => "u64",
+            Self::Float => "f32",
+            Self::Double => "f64",
+        }
+    }
+
+    /// Returns the SBE default minimum value as printed in field docs.
+    #[must_use]
+    pub const fn min_value(self) -> &'static str {
+        match self {
+            Self::Char => "32",
+            Self::Int8 => "-127",
+            Self::Int16 => "-32767",
+            Self::Int32 => "-2147483647",
+            Self::Int64 => "-9223372036854775807",
+            Self::UInt8 | Self::UInt16 | Self::UInt32 | Self::UInt64 => "0",
+            Self::Float => "1.401298464324817E-45",
+            Self::Double => "4.9E-324",
+        }
+    }
+
+    /// Returns the SBE default maximum value as printed in field docs.
+    #[must_use]
+    pub const fn max_value(self) -> &'static str {
+        match self {
+            Self::Char => "126",
+            Self::Int8 => "127",
+            Self::Int16 => "32767",
+            Self::Int32 => "2147483647",
+            Self::Int64 => "9223372036854775807",
+            Self::UInt8 => "254",
+            Self::UInt16 => "65534",
+            Self::UInt32 => "4294967294",
+            Self::UInt64 => "18446744073709551614",
+            Self::Float => "3.4028234663852886E38",
+            Self::Double => "1.7976931348623157E308",
+        }
+    }
+
+    /// Returns the SBE default null value as printed in field docs.
+    #[must_use]
+    pub const fn null_value(self) -> &'static str {
+        match self {
+            Self::Char => "0",
+            Self::Int8 => "-128",
+            Self::Int16 => "-32768",
+            Self::Int32 => "-2147483648",
+            Self::Int64 => "-9223372036854775808",
+            Self::UInt8 => "255",
+            Self::UInt16 => "65535",
+            Self::UInt32 => "4294967295",
+            Self::UInt64 => "18446744073709551615",
+            Self::Float | Self::Double => "NaN",
+        }
+    }
+
+    /// Returns the null value as Rust expression.
+    #[must_use]
+    pub fn null_expr(self) -> String {
+        self.null_literal()
+            .unwrap_or_else(|| format!("{}::NAN", self.rust_type()))
+    }
+
+    /// Returns the null value as Rust literal, or `None` for floating point types
+    /// where null is NaN and must be tested with `is_nan`.
+    #[must_use]
+    pub fn null_literal(self) -> Option<String> {
+        let rust_type = self.rust_type();
+        match self {
+            Self::Float | Self::Double => None,
+            Self::Char | Self::UInt8 | Self::UInt16 | Self::UInt32 | Self::UInt64 => {
+                let value: u64 = self.null_value().parse().unwrap_or_default();
+                Some(format!("0x{value:x}_{rust_type}"))
+            }
+            Self::Int8 | Self::Int16 | Self::Int32 | Self::Int64 => {
+                Some(format!("{}_{rust_type}", self.null_value()))
+            }
+        }
+    }
+}
+
+/// Presence of a field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Presence {
+    #[default]
+    Required,
+    Optional,
+}
+
+/// Type of a field: a primitive, an enum with its encoding type,
+/// or a composite with its encoded length.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FieldType {
+    Primitive(PrimitiveType),
+    Enum {
+        name: String,
+        encoding: PrimitiveType,
+    },
+    Composite {
+        name: String,
+        encoded_length: usize,
+    },
+}
+
+impl FieldType {
+    /// Returns the encoded size in bytes.
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        match self {
+            Self::Primitive(primitive) => primitive.size(),
+            Self::Enum { encoding, .. } => encoding.size(),
+            Self::Composite { encoded_length, .. } => *encoded_length,
+        }
+    }
+}
+
+/// A field of a message or a member of a composite.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Field {
+    pub name: String,
+    pub id: u16,
+    pub field_type: FieldType,
+    pub presence: Presence,
+    pub offset: usize,
+    pub since_version: u16,
+    pub semantic_type: Option<String>,
+}
+
+impl Field {
+    /// Returns the offset of the first byte after the field.
+    #[must_use]
+    pub const fn end(&self) -> usize {
+        self.offset + self.field_type.size()
+    }
+}
+
+/// A valid value of an enum.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ValidValue {
+    pub name: String,
+    pub value: u64,
+}
+
+/// An SBE enum type.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EnumType {
+    pub name: String,
+    pub encoding: PrimitiveType,
+    pub values: Vec<ValidValue>,
+}
+
+/// An SBE composite type.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CompositeType {
+    pub name: String,
+    pub fields: Vec<Field>,
+}
+
+impl CompositeType {
+    /// Returns the encoded length of the composite in bytes.
+    #[must_use]
+    pub fn encoded_length(&self) -> usize {
+        self.fields.iter().map(Field::end).max().unwrap_or_default()
+    }
+}
+
+/// A repeating group of a message.
+///
+/// Each entry of the group is a block of `block_length` bytes. The entries follow
+/// the dimension composite, which holds the block length and the number of entries.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Group {
+    pub name: String,
+    pub id: u16,
+    pub block_length: u16,
+    pub fields: Vec<Field>,
+}
+
+/// An SBE message.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Message {
+    pub name: String,
+    pub id: u16,
+    pub block_length: u16,
+    pub fields: Vec<Field>,
+    pub groups: Vec<Group>,
+}
+
+impl Message {
+    /// Returns the block length of all fields present since the first version.
+    ///
+    /// Fields added with `sinceVersion` are optional to a decoder, so a message
+    /// from an older producer only needs to carry this many bytes.
+    #[must_use]
+    pub fn min_block_length(&self) -> usize {
+        self.fields
+            .iter()
+            .filter(|field| field.since_version == 0)
+            .map(Field::end)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+/// A parsed SBE message schema.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Schema {
+    pub package: String,
+    pub id: u16,
+    pub version: u16,
+    pub semantic_version: String,
+    pub header: CompositeType,
+    pub enums: Vec<EnumType>,
+    pub composites: Vec<CompositeType>,
+    pub messages: Vec<Message>,
+}
+
+impl Schema {
+    /// Reads and parses an SBE schema file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CodegenError` if the file cannot be read or the schema is invalid.
+    pub fn from_file(path: &Path) -> Result<Self, CodegenError> {
+        let xml = std::fs::read_to_string(path)
+            .map_err(|e| CodegenError(format!("Failed to read schema {}: {e}", path.display())))?;
+        Self::parse(&xml)
+    }
+
+    /// Parses an SBE schema from its XML source.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CodegenError` if the XML is malformed, a type is unknown,
+    /// or a required attribute is missing.
+    pub fn parse(xml: &str) -> Result<Self, CodegenError> {
+        let root = xml::parse(xml)?;
+        if root.local_name() != "messageSchema" {
+            return Err(CodegenError(format!(
+                "Expected <messageSchema> as root element but found <{}>",
+                root.name
+            )));
+        }
+
+        let package = root.attribute("package").unwrap_or_default().to_string();
+        let id = parse_number(&root, "id")?;
+        let version = root
+            .attribute("version")
+            .map_or(Ok(0), |_| parse_number(&root, "version"))?;
+        let semantic_version = root
+            .attribute("semanticVersion")
+            .unwrap_or_default()
+            .to_string();
+
+        let mut enums = Vec::new();
+        let mut composites = Vec::new();
+        for types in root.children_named("types") {
+            for enum_element in types.children_named("enum") {
+                enums.push(parse_enum(enum_element)?);
+            }
+        }
+        for types in root.children_named("types") {
+            for composite in types.children_named("composite") {
+                composites.push(parse_composite(composite, &enums)?);
+            }
+        }
+
+        let header_name = root.attribute("headerType").unwrap_or("messageHeader");
+        let header_index = composites
+            .iter()
+            .position(|composite| composite.name == header_name)
+            .ok_or_else(|| CodegenError(format!("Missing <composite name=\"{header_name}\">")))?;
+        let header = composites.remove(header_index);
+
+        let messages = root
+            .children_named("message")
+            .map(|message| parse_message(message, &enums, &composites))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            package,
+            id,
+            version,
+            semantic_version,
+            header,
+            enums,
+            composites,
+            messages,
+        })
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(element: &Element, name: &str) -> Result<T, CodegenError> {
+    let value = element.required_attribute(name)?;
+    value.trim().parse().map_err(|_| {
+        CodegenError(format!(
+            "<{}>: attribute `{name}` has the invalid value `{value}`",
+            element.name
+        ))
+    })
+}
+
+fn parse_enum(element: &Element) -> Result<EnumType, CodegenError> {
+    let name = element.required_attribute("name")?.to_string();
+    let encoding_name = element.required_attribute("encodingType")?;
+    let encoding = PrimitiveType::from_name(encoding_name).ok_or_else(|| {
+        CodegenError(format!(
+            "Enum {name}: unsupported encoding type `{encoding_name}`"
+        ))
+    })?;
+
+    let values = element
+        .children_named("validValue")
+        .map(|valid_value| {
+            let value_name = valid_value.required_attribute("name")?.to_string();
+            let value = valid_value.text.parse().map_err(|_| {
+                CodegenError(format!(
+                    "Enum {name}: value {value_name} has the invalid value `{}`",
+                    valid_value.text
+                ))
+            })?;
+            Ok(ValidValue {
+                name: value_name,
+                value,
+            })
+        })
+        .collect::<Result<Vec<_>, CodegenError>>()?;
+
+    Ok(EnumType {
+        name,
+        encoding,
+        values,
+    })
+}
+
+fn parse_composite(element: &Element, enums: &[EnumType]) -> Result<CompositeType, CodegenError> {
+    let name = element.required_attribute("name")?.to_string();
+
+    let mut fields = Vec::new();
+    let mut offset = 0;
+    for member in &element.children {
+        let type_name = match member.local_name() {
+            "type" => member.required_attribute("primitiveType")?,
+            "ref" => member.required_attribute("type")?,
+            other => {
+                return Err(CodegenError(format!(
+                    "Composite {name}: unsupported member <{other}>"
+                )))
+            }
+        };
+        let field = parse_field(member, type_name, offset, enums, &[])?;
+        if field.since_version != 0 {
+            return Err(CodegenError(format!(
+                "Composite {name}: sinceVersion is only supported on message fields"
+            )));
+        }
+        offset = field.end();
+        fields.push(field);
+    }
+
+    Ok(CompositeType { name, fields })
+}
+
+fn parse_message(
+    element: &Element,
+    enums: &[EnumType],
+    composites: &[CompositeType],
+) -> Result<Message, CodegenError> {
+    let name = element.required_attribute("name")?.to_string();
+    let id = parse_number(element, "id")?;
+
+    let mut fields = Vec::new();
+    let mut groups = Vec::new();
+    let mut offset = 0;
+    for child in &element.children {
+        match child.local_name() {
+            "field" if groups.is_empty() => {
+                let type_name = child.required_attribute("type")?;
+                let field = parse_field(child, type_name, offset, enums, composites)?;
+                let is_composite = matches!(field.field_type, FieldType::Composite { .. });
+                if field.since_version != 0 && is_composite {
+                    return Err(CodegenError(format!(
+                        "Message {name}: sinceVersion is not supported on the composite field {}",
+                        field.name
+                    )));
+                }
+                offset = field.end();
+                fields.push(field);
+            }
+            "field" => {
+                return Err(CodegenError(format!(
+                    "Message {name}: fields must precede all groups"
+                )))
+            }
+            "group" => groups.push(parse_group(child, enums, composites)?),
+            other => {
+                return Err(CodegenError(format!(
+                    "Message {name}: unsupported member <{other}>"
+                )))
+            }
+        }
+    }
+
+    let block_length = parse_block_length(element, &name, offset)?;
+
+    Ok(Message {
+        name,
+        id,
+        block_length,
+        fields,
+        groups,
+    })
+}
+
+fn parse_group(
+    element: &Element,
+    enums: &[EnumType],
+    composites: &[CompositeType],
+) -> Result<Group, CodegenError> {
+    let name = element.required_attribute("name")?.to_string();
+    let id = parse_number(element, "id")?;
+
+    let dimension_name = element
+        .attribute("dimensionType")
+        .unwrap_or("groupSizeEncoding");
+    let dimension = composites
+        .iter()
+        .find(|composite| composite.name == dimension_name)
+        .ok_or_else(|| {
+            CodegenError(format!(
+                "Group {name}: missing <composite name=\"{dimension_name}\">"
+            ))
+        })?;
+    let is_u16_at = |field: Option<&Field>, offset: usize| {
+        field.is_some_and(|field| {
+            field.field_type == FieldType::Primitive(PrimitiveType::UInt16)
+                && field.offset == offset
+        })
+    };
+    if !is_u16_at(dimension.fields.first(), 0) || !is_u16_at(dimension.fields.get(1), 2) {
+        return Err(CodegenError(format!(
+            "Group {name}: dimension {dimension_name} must hold blockLength and numInGroup as uint16"
+        )));
+    }
+
+    let mut fields = Vec::new();
+    let mut offset = 0;
+    for child in &element.children {
+        if child.local_name() != "field" {
+            return Err(CodegenError(format!(
+                "Group {name}: unsupported member <{}>",
+                child.name
+            )));
+        }
+        let type_name = child.required_attribute("type")?;
+        let field = parse_field(child, type_name, offset, enums, composites)?;
+        if field.since_version != 0 {
+            return Err(CodegenError(format!(
+                "Group {name}: sinceVersion is only supported on message fields"
+            )));
+        }
+        offset = field.end();
+        fields.push(field);
+    }
+
+    let block_length = parse_block_length(element, &name, offset)?;
+
+    Ok(Group {
+        name,
+        id,
+        block_length,
+        fields,
+    })
+}
+
+fn parse_block_length(element: &Element, name: &str, offset: usize) -> Result<u16, CodegenError> {
+    match element.attribute("blockLength") {
+        Some(_) => parse_number(element, "blockLength"),
+        None => u16::try_from(offset)
+            .map_err(|_| CodegenError(format!("{name}: block length {offset} exceeds u16"))),
+    }
+}
+
+fn parse_field(
+    element: &Element,
+    type_name: &str,
+    offset: usize,
+    enums: &[EnumType],
+    composites: &[CompositeType],
+) -> Result<Field, CodegenError> {
+    let name = element.required_attribute("name")?.to_string();
+
+    let field_type = if let Some(primitive) = PrimitiveType::from_name(type_name) {
+        FieldType::Primitive(primitive)
+    } else if let Some(enum_type) = enums.iter().find(|e| e.name == type_name) {
+        FieldType::Enum {
+            name: enum_type.name.clone(),
+            encoding: enum_type.encoding,
+        }
+    } else if let Some(composite) = composites.iter().find(|c| c.name == type_name) {
+        FieldType::Composite {
+            name: composite.name.clone(),
+            encoded_length: composite.encoded_length(),
+        }
+    } else {
+        return Err(CodegenError(format!(
+            "Field {name}: unknown type `{type_name}`"
+        )));
+    };
+
+    let presence = match element.attribute("presence") {
+        None | Some("required") => Presence::Required,
+        Some("optional") => Presence::Optional,
+        Some(other) => {
+            return Err(CodegenError(format!(
+                "Field {name}: unsupported presence `{other}`"
+            )))
+        }
+    };
+
+    let id = match element.attribute("id") {
+        Some(_) => parse_number(element, "id")?,
+        None => 0,
+    };
+    let offset = match element.attribute("offset") {
+        Some(_) => parse_number(element, "offset")?,
+        None => offset,
+    };
+    let since_version = match element.attribute("sinceVersion") {
+        Some(_) => parse_number(element, "sinceVersion")?,
+        None => 0,
+    };
+
+    Ok(Field {
+        name,
+        id,
+        field_type,
+        presence,
+        offset,
+        since_version,
+        semantic_type: element.attribute("semanticType").map(str::to_string),
+    })
+}
@@ -0,0 +1,226 @@
+use crate::CodegenError;
+
+/// A parsed XML element.
+///
+/// Only covers the subset of XML used by SBE schemas:
+/// elements, attributes, text content, comments and processing instructions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Element>,
+    pub text: String,
+}
+
+impl Element {
+    /// Returns the element name without its namespace prefix.
+    #[must_use]
+    pub fn local_name(&self) -> &str {
+        self.name.rsplit(':').next().unwrap_or(&self.name)
+    }
+
+    /// Returns the value of the attribute with the given name, if present.
+    #[must_use]
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the value of the attribute with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CodegenError` if the attribute is missing.
+    pub fn required_attribute(&self, name: &str) -> Result<&str, CodegenError> {
+        self.attribute(name).ok_or_else(|| {
+            CodegenError(format!(
+                "<{}> is missing the required attribute `{name}`",
+                self.name
+            ))
+        })
+    }
+
+    /// Returns all child elements with the given local name.
+    pub fn children_named<'a>(&'a self, local_name: &'a str) -> impl Iterator<Item = &'a Self> {
+        self.children
+            .iter()
+            .filter(move |child| child.local_name() == local_name)
+    }
+}
+
+/// Parses an XML document and returns its root element.
+///
+/// # Errors
+///
+/// Returns `CodegenError` with the line number if the document is malformed.
+pub fn parse(input: &str) -> Result<Element, CodegenError> {
+    let mut parser = Parser { input, pos: 0 };
+
+    parser.skip_misc()?;
+    let root = parser.element()?;
+    parser.skip_misc()?;
+
+    if parser.rest().is_empty() {
+        Ok(root)
+    } else {
+        Err(parser.error("unexpected content after the root element"))
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn error(&self, msg: &str) -> CodegenError {
+        let line = self.input[..self.pos].matches('\n').count() + 1;
+        CodegenError(format!("XML line {line}: {msg}"))
+    }
+
+    fn skip_whitespace(&mut self) {
+        let trimmed = self.rest().trim_start();
+        self.pos = self.input.len() - trimmed.len();
+    }
+
+    fn skip_past(&mut self, end: &str) -> Result<(), CodegenError> {
+        match self.rest().find(end) {
+            Some(index) => {
+                self.pos += index + end.len();
+                Ok(())
+            }
+            None => Err(self.error(&format!("expected `{end}`"))),
+        }
+    }
+
+    /// Skips whitespace, comments, processing instructions and doctype declarations.
+    fn skip_misc(&mut self) -> Result<(), CodegenError> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if self.rest().starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if self.rest().starts_with("<!") {
+                self.skip_past(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), CodegenError> {
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected `{token}`")))
+        }
+    }
+
+    fn name(&mut self) -> Result<&'a str, CodegenError> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '-' | ':' | '.')))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("expected a name"));
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    fn attribute_value(&mut self) -> Result<String, CodegenError> {
+        let quote = match self.rest().chars().next() {
+            Some(quote @ ('"' | '\'')) => quote,
+            _ => return Err(self.error("expected a quoted attribute value")),
+        };
+        self.pos += 1;
+
+        let rest = self.rest();
+        let Some(len) = rest.find(quote) else {
+            return Err(self.error("unterminated attribute value"));
+        };
+        self.pos += len + 1;
+        Ok(unescape(&rest[..len]))
+    }
+
+    fn element(&mut self) -> Result<Element, CodegenError> {
+        self.expect("<")?;
+        let name = self.name()?.to_string();
+
+        let mut attributes = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.pos += 2;
+                return Ok(Element {
+                    name,
+                    attributes,
+                    ..Default::default()
+                });
+            }
+            if self.rest().starts_with('>') {
+                self.pos += 1;
+                break;
+            }
+
+            let key = self.name()?.to_string();
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let value = self.attribute_value()?;
+            attributes.push((key, value));
+        }
+
+        let mut children = Vec::new();
+        let mut text = String::new();
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                self.pos += 2;
+                let end_name = self.name()?;
+                if end_name != name {
+                    return Err(
+                        self.error(&format!("expected `</{name}>` but found `</{end_name}>`"))
+                    );
+                }
+                self.skip_whitespace();
+                self.expect(">")?;
+                break;
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with('<') {
+                children.push(self.element()?);
+            } else if rest.is_empty() {
+                return Err(self.error(&format!("unexpected end of input in <{name}>")));
+            } else {
+                let len = rest.find('<').unwrap_or(rest.len());
+                text.push_str(&unescape(&rest[..len]));
+                self.pos += len;
+            }
+        }
+
+        Ok(Element {
+            name,
+            attributes,
+            children,
+            text: text.trim().to_string(),
+        })
+    }
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
@@ -1,3 +1,4 @@
+use crate::MessageType;
 use std::fmt;
 
 /// `SbeEncodeError` struct definition.
@@ -26,13 +27,22 @@ impl fmt::Display for SbeEncodeError {
 
 impl std::error::Error for SbeEncodeError {}
 
-/// `SbeDecodeError` struct definition.
+/// `SbeDecodeError` enum definition.
 ///
 /// Used to represent SBE decoding errors.
 ///
-/// # Fields
+/// # Variants
 ///
-/// `0` - Error message string
+/// * `BufferTooShort` - The buffer is shorter than the header plus the message block
+/// * `WrongTemplateId` - The header carries the template id of another message
+/// * `UnknownTemplateId` - The header carries a template id no message is defined for
+/// * `WrongSchemaId` - The header carries an unknown schema id
+/// * `WrongSchemaVersion` - The header carries a schema version older than supported
+/// * `BlockLengthTooShort` - The block length in the header cannot hold the required fields
+/// * `UnknownEnumValue` - A field holds a value that does not map to its enum
+/// * `DecimalOutOfRange` - A decimal field holds a value that does not fit into `Decimal`
+/// * `WrongMessageType` - The message type field does not match the decoded message
+/// * `InvalidMessage` - The message decoded but its content is invalid
 ///
 /// # Implements
 ///
@@ -41,13 +51,86 @@ impl std::error::Error for SbeEncodeError {}
 /// `std::error::Error` - Implements `std::error::Error` trait
 ///
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct SbeDecodeError(pub String);
+pub enum SbeDecodeError {
+    BufferTooShort { expected: usize, actual: usize },
+    WrongTemplateId { expected: u16, actual: u16 },
+    UnknownTemplateId(u16),
+    WrongSchemaId { expected: u16, actual: u16 },
+    WrongSchemaVersion { expected: u16, actual: u16 },
+    BlockLengthTooShort { expected: u16, actual: u16 },
+    UnknownEnumValue { field: &'static str, value: u16 },
+    DecimalOutOfRange { field: &'static str, value: String },
+    WrongMessageType { expected: MessageType, actual: u16 },
+    InvalidMessage(String),
+}
 
 impl std::error::Error for SbeDecodeError {}
 
 impl fmt::Display for SbeDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::BufferTooShort { expected, actual } => write!(
+                f,
+                "SbeDecodeError: buffer too short, expected at least {expected} bytes but got {actual}"
+            ),
+            Self::WrongTemplateId { expected, actual } => write!(
+                f,
+                "SbeDecodeError: wrong template id, expected {expected} but got {actual}"
+            ),
+            Self::UnknownTemplateId(template_id) => {
+                write!(f, "SbeDecodeError: unknown template id {template_id}")
+            }
+            Self::WrongSchemaId { expected, actual } => write!(
+                f,
+                "SbeDecodeError: unknown schema id {actual}, expected {expected}"
+            ),
+            Self::WrongSchemaVersion { expected, actual } => write!(
+                f,
+                "SbeDecodeError: unsupported schema version {actual}, expected at least {expected}"
+            ),
+            Self::BlockLengthTooShort { expected, actual } => write!(
+                f,
+                "SbeDecodeError: block length too short, expected at least {expected} but got {actual}"
+            ),
+            Self::UnknownEnumValue { field, value } => write!(
+                f,
+                "SbeDecodeError: unknown value {value} for field {field}"
+            ),
+            Self::DecimalOutOfRange { field, value } => write!(
+                f,
+                "SbeDecodeError: decimal {value} of field {field} is out of range"
+            ),
+            Self::WrongMessageType { expected, actual } => write!(
+                f,
+                "SbeDecodeError: wrong message type, expected {expected} but got {actual}"
+            ),
+            Self::InvalidMessage(e) => write!(f, "SbeDecodeError: invalid message: {e}"),
+        }
+    }
+}
+
+/// `SbeValidationError` struct definition.
+///
+/// Used to represent invalid message content, i.e. a bar with a high below its low.
+///
+/// # Fields
+///
+/// `0` - Error message string
+///
+/// # Implements
+///
+/// `Clone`, `Debug`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Hash` - Rust defaults
+/// `fmt::Display` - Custom Display implementation to print error messages
+/// `std::error::Error` - Implements `std::error::Error` trait
+///
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SbeValidationError(pub String);
+
+impl std::error::Error for SbeValidationError {}
+
+impl fmt::Display for SbeValidationError {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "SbeDecodeError: {}", self.0)
+        write!(f, "SbeValidationError: {}", self.0)
     }
 }
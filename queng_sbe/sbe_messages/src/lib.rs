@@ -1,7 +1,10 @@
 mod errors;
 mod messages;
+mod sbe_message;
+mod traits;
 
 mod types;
+mod utils;
 
 // Re exports
 
@@ -9,13 +12,37 @@ mod types;
 pub use crate::errors::*;
 // Client messages
 pub use crate::messages::client_login::ClientLoginMessage;
+pub use crate::messages::client_login_reply::ClientLoginReplyMessage;
 pub use crate::messages::client_logout::ClientLogoutMessage;
+pub use crate::messages::heartbeat::HeartbeatMessage;
 // Data messages
+pub use crate::messages::first_ohlcv_bar::FirstOHLCVBarMessage;
+pub use crate::messages::first_trade_bar::FirstTradeBarMessage;
+pub use crate::messages::last_ohlcv_bar::LastOHLCVBarMessage;
+pub use crate::messages::last_trade_bar::LastTradeBarMessage;
+pub use crate::messages::ohlcv_bar::OHLCVBarMessage;
+pub use crate::messages::order_book_snapshot::OrderBookSnapshotMessage;
+pub use crate::messages::order_book_update::OrderBookUpdateMessage;
+pub use crate::messages::quote::QuoteMessage;
+pub use crate::messages::start_data::StartDataMessage;
+pub use crate::messages::stop_all_data::StopAllDataMessage;
+pub use crate::messages::stop_data::StopDataMessage;
+pub use crate::messages::trade_bar::TradeBarMessage;
 // Error messages
 pub use crate::messages::error_client::ClientErrorMessage;
 pub use crate::messages::error_data::DataErrorMessage;
+// Traits
+pub use crate::traits::partition_key::PartitionKey;
+pub use crate::traits::sbe_encode::SbeEncode;
+// Message dispatch
+pub use crate::sbe_message::{decode_any, SbeMessage};
 // Message types
+pub use crate::types::book_side::BookSide;
 pub use crate::types::client_error_types::ClientErrorType;
 pub use crate::types::data_error_types::DataErrorType;
 pub use crate::types::data_type::DataType;
+pub use crate::types::exchange_id::ExchangeID;
 pub use crate::types::message_types::MessageType;
+pub use crate::types::order_book_level::OrderBookLevel;
+pub use crate::types::order_book_level_update::OrderBookLevelUpdate;
+pub use crate::types::time_resolution::TimeResolution;
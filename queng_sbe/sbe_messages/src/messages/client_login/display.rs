@@ -2,6 +2,7 @@ use crate::messages::client_login::ClientLoginMessage;
 use std::fmt;
 
 impl fmt::Display for ClientLoginMessage {
+    /// Prints the client id only; the secret is never printed.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
@@ -10,4 +10,13 @@ impl ClientLoginMessage {
     pub const fn client_id(&self) -> u16 {
         self.client_id
     }
+    #[must_use]
+    pub const fn secret(&self) -> Option<u128> {
+        self.secret
+    }
+    /// Returns the secret as 32 digit lowercase hex string, i.e. the password of the client stream user.
+    #[must_use]
+    pub fn secret_hex(&self) -> Option<String> {
+        self.secret.map(|secret| format!("{secret:032x}"))
+    }
 }
@@ -1,3 +1,4 @@
+use crate::errors::SbeDecodeError;
 use crate::MessageType;
 
 mod display;
@@ -5,10 +6,17 @@ mod getters;
 mod sbe_decode;
 mod sbe_encode;
 
+/// Login of a client.
+///
+/// Carries the secret the client chose for this login. The service creates the stream
+/// user of the client's data and error channels with the secret as password, so only
+/// the client can read its channels. Clients can write to the control channel but must
+/// not be able to read it, or they could read the secrets of other clients.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct ClientLoginMessage {
     message_type: MessageType,
     client_id: u16,
+    secret: Option<u128>,
 }
 
 impl ClientLoginMessage {
@@ -22,8 +30,8 @@ impl ClientLoginMessage {
     ///
     /// # Returns
     ///
-    /// `ClientLoginMessage` instance
-
+    /// `ClientLoginMessage` instance without a secret, which the service rejects
+    ///
     #[must_use]
     pub const fn new(client_id: u16) -> Self {
         let message_type = MessageType::ClientLogin;
@@ -31,12 +39,41 @@ impl ClientLoginMessage {
         Self {
             message_type,
             client_id,
+            secret: None,
+        }
+    }
+
+    /// Creates a new `ClientLoginMessage` instance with the secret of the client.
+    ///
+    /// The secret was added in schema version 8 and decodes to `None`
+    /// from logins encoded with an older schema version.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - u16 client ID
+    /// * `secret` - u128 random secret generated by the client for this login;
+    ///   `u128::MAX` is the null value and encodes as no secret
+    ///
+    /// # Returns
+    ///
+    /// `ClientLoginMessage` instance
+    ///
+    #[must_use]
+    pub const fn with_secret(client_id: u16, secret: u128) -> Self {
+        let message_type = MessageType::ClientLogin;
+
+        Self {
+            message_type,
+            client_id,
+            secret: Some(secret),
         }
     }
 }
 
-impl From<&[u8]> for ClientLoginMessage {
-    /// Implements the From trait to decode a `ClientLoginMessage` from a byte slice.
+impl TryFrom<&[u8]> for ClientLoginMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `ClientLoginMessage` from a byte slice.
     ///
     /// Calls the `sbe_decode::decode_client_login_message` function to decode the message.
     ///
@@ -50,9 +87,9 @@ impl From<&[u8]> for ClientLoginMessage {
     ///
     /// # Errors
     ///
-    /// Panics if decode fails
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `ClientLoginMessage`
     #[inline]
-    fn from(value: &[u8]) -> Self {
-        sbe_decode::decode_client_login_message(value).expect("Failed to decode ClientLoginMessage")
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_client_login_message(value)
     }
 }
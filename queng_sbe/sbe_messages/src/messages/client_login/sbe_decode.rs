@@ -1,6 +1,8 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::decode_utils;
 use crate::{ClientLoginMessage, MessageType};
-use sbe_bindings::client_login_codec::SBE_TEMPLATE_ID;
-use sbe_bindings::{ClientLoginDecoder, MessageHeaderDecoder, ReadBuf, SbeResult};
+use sbe_bindings::client_login_codec::{SBE_MIN_BLOCK_LENGTH, SBE_TEMPLATE_ID};
+use sbe_bindings::ClientLoginDecoder;
 
 /// Decodes a `ClientLoginMessage` from a byte buffer.
 ///
@@ -14,32 +16,34 @@ use sbe_bindings::{ClientLoginDecoder, MessageHeaderDecoder, ReadBuf, SbeResult}
 ///
 /// # Errors
 ///
-/// Returns Err if decode fails
+/// Returns `SbeDecodeError` if the buffer is too short, the header does not match,
+/// or a field holds an unexpected value
 ///
 /// # Process
 ///
-/// - Create default `ClientLoginDecoder`
-/// - Wrap buffer in `ReadBuf`
-/// - Decode header and validate template ID
+/// - Validate buffer length and header
+/// - Wrap header in `ClientLoginDecoder`
 /// - Decode `message_type` and validate
 /// - Decode `client_id`
+/// - Decode the high and low half of the `secret`, absent before schema version 8
 /// - Create and return `ClientLoginMessage`
 ///
-pub fn decode_client_login_message(buffer: &[u8]) -> SbeResult<ClientLoginMessage> {
-    let mut csg = ClientLoginDecoder::default();
-    let buf = ReadBuf::new(buffer);
-
-    let header = MessageHeaderDecoder::default().wrap(buf, 0);
-    assert_eq!(SBE_TEMPLATE_ID, header.template_id());
-    csg = csg.header(header);
+pub fn decode_client_login_message(buffer: &[u8]) -> Result<ClientLoginMessage, SbeDecodeError> {
+    let header = decode_utils::decode_header(buffer, SBE_TEMPLATE_ID, SBE_MIN_BLOCK_LENGTH)?;
+    let csg = ClientLoginDecoder::default().header(header);
 
     let sbe_message_type = csg.message_type();
-    let message_type = MessageType::from(sbe_message_type as u16);
-    assert_eq!(message_type, MessageType::ClientLogin);
+    decode_utils::check_message_type(MessageType::ClientLogin, sbe_message_type as u16)?;
 
     let client_id = csg.client_id();
 
-    let message = ClientLoginMessage::new(client_id);
+    // Both halves decode to all ones, the null value, if the secret is absent
+    let secret = (u128::from(csg.secret_high()) << 64) | u128::from(csg.secret_low());
+    let message = if secret == u128::MAX {
+        ClientLoginMessage::new(client_id)
+    } else {
+        ClientLoginMessage::with_secret(client_id, secret)
+    };
 
     Ok(message)
 }
@@ -1,18 +1,38 @@
 use crate::errors::SbeEncodeError;
 use crate::ClientLoginMessage;
+use crate::SbeEncode;
 use sbe_bindings::MessageType as SbeMessageType;
 use sbe_bindings::{message_header_codec, ClientLoginEncoder, Encoder, WriteBuf};
 
 impl ClientLoginMessage {
-    /// Encodes a `ClientLoginMessage` to a byte buffer.
+    /// Encodes a `ClientLoginMessage` to a new byte buffer.
+    ///
+    /// # Returns
+    ///
+    /// (usize, `Vec<u8>`) - Tuple of encoded size and byte buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if encoding fails
+    ///
+    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
+        let mut buffer = Vec::new();
+        let limit = self.encode_into(&mut buffer)?;
+        Ok((limit, buffer))
+    }
+}
+
+impl SbeEncode for ClientLoginMessage {
+    /// Encodes a `ClientLoginMessage` into the given byte buffer, replacing its content.
     ///
     /// # Arguments
     ///
     /// * `self` - `ClientLoginMessage` to encode
+    /// * `buffer` - Byte buffer to encode into, reused across calls
     ///
     /// # Returns
     ///
-    /// (usize, `Vec<u8>`) - Tuple of encoded size and byte buffer
+    /// usize - Encoded size
     ///
     /// # Errors
     ///
@@ -20,17 +40,19 @@ impl ClientLoginMessage {
     ///
     /// # Process
     ///
-    /// - Create a 12 byte buffer
+    /// - Resize the buffer to 28 bytes
     /// - Create default `ClientLoginEncoder`
     /// - Wrap buffer in `WriteBuf`
     /// - Encode header
     /// - Encode `message_type`
     /// - Encode `client_id`
-    /// - Return encoded size and buffer
+    /// - Encode the high and low half of the `secret`, all ones if there is no secret
+    /// - Return encoded size
     ///
-    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
-        // precise buffer size is 12 bytes for the entire message.
-        let mut buffer = vec![0u8; 12];
+    fn encode_into(&self, buffer: &mut Vec<u8>) -> Result<usize, SbeEncodeError> {
+        // precise buffer size is 28 bytes for the entire message.
+        buffer.clear();
+        buffer.resize(28, 0);
 
         let mut csg = ClientLoginEncoder::default();
 
@@ -50,7 +72,14 @@ impl ClientLoginMessage {
         let value = self.client_id;
         csg.client_id(value);
 
+        let value = self.secret.unwrap_or(u128::MAX);
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            csg.secret_high((value >> 64) as u64);
+            csg.secret_low(value as u64);
+        }
+
         let limit = csg.get_limit();
-        Ok((limit, buffer))
+        Ok(limit)
     }
 }
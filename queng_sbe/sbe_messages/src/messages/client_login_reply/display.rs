@@ -0,0 +1,12 @@
+use crate::ClientLoginReplyMessage;
+use std::fmt;
+
+impl fmt::Display for ClientLoginReplyMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ClientLoginReplyMessage {{ client_id: {} }}",
+            self.client_id()
+        )
+    }
+}
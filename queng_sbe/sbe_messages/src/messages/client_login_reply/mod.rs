@@ -0,0 +1,64 @@
+use crate::errors::SbeDecodeError;
+use crate::MessageType;
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+/// Reply to a successful client login.
+///
+/// Sent on the error channel of the client, so only the client can read it,
+/// once the channels of the client are ready.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ClientLoginReplyMessage {
+    message_type: MessageType,
+    client_id: u16,
+}
+
+impl ClientLoginReplyMessage {
+    /// Creates a new `ClientLoginReplyMessage` instance.
+    ///
+    /// Sets the `message_type` to `ClientLoginReply`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - u16 client ID
+    ///
+    /// # Returns
+    ///
+    /// `ClientLoginReplyMessage` instance
+    ///
+    #[must_use]
+    pub const fn new(client_id: u16) -> Self {
+        let message_type = MessageType::ClientLoginReply;
+        Self {
+            message_type,
+            client_id,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for ClientLoginReplyMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `ClientLoginReplyMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_client_login_reply_message` function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded `ClientLoginReplyMessage`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `ClientLoginReplyMessage`
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_client_login_reply_message(value)
+    }
+}
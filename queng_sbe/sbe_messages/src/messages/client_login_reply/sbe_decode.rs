@@ -0,0 +1,43 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::decode_utils;
+use crate::{ClientLoginReplyMessage, MessageType};
+use sbe_bindings::client_login_reply_codec::{SBE_MIN_BLOCK_LENGTH, SBE_TEMPLATE_ID};
+use sbe_bindings::ClientLoginReplyDecoder;
+
+/// Decodes a `ClientLoginReplyMessage` from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded `ClientLoginReplyMessage`
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError` if the buffer is too short, the header does not match,
+/// or a field holds an unexpected value
+///
+/// # Process
+///
+/// - Validate buffer length and header
+/// - Wrap header in `ClientLoginReplyDecoder`
+/// - Decode `message_type` and validate
+/// - Decode `client_id`
+/// - Create and return `ClientLoginReplyMessage`
+pub fn decode_client_login_reply_message(
+    buffer: &[u8],
+) -> Result<ClientLoginReplyMessage, SbeDecodeError> {
+    let header = decode_utils::decode_header(buffer, SBE_TEMPLATE_ID, SBE_MIN_BLOCK_LENGTH)?;
+    let csg = ClientLoginReplyDecoder::default().header(header);
+
+    let sbe_message_type = csg.message_type();
+    decode_utils::check_message_type(MessageType::ClientLoginReply, sbe_message_type as u16)?;
+
+    let client_id = csg.client_id();
+
+    let message = ClientLoginReplyMessage::new(client_id);
+
+    Ok(message)
+}
@@ -1,3 +1,4 @@
+use crate::errors::SbeDecodeError;
 use crate::MessageType;
 
 mod display;
@@ -34,8 +35,10 @@ impl ClientLogoutMessage {
     }
 }
 
-impl From<&[u8]> for ClientLogoutMessage {
-    /// Implements the From trait to decode a `ClientLogoutMessage` from a byte slice.
+impl TryFrom<&[u8]> for ClientLogoutMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `ClientLogoutMessage` from a byte slice.
     ///
     /// Calls the `sbe_decode::decode_client_logout_message` function to decode the message.
     ///
@@ -49,10 +52,9 @@ impl From<&[u8]> for ClientLogoutMessage {
     ///
     /// # Errors
     ///
-    /// Panics if decode fails
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `ClientLogoutMessage`
     #[inline]
-    fn from(value: &[u8]) -> Self {
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
         sbe_decode::decode_client_logout_message(value)
-            .expect("Failed to decode ClientLoginMessage")
     }
 }
@@ -1,6 +1,8 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::decode_utils;
 use crate::{ClientLogoutMessage, MessageType};
-use sbe_bindings::client_logout_codec::SBE_TEMPLATE_ID;
-use sbe_bindings::{ClientLogoutDecoder, MessageHeaderDecoder, ReadBuf, SbeResult};
+use sbe_bindings::client_logout_codec::{SBE_MIN_BLOCK_LENGTH, SBE_TEMPLATE_ID};
+use sbe_bindings::ClientLogoutDecoder;
 
 /// Decodes a `ClientLogoutMessage` from a byte buffer.
 ///
@@ -14,27 +16,22 @@ use sbe_bindings::{ClientLogoutDecoder, MessageHeaderDecoder, ReadBuf, SbeResult
 ///
 /// # Errors
 ///
-/// Returns Err if decode fails
+/// Returns `SbeDecodeError` if the buffer is too short, the header does not match,
+/// or a field holds an unexpected value
 ///
 /// # Process
 ///
-/// - Create default `ClientLogoutDecoder`
-/// - Wrap buffer in `ReadBuf`
-/// - Decode header and validate template ID
+/// - Validate buffer length and header
+/// - Wrap header in `ClientLogoutDecoder`
 /// - Decode `message_type` and validate
 /// - Decode `client_id`
 /// - Create and return `ClientLogoutMessage`
-pub fn decode_client_logout_message(buffer: &[u8]) -> SbeResult<ClientLogoutMessage> {
-    let mut csg = ClientLogoutDecoder::default();
-    let buf = ReadBuf::new(buffer);
-
-    let header = MessageHeaderDecoder::default().wrap(buf, 0);
-    assert_eq!(SBE_TEMPLATE_ID, header.template_id());
-    csg = csg.header(header);
+pub fn decode_client_logout_message(buffer: &[u8]) -> Result<ClientLogoutMessage, SbeDecodeError> {
+    let header = decode_utils::decode_header(buffer, SBE_TEMPLATE_ID, SBE_MIN_BLOCK_LENGTH)?;
+    let csg = ClientLogoutDecoder::default().header(header);
 
     let sbe_message_type = csg.message_type();
-    let message_type = MessageType::from(sbe_message_type as u16);
-    assert_eq!(message_type, MessageType::ClientLogout);
+    decode_utils::check_message_type(MessageType::ClientLogout, sbe_message_type as u16)?;
 
     let client_id = csg.client_id();
 
@@ -1,18 +1,38 @@
 use crate::errors::SbeEncodeError;
 use crate::ClientLogoutMessage;
+use crate::SbeEncode;
 use sbe_bindings::MessageType as SbeMessageType;
 use sbe_bindings::{message_header_codec, ClientLogoutEncoder, Encoder, WriteBuf};
 
 impl ClientLogoutMessage {
-    /// Encodes a `ClientLogoutMessage` to a byte buffer.
+    /// Encodes a `ClientLogoutMessage` to a new byte buffer.
+    ///
+    /// # Returns
+    ///
+    /// (usize, `Vec<u8>`) - Tuple of encoded size and byte buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if encoding fails
+    ///
+    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
+        let mut buffer = Vec::new();
+        let limit = self.encode_into(&mut buffer)?;
+        Ok((limit, buffer))
+    }
+}
+
+impl SbeEncode for ClientLogoutMessage {
+    /// Encodes a `ClientLogoutMessage` into the given byte buffer, replacing its content.
     ///
     /// # Arguments
     ///
     /// * `self` - `ClientLogoutMessage` to encode
+    /// * `buffer` - Byte buffer to encode into, reused across calls
     ///
     /// # Returns
     ///
-    /// (usize, `Vec<u8>`) - Tuple of encoded size and byte buffer
+    /// usize - Encoded size
     ///
     /// # Errors
     ///
@@ -20,17 +40,18 @@ impl ClientLogoutMessage {
     ///
     /// # Process
     ///
-    /// - Create a 12 byte buffer
+    /// - Resize the buffer to 12 bytes
     /// - Create default `ClientLogoutEncoder`
     /// - Wrap buffer in `WriteBuf`
     /// - Encode header
     /// - Encode `message_type`
     /// - Encode `client_id`
-    /// - Return encoded size and buffer
+    /// - Return encoded size
     ///
-    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
+    fn encode_into(&self, buffer: &mut Vec<u8>) -> Result<usize, SbeEncodeError> {
         // precise buffer size is 12 bytes for the entire message.
-        let mut buffer = vec![0u8; 12];
+        buffer.clear();
+        buffer.resize(12, 0);
 
         let mut csg = ClientLogoutEncoder::default();
 
@@ -48,6 +69,6 @@ impl ClientLogoutMessage {
         csg.client_id(value);
 
         let limit = csg.get_limit();
-        Ok((limit, buffer))
+        Ok(limit)
     }
 }
@@ -1,3 +1,4 @@
+use crate::errors::SbeDecodeError;
 use crate::{ClientErrorType, MessageType};
 
 mod display;
@@ -39,27 +40,26 @@ impl ClientErrorMessage {
     }
 }
 
-impl From<&[u8]> for ClientErrorMessage {
-    /// Decodes a `ClientErrorMessage` from a byte buffer.
+impl TryFrom<&[u8]> for ClientErrorMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `ClientErrorMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_client_error_message` function to decode the message.
     ///
     /// # Arguments
     ///
-    /// * `value` - Byte buffer containing encoded `ClientErrorMessage`
+    /// * `value` - Byte slice to decode
     ///
     /// # Returns
     ///
-    /// Decoded `ClientErrorMessage` on success
+    /// Decoded `ClientErrorMessage`
     ///
     /// # Errors
     ///
-    /// Returns Err if decoding fails
-    ///
-    /// # Remarks
-    ///
-    /// Calls `sbe_decode::decode_client_error_message` to decode message
-    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `ClientErrorMessage`
     #[inline]
-    fn from(value: &[u8]) -> Self {
-        sbe_decode::decode_client_error_message(value).expect("Failed to decode ClientLoginMessage")
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_client_error_message(value)
     }
 }
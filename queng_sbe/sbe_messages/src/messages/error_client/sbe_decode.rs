@@ -1,6 +1,8 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::decode_utils;
 use crate::{ClientErrorMessage, ClientErrorType, MessageType};
-use sbe_bindings::client_error_codec::SBE_TEMPLATE_ID;
-use sbe_bindings::{ClientErrorDecoder, MessageHeaderDecoder, ReadBuf, SbeResult};
+use sbe_bindings::client_error_codec::{SBE_MIN_BLOCK_LENGTH, SBE_TEMPLATE_ID};
+use sbe_bindings::ClientErrorDecoder;
 
 /// Decodes a `ClientErrorMessage` from a byte buffer.
 ///
@@ -14,35 +16,38 @@ use sbe_bindings::{ClientErrorDecoder, MessageHeaderDecoder, ReadBuf, SbeResult}
 ///
 /// # Errors
 ///
-/// Returns Err if decoding fails
+/// Returns `SbeDecodeError` if the buffer is too short, the header does not match,
+/// or a field holds an unexpected value
 ///
 /// # Process
 ///
-/// - Create default `ClientErrorDecoder`
-/// - Wrap buffer in `ReadBuf`
-/// - Decode header and validate template ID
+/// - Validate buffer length and header
+/// - Wrap header in `ClientErrorDecoder`
 /// - Decode and validate `message_type`
 /// - Decode `client_id`
 /// - Decode and validate `client_error_type`
 /// - Create and return `ClientErrorMessage`
 ///
-pub fn decode_client_error_message(buffer: &[u8]) -> SbeResult<ClientErrorMessage> {
-    let mut csg = ClientErrorDecoder::default();
-    let buf = ReadBuf::new(buffer);
-
-    let header = MessageHeaderDecoder::default().wrap(buf, 0);
-    assert_eq!(SBE_TEMPLATE_ID, header.template_id());
-    csg = csg.header(header);
+pub fn decode_client_error_message(buffer: &[u8]) -> Result<ClientErrorMessage, SbeDecodeError> {
+    let header = decode_utils::decode_header(buffer, SBE_TEMPLATE_ID, SBE_MIN_BLOCK_LENGTH)?;
+    let csg = ClientErrorDecoder::default().header(header);
 
     let sbe_message_type = csg.message_type();
-    let message_type = MessageType::from(sbe_message_type as u16);
-    assert_eq!(message_type, MessageType::ClientError);
+    decode_utils::check_message_type(MessageType::ClientError, sbe_message_type as u16)?;
 
     let client_id = csg.client_id();
-    let client_error_type_raw = csg
-        .client_error_type()
-        .expect("Failed to decode client error type");
+    let client_error_type_raw =
+        csg.client_error_type()
+            .ok_or(SbeDecodeError::UnknownEnumValue {
+                field: "client_error_type",
+                value: 0xff,
+            })?;
     let client_error_type = ClientErrorType::from(client_error_type_raw);
+    decode_utils::check_enum_value(
+        "client_error_type",
+        client_error_type_raw,
+        client_error_type as u8,
+    )?;
 
     let message = ClientErrorMessage::new(client_id, client_error_type);
 
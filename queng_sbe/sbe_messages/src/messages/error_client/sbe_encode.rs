@@ -1,36 +1,57 @@
 use crate::errors::SbeEncodeError;
 use crate::ClientErrorMessage;
+use crate::SbeEncode;
 use sbe_bindings::MessageType as SbeMessageType;
 use sbe_bindings::{message_header_codec, ClientErrorEncoder, Encoder, WriteBuf};
 
-/// Encodes a `ClientErrorMessage` to a byte buffer.
-///
-/// # Arguments
-///
-/// * `self` - `ClientErrorMessage` to encode
-///
-/// # Returns
-///
-/// (usize, `Vec<u8>`) - Tuple containing encoded size and byte buffer
-///
-/// # Errors
-///
-/// Returns Err if encoding fails
-///
-/// # Process
-///
-/// - Create 13 byte buffer
-/// - Create default `ClientErrorEncoder`
-/// - Wrap buffer in `WriteBuf`
-/// - Encode header
-/// - Encode `message_type`
-/// - Encode `client_id`
-/// - Encode `client_error_type`
-/// - Return encoded size and buffer
-///
 impl ClientErrorMessage {
+    /// Encodes a `ClientErrorMessage` to a new byte buffer.
+    ///
+    /// # Returns
+    ///
+    /// (usize, `Vec<u8>`) - Tuple of encoded size and byte buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if encoding fails
+    ///
     pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
-        let mut buffer = vec![0u8; 13];
+        let mut buffer = Vec::new();
+        let limit = self.encode_into(&mut buffer)?;
+        Ok((limit, buffer))
+    }
+}
+
+impl SbeEncode for ClientErrorMessage {
+    /// Encodes a `ClientErrorMessage` into the given byte buffer, replacing its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - `ClientErrorMessage` to encode
+    /// * `buffer` - Byte buffer to encode into, reused across calls
+    ///
+    /// # Returns
+    ///
+    /// usize - Encoded size
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if encoding fails
+    ///
+    /// # Process
+    ///
+    /// - Resize the buffer to 13 bytes
+    /// - Create default `ClientErrorEncoder`
+    /// - Wrap buffer in `WriteBuf`
+    /// - Encode header
+    /// - Encode `message_type`
+    /// - Encode `client_id`
+    /// - Encode `client_error_type`
+    /// - Return encoded size
+    ///
+    fn encode_into(&self, buffer: &mut Vec<u8>) -> Result<usize, SbeEncodeError> {
+        buffer.clear();
+        buffer.resize(13, 0);
 
         let mut csg = ClientErrorEncoder::default();
 
@@ -51,6 +72,6 @@ impl ClientErrorMessage {
         csg.client_error_type(value);
 
         let limit = csg.get_limit();
-        Ok((limit, buffer))
+        Ok(limit)
     }
 }
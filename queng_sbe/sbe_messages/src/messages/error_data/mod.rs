@@ -1,3 +1,4 @@
+use crate::errors::SbeDecodeError;
 use crate::{DataErrorType, MessageType};
 
 mod display;
@@ -39,27 +40,26 @@ impl DataErrorMessage {
     }
 }
 
-impl From<&[u8]> for DataErrorMessage {
-    /// Decodes a `DataErrorMessage` from a byte buffer.
+impl TryFrom<&[u8]> for DataErrorMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `DataErrorMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_data_error_message` function to decode the message.
     ///
     /// # Arguments
     ///
-    /// * `value` - Byte buffer containing encoded `DataErrorMessage`
+    /// * `value` - Byte slice to decode
     ///
     /// # Returns
     ///
-    /// Decoded `DataErrorMessage` on success
+    /// Decoded `DataErrorMessage`
     ///
     /// # Errors
     ///
-    /// Returns Err if decoding fails
-    ///
-    /// # Remarks
-    ///
-    /// Calls `sbe_decode::decode_data_error_message` to decode message
-    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `DataErrorMessage`
     #[inline]
-    fn from(value: &[u8]) -> Self {
-        sbe_decode::decode_client_error_message(value).expect("Failed to decode DataErrorMessage")
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_data_error_message(value)
     }
 }
@@ -1,6 +1,8 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::decode_utils;
 use crate::{DataErrorMessage, DataErrorType, MessageType};
-use sbe_bindings::data_error_codec::SBE_TEMPLATE_ID;
-use sbe_bindings::{DataErrorDecoder, MessageHeaderDecoder, ReadBuf, SbeResult};
+use sbe_bindings::data_error_codec::{SBE_MIN_BLOCK_LENGTH, SBE_TEMPLATE_ID};
+use sbe_bindings::DataErrorDecoder;
 
 /// Decodes a `DataErrorMessage` from a byte buffer.
 ///
@@ -14,37 +16,38 @@ use sbe_bindings::{DataErrorDecoder, MessageHeaderDecoder, ReadBuf, SbeResult};
 ///
 /// # Errors
 ///
-/// Returns Err if decoding fails
+/// Returns `SbeDecodeError` if the buffer is too short, the header does not match,
+/// or a field holds an unexpected value
 ///
 /// # Process
 ///
-/// - Create default `DataErrorDecoder`
-/// - Wrap buffer in `ReadBuf`
-/// - Decode header and validate template ID
+/// - Validate buffer length and header
+/// - Wrap header in `DataErrorDecoder`
 /// - Decode and validate `message_type`
 /// - Decode `client_id`
 /// - Decode and validate `data_error_type`
 /// - Create and return `DataErrorMessage`
 ///
-pub fn decode_client_error_message(buffer: &[u8]) -> SbeResult<DataErrorMessage> {
-    let mut csg = DataErrorDecoder::default();
-    let buf = ReadBuf::new(buffer);
-
-    let header = MessageHeaderDecoder::default().wrap(buf, 0);
-    assert_eq!(SBE_TEMPLATE_ID, header.template_id());
-
-    csg = csg.header(header);
+pub fn decode_data_error_message(buffer: &[u8]) -> Result<DataErrorMessage, SbeDecodeError> {
+    let header = decode_utils::decode_header(buffer, SBE_TEMPLATE_ID, SBE_MIN_BLOCK_LENGTH)?;
+    let csg = DataErrorDecoder::default().header(header);
 
     let sbe_message_type = csg.message_type();
-    let message_type = MessageType::from(sbe_message_type as u16);
-    assert_eq!(message_type, MessageType::DataError);
+    decode_utils::check_message_type(MessageType::DataError, sbe_message_type as u16)?;
 
     let client_id = csg.client_id();
     let data_error_type_raw = csg
         .data_error_type()
-        .expect("Failed to decode client error type");
-
+        .ok_or(SbeDecodeError::UnknownEnumValue {
+            field: "data_error_type",
+            value: 0xff,
+        })?;
     let data_error_type = DataErrorType::from(data_error_type_raw);
+    decode_utils::check_enum_value(
+        "data_error_type",
+        data_error_type_raw,
+        data_error_type as u8,
+    )?;
 
     let message = DataErrorMessage::new(client_id, data_error_type);
 
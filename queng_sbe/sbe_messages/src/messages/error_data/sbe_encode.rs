@@ -1,18 +1,38 @@
 use crate::errors::SbeEncodeError;
 use crate::DataErrorMessage;
+use crate::SbeEncode;
 use sbe_bindings::MessageType as SbeMessageType;
 use sbe_bindings::{message_header_codec, DataErrorEncoder, Encoder, WriteBuf};
 
 impl DataErrorMessage {
-    /// Encodes a `DataErrorMessage` to a byte buffer.
+    /// Encodes a `DataErrorMessage` to a new byte buffer.
+    ///
+    /// # Returns
+    ///
+    /// (usize, `Vec<u8>`) - Tuple of encoded size and byte buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if encoding fails
+    ///
+    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
+        let mut buffer = Vec::new();
+        let limit = self.encode_into(&mut buffer)?;
+        Ok((limit, buffer))
+    }
+}
+
+impl SbeEncode for DataErrorMessage {
+    /// Encodes a `DataErrorMessage` into the given byte buffer, replacing its content.
     ///
     /// # Arguments
     ///
     /// * `self` - `DataErrorMessage` to encode
+    /// * `buffer` - Byte buffer to encode into, reused across calls
     ///
     /// # Returns
     ///
-    /// (usize, `Vec<u8>`) - Tuple containing encoded size and byte buffer
+    /// usize - Encoded size
     ///
     /// # Errors
     ///
@@ -20,17 +40,18 @@ impl DataErrorMessage {
     ///
     /// # Process
     ///
-    /// - Create 13 byte buffer
+    /// - Resize the buffer to 13 bytes
     /// - Create default `DataErrorEncoder`
     /// - Wrap buffer in `WriteBuf`
     /// - Encode header
     /// - Encode `message_type`
     /// - Encode `client_id`
     /// - Encode `data_error_type`
-    /// - Return encoded size and buffer
+    /// - Return encoded size
     ///
-    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
-        let mut buffer = vec![0u8; 13];
+    fn encode_into(&self, buffer: &mut Vec<u8>) -> Result<usize, SbeEncodeError> {
+        buffer.clear();
+        buffer.resize(13, 0);
 
         let mut csg = DataErrorEncoder::default();
 
@@ -51,6 +72,6 @@ impl DataErrorMessage {
         csg.data_error_type(value);
 
         let limit = csg.get_limit();
-        Ok((limit, buffer))
+        Ok(limit)
     }
 }
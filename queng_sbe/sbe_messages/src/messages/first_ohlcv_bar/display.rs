@@ -0,0 +1,12 @@
+use crate::messages::first_ohlcv_bar::FirstOHLCVBarMessage;
+use std::fmt;
+
+impl fmt::Display for FirstOHLCVBarMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "FirstOHLCVBarMessage {{ symbol_id: {} }}",
+            self.symbol_id()
+        )
+    }
+}
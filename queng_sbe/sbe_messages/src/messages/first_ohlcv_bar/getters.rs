@@ -0,0 +1,13 @@
+use crate::messages::first_ohlcv_bar::FirstOHLCVBarMessage;
+use crate::MessageType;
+
+impl FirstOHLCVBarMessage {
+    #[must_use]
+    pub const fn message_type(&self) -> &MessageType {
+        &self.message_type
+    }
+    #[must_use]
+    pub const fn symbol_id(&self) -> u16 {
+        self.symbol_id
+    }
+}
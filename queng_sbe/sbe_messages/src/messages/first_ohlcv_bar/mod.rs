@@ -0,0 +1,60 @@
+use crate::errors::SbeDecodeError;
+use crate::MessageType;
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FirstOHLCVBarMessage {
+    message_type: MessageType,
+    symbol_id: u16,
+}
+
+impl FirstOHLCVBarMessage {
+    /// Creates a new `FirstOHLCVBarMessage` instance.
+    ///
+    /// Sets the `message_type` to `FirstOHLCVBar`.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_id` - u16 symbol ID
+    ///
+    /// # Returns
+    ///
+    /// `FirstOHLCVBarMessage` instance
+    ///
+    #[must_use]
+    pub const fn new(symbol_id: u16) -> Self {
+        let message_type = MessageType::FirstOHLCVBar;
+        Self {
+            message_type,
+            symbol_id,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for FirstOHLCVBarMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `FirstOHLCVBarMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_first_ohlcv_bar_message` function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded `FirstOHLCVBarMessage`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `FirstOHLCVBarMessage`
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_first_ohlcv_bar_message(value)
+    }
+}
@@ -0,0 +1,12 @@
+use crate::messages::first_trade_bar::FirstTradeBarMessage;
+use std::fmt;
+
+impl fmt::Display for FirstTradeBarMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "FirstTradeBarMessage {{ symbol_id: {} }}",
+            self.symbol_id()
+        )
+    }
+}
@@ -0,0 +1,60 @@
+use crate::errors::SbeDecodeError;
+use crate::MessageType;
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FirstTradeBarMessage {
+    message_type: MessageType,
+    symbol_id: u16,
+}
+
+impl FirstTradeBarMessage {
+    /// Creates a new `FirstTradeBarMessage` instance.
+    ///
+    /// Sets the `message_type` to `FirstTradeBar`.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_id` - u16 symbol ID
+    ///
+    /// # Returns
+    ///
+    /// `FirstTradeBarMessage` instance
+    ///
+    #[must_use]
+    pub const fn new(symbol_id: u16) -> Self {
+        let message_type = MessageType::FirstTradeBar;
+        Self {
+            message_type,
+            symbol_id,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for FirstTradeBarMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `FirstTradeBarMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_first_trade_bar_message` function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded `FirstTradeBarMessage`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `FirstTradeBarMessage`
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_first_trade_bar_message(value)
+    }
+}
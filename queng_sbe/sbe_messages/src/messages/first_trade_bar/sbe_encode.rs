@@ -0,0 +1,77 @@
+use crate::errors::SbeEncodeError;
+use crate::FirstTradeBarMessage;
+use crate::SbeEncode;
+use sbe_bindings::MessageType as SbeMessageType;
+use sbe_bindings::{message_header_codec, Encoder, FirstTradeBarEncoder, WriteBuf};
+
+impl FirstTradeBarMessage {
+    /// Encodes a `FirstTradeBarMessage` to a new byte buffer.
+    ///
+    /// # Returns
+    ///
+    /// (usize, `Vec<u8>`) - Tuple of encoded size and byte buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if encoding fails
+    ///
+    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
+        let mut buffer = Vec::new();
+        let limit = self.encode_into(&mut buffer)?;
+        Ok((limit, buffer))
+    }
+}
+
+impl SbeEncode for FirstTradeBarMessage {
+    /// Encodes a `FirstTradeBarMessage` into the given byte buffer, replacing its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - `FirstTradeBarMessage` to encode
+    /// * `buffer` - Byte buffer to encode into, reused across calls
+    ///
+    /// # Returns
+    ///
+    /// usize - Encoded size
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if encoding fails
+    ///
+    /// # Process
+    ///
+    /// - Resize the buffer to 12 bytes
+    /// - Create default `FirstTradeBarEncoder`
+    /// - Wrap buffer in `WriteBuf`
+    /// - Encode header
+    /// - Encode `message_type`
+    /// - Encode `symbol_id`
+    /// - Return encoded size
+    ///
+    fn encode_into(&self, buffer: &mut Vec<u8>) -> Result<usize, SbeEncodeError> {
+        // precise buffer size is 12 bytes for the entire message.
+        buffer.clear();
+        buffer.resize(12, 0);
+
+        let mut csg = FirstTradeBarEncoder::default();
+
+        csg = csg.wrap(
+            WriteBuf::new(buffer.as_mut_slice()),
+            message_header_codec::ENCODED_LENGTH,
+        );
+
+        csg = csg
+            .header(0)
+            .parent()
+            .expect("[FirstTradeBarMessage]: Failed to encode header");
+
+        let value = SbeMessageType::from(self.message_type as u16);
+        csg.message_type(value);
+
+        let value = self.symbol_id;
+        csg.symbol_id(value);
+
+        let limit = csg.get_limit();
+        Ok(limit)
+    }
+}
@@ -0,0 +1,13 @@
+use crate::HeartbeatMessage;
+use std::fmt;
+
+impl fmt::Display for HeartbeatMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "HeartbeatMessage {{ client_id: {}, timestamp: {:?} }}",
+            self.client_id(),
+            self.timestamp()
+        )
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{HeartbeatMessage, MessageType};
+
+impl HeartbeatMessage {
+    #[must_use]
+    pub const fn message_type(&self) -> &MessageType {
+        &self.message_type
+    }
+    #[must_use]
+    pub const fn client_id(&self) -> u16 {
+        self.client_id
+    }
+    #[must_use]
+    pub const fn timestamp(&self) -> Option<u64> {
+        self.timestamp
+    }
+}
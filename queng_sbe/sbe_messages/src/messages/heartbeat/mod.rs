@@ -0,0 +1,86 @@
+use crate::errors::SbeDecodeError;
+use crate::MessageType;
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct HeartbeatMessage {
+    message_type: MessageType,
+    client_id: u16,
+    timestamp: Option<u64>,
+}
+
+impl HeartbeatMessage {
+    /// Creates a new `HeartbeatMessage` instance.
+    ///
+    /// Sets the `message_type` to `Heartbeat`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - u16 client ID
+    ///
+    /// # Returns
+    ///
+    /// `HeartbeatMessage` instance without a timestamp
+    ///
+    #[must_use]
+    pub const fn new(client_id: u16) -> Self {
+        let message_type = MessageType::Heartbeat;
+        Self {
+            message_type,
+            client_id,
+            timestamp: None,
+        }
+    }
+
+    /// Creates a new `HeartbeatMessage` instance with the time the client sent it.
+    ///
+    /// The timestamp was added in schema version 7 and decodes to `None`
+    /// from heartbeats encoded with an older schema version.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - u16 client ID
+    /// * `timestamp` - u64 send timestamp of the client
+    ///
+    /// # Returns
+    ///
+    /// `HeartbeatMessage` instance
+    ///
+    #[must_use]
+    pub const fn with_timestamp(client_id: u16, timestamp: u64) -> Self {
+        let message_type = MessageType::Heartbeat;
+        Self {
+            message_type,
+            client_id,
+            timestamp: Some(timestamp),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for HeartbeatMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `HeartbeatMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_heartbeat_message` function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded `HeartbeatMessage`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `HeartbeatMessage`
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_heartbeat_message(value)
+    }
+}
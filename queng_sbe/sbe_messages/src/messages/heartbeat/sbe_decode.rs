@@ -0,0 +1,45 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::decode_utils;
+use crate::{HeartbeatMessage, MessageType};
+use sbe_bindings::heartbeat_codec::{SBE_MIN_BLOCK_LENGTH, SBE_TEMPLATE_ID};
+use sbe_bindings::HeartbeatDecoder;
+
+/// Decodes a `HeartbeatMessage` from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded `HeartbeatMessage`
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError` if the buffer is too short, the header does not match,
+/// or a field holds an unexpected value
+///
+/// # Process
+///
+/// - Validate buffer length and header
+/// - Wrap header in `HeartbeatDecoder`
+/// - Decode `message_type` and validate
+/// - Decode `client_id`
+/// - Decode the optional `timestamp`, absent before schema version 7
+/// - Create and return `HeartbeatMessage`
+pub fn decode_heartbeat_message(buffer: &[u8]) -> Result<HeartbeatMessage, SbeDecodeError> {
+    let header = decode_utils::decode_header(buffer, SBE_TEMPLATE_ID, SBE_MIN_BLOCK_LENGTH)?;
+    let csg = HeartbeatDecoder::default().header(header);
+
+    let sbe_message_type = csg.message_type();
+    decode_utils::check_message_type(MessageType::Heartbeat, sbe_message_type as u16)?;
+
+    let client_id = csg.client_id();
+
+    let message = match csg.timestamp() {
+        Some(timestamp) => HeartbeatMessage::with_timestamp(client_id, timestamp),
+        None => HeartbeatMessage::new(client_id),
+    };
+
+    Ok(message)
+}
@@ -0,0 +1,12 @@
+use crate::messages::last_ohlcv_bar::LastOHLCVBarMessage;
+use std::fmt;
+
+impl fmt::Display for LastOHLCVBarMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "LastOHLCVBarMessage {{ symbol_id: {} }}",
+            self.symbol_id()
+        )
+    }
+}
@@ -0,0 +1,60 @@
+use crate::errors::SbeDecodeError;
+use crate::MessageType;
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LastOHLCVBarMessage {
+    message_type: MessageType,
+    symbol_id: u16,
+}
+
+impl LastOHLCVBarMessage {
+    /// Creates a new `LastOHLCVBarMessage` instance.
+    ///
+    /// Sets the `message_type` to `LastOHLCVBar`.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_id` - u16 symbol ID
+    ///
+    /// # Returns
+    ///
+    /// `LastOHLCVBarMessage` instance
+    ///
+    #[must_use]
+    pub const fn new(symbol_id: u16) -> Self {
+        let message_type = MessageType::LastOHLCVBar;
+        Self {
+            message_type,
+            symbol_id,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for LastOHLCVBarMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `LastOHLCVBarMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_last_ohlcv_bar_message` function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded `LastOHLCVBarMessage`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `LastOHLCVBarMessage`
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_last_ohlcv_bar_message(value)
+    }
+}
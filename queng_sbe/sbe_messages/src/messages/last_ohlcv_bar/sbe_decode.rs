@@ -0,0 +1,42 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::decode_utils;
+use crate::{LastOHLCVBarMessage, MessageType};
+use sbe_bindings::last_ohlcv_bar_codec::{SBE_MIN_BLOCK_LENGTH, SBE_TEMPLATE_ID};
+use sbe_bindings::LastOHLCVBarDecoder;
+
+/// Decodes a `LastOHLCVBarMessage` from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded `LastOHLCVBarMessage`
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError` if the buffer is too short, the header does not match,
+/// or a field holds an unexpected value
+///
+/// # Process
+///
+/// - Validate buffer length and header
+/// - Wrap header in `LastOHLCVBarDecoder`
+/// - Decode `message_type` and validate
+/// - Decode `symbol_id`
+/// - Create and return `LastOHLCVBarMessage`
+///
+pub fn decode_last_ohlcv_bar_message(buffer: &[u8]) -> Result<LastOHLCVBarMessage, SbeDecodeError> {
+    let header = decode_utils::decode_header(buffer, SBE_TEMPLATE_ID, SBE_MIN_BLOCK_LENGTH)?;
+    let csg = LastOHLCVBarDecoder::default().header(header);
+
+    let sbe_message_type = csg.message_type();
+    decode_utils::check_message_type(MessageType::LastOHLCVBar, sbe_message_type as u16)?;
+
+    let symbol_id = csg.symbol_id();
+
+    let message = LastOHLCVBarMessage::new(symbol_id);
+
+    Ok(message)
+}
@@ -0,0 +1,12 @@
+use crate::messages::last_trade_bar::LastTradeBarMessage;
+use std::fmt;
+
+impl fmt::Display for LastTradeBarMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "LastTradeBarMessage {{ symbol_id: {} }}",
+            self.symbol_id()
+        )
+    }
+}
@@ -0,0 +1,13 @@
+use crate::messages::last_trade_bar::LastTradeBarMessage;
+use crate::MessageType;
+
+impl LastTradeBarMessage {
+    #[must_use]
+    pub const fn message_type(&self) -> &MessageType {
+        &self.message_type
+    }
+    #[must_use]
+    pub const fn symbol_id(&self) -> u16 {
+        self.symbol_id
+    }
+}
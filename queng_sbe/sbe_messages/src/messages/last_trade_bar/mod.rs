@@ -0,0 +1,60 @@
+use crate::errors::SbeDecodeError;
+use crate::MessageType;
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LastTradeBarMessage {
+    message_type: MessageType,
+    symbol_id: u16,
+}
+
+impl LastTradeBarMessage {
+    /// Creates a new `LastTradeBarMessage` instance.
+    ///
+    /// Sets the `message_type` to `LastTradeBar`.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_id` - u16 symbol ID
+    ///
+    /// # Returns
+    ///
+    /// `LastTradeBarMessage` instance
+    ///
+    #[must_use]
+    pub const fn new(symbol_id: u16) -> Self {
+        let message_type = MessageType::LastTradeBar;
+        Self {
+            message_type,
+            symbol_id,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for LastTradeBarMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `LastTradeBarMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_last_trade_bar_message` function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded `LastTradeBarMessage`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `LastTradeBarMessage`
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_last_trade_bar_message(value)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::decode_utils;
+use crate::{LastTradeBarMessage, MessageType};
+use sbe_bindings::last_trade_bar_codec::{SBE_MIN_BLOCK_LENGTH, SBE_TEMPLATE_ID};
+use sbe_bindings::LastTradeBarDecoder;
+
+/// Decodes a `LastTradeBarMessage` from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded `LastTradeBarMessage`
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError` if the buffer is too short, the header does not match,
+/// or a field holds an unexpected value
+///
+/// # Process
+///
+/// - Validate buffer length and header
+/// - Wrap header in `LastTradeBarDecoder`
+/// - Decode `message_type` and validate
+/// - Decode `symbol_id`
+/// - Create and return `LastTradeBarMessage`
+///
+pub fn decode_last_trade_bar_message(buffer: &[u8]) -> Result<LastTradeBarMessage, SbeDecodeError> {
+    let header = decode_utils::decode_header(buffer, SBE_TEMPLATE_ID, SBE_MIN_BLOCK_LENGTH)?;
+    let csg = LastTradeBarDecoder::default().header(header);
+
+    let sbe_message_type = csg.message_type();
+    decode_utils::check_message_type(MessageType::LastTradeBar, sbe_message_type as u16)?;
+
+    let symbol_id = csg.symbol_id();
+
+    let message = LastTradeBarMessage::new(symbol_id);
+
+    Ok(message)
+}
@@ -1,5 +1,20 @@
 pub mod client_login;
+pub mod client_login_reply;
 pub mod client_logout;
+pub mod heartbeat;
+
+pub mod first_ohlcv_bar;
+pub mod first_trade_bar;
+pub mod last_ohlcv_bar;
+pub mod last_trade_bar;
+pub mod ohlcv_bar;
+pub mod order_book_snapshot;
+pub mod order_book_update;
+pub mod quote;
+pub mod start_data;
+pub mod stop_all_data;
+pub mod stop_data;
+pub mod trade_bar;
 
 pub mod error_client;
 pub mod error_data;
@@ -0,0 +1,12 @@
+use crate::messages::ohlcv_bar::OHLCVBarMessage;
+use std::fmt;
+
+impl fmt::Display for OHLCVBarMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "OHLCVBarMessage {{ symbol_id: {}, date_time: {}, open: {}, high: {}, low: {}, close: {}, volume: {} }}",
+            self.symbol_id, self.date_time, self.open, self.high, self.low, self.close, self.volume
+        )
+    }
+}
@@ -0,0 +1,39 @@
+use crate::messages::ohlcv_bar::OHLCVBarMessage;
+use crate::MessageType;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+impl OHLCVBarMessage {
+    #[must_use]
+    pub const fn message_type(&self) -> &MessageType {
+        &self.message_type
+    }
+    #[must_use]
+    pub const fn symbol_id(&self) -> u16 {
+        self.symbol_id
+    }
+    #[must_use]
+    pub const fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+    #[must_use]
+    pub const fn open(&self) -> Decimal {
+        self.open
+    }
+    #[must_use]
+    pub const fn high(&self) -> Decimal {
+        self.high
+    }
+    #[must_use]
+    pub const fn low(&self) -> Decimal {
+        self.low
+    }
+    #[must_use]
+    pub const fn close(&self) -> Decimal {
+        self.close
+    }
+    #[must_use]
+    pub const fn volume(&self) -> Decimal {
+        self.volume
+    }
+}
@@ -0,0 +1,125 @@
+use crate::errors::{SbeDecodeError, SbeValidationError};
+use crate::MessageType;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct OHLCVBarMessage {
+    message_type: MessageType,
+    symbol_id: u16,
+    date_time: DateTime<Utc>,
+    open: Decimal,
+    high: Decimal,
+    low: Decimal,
+    close: Decimal,
+    volume: Decimal,
+}
+
+impl OHLCVBarMessage {
+    /// Creates a new `OHLCVBarMessage` instance.
+    ///
+    /// Sets the `message_type` to `OHLCVBar`.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_id` - u16 symbol ID
+    /// * `date_time` - `DateTime<Utc>` open time of the bar
+    /// * `open` - `Decimal` open price
+    /// * `high` - `Decimal` high price
+    /// * `low` - `Decimal` low price
+    /// * `close` - `Decimal` close price
+    /// * `volume` - `Decimal` traded volume
+    ///
+    /// # Returns
+    ///
+    /// `OHLCVBarMessage` instance
+    ///
+    /// # Remarks
+    ///
+    /// The constructor does not validate the bar; call `validate` to check it.
+    /// Encoding and decoding always validate.
+    ///
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        symbol_id: u16,
+        date_time: DateTime<Utc>,
+        open: Decimal,
+        high: Decimal,
+        low: Decimal,
+        close: Decimal,
+        volume: Decimal,
+    ) -> Self {
+        let message_type = MessageType::OHLCVBar;
+        Self {
+            message_type,
+            symbol_id,
+            date_time,
+            open,
+            high,
+            low,
+            close,
+            volume,
+        }
+    }
+}
+
+impl OHLCVBarMessage {
+    /// Validates the bar.
+    ///
+    /// # Returns
+    ///
+    /// Ok if the high price is greater than or equal to the low price
+    /// and the volume is not negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeValidationError` if either check fails.
+    ///
+    pub fn validate(&self) -> Result<(), SbeValidationError> {
+        if self.high < self.low {
+            return Err(SbeValidationError(format!(
+                "[OHLCVBarMessage]: high {} is below low {} for symbol {}",
+                self.high, self.low, self.symbol_id
+            )));
+        }
+
+        if self.volume < Decimal::ZERO {
+            return Err(SbeValidationError(format!(
+                "[OHLCVBarMessage]: negative volume {} for symbol {}",
+                self.volume, self.symbol_id
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&[u8]> for OHLCVBarMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `OHLCVBarMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_ohlcv_bar_message` function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded `OHLCVBarMessage`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `OHLCVBarMessage`
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_ohlcv_bar_message(value)
+    }
+}
@@ -0,0 +1,62 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::{decimal_utils, decode_utils};
+use crate::{MessageType, OHLCVBarMessage};
+use chrono::DateTime;
+use sbe_bindings::ohlcv_bar_codec::{SBE_MIN_BLOCK_LENGTH, SBE_TEMPLATE_ID};
+use sbe_bindings::OHLCVBarDecoder;
+
+/// Decodes a `OHLCVBarMessage` from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded `OHLCVBarMessage`
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError` if the buffer is too short, the header does not match,
+/// the timestamp or a decimal is out of range, or the decoded bar fails validation
+///
+/// # Process
+///
+/// - Validate buffer length and header
+/// - Validate the schema version holds decimal prices and volumes
+/// - Wrap header in `OHLCVBarDecoder`
+/// - Decode `message_type` and validate
+/// - Decode `symbol_id` and `date_time` as microseconds since the Unix epoch
+/// - Decode open, high, low, close and volume as `Decimal`
+/// - Create, validate and return `OHLCVBarMessage`
+///
+pub fn decode_ohlcv_bar_message(buffer: &[u8]) -> Result<OHLCVBarMessage, SbeDecodeError> {
+    let header = decode_utils::decode_header(buffer, SBE_TEMPLATE_ID, SBE_MIN_BLOCK_LENGTH)?;
+    decode_utils::check_schema_version(&header, decode_utils::SBE_DECIMAL_SCHEMA_VERSION)?;
+    let csg = OHLCVBarDecoder::default().header(header);
+
+    let sbe_message_type = csg.message_type();
+    decode_utils::check_message_type(MessageType::OHLCVBar, sbe_message_type as u16)?;
+
+    let symbol_id = csg.symbol_id();
+
+    let micros = csg.date_time();
+    let date_time = DateTime::from_timestamp_micros(micros).ok_or_else(|| {
+        SbeDecodeError::InvalidMessage(format!(
+            "[OHLCVBarMessage]: date_time {micros} is out of range"
+        ))
+    })?;
+
+    let open = decimal_utils::decode_decimal("open", &csg.open_price_decoder())?;
+    let high = decimal_utils::decode_decimal("high", &csg.high_price_decoder())?;
+    let low = decimal_utils::decode_decimal("low", &csg.low_price_decoder())?;
+    let close = decimal_utils::decode_decimal("close", &csg.close_price_decoder())?;
+    let volume = decimal_utils::decode_decimal("volume", &csg.volume_decoder())?;
+
+    let message = OHLCVBarMessage::new(symbol_id, date_time, open, high, low, close, volume);
+    message
+        .validate()
+        .map_err(|e| SbeDecodeError::InvalidMessage(e.to_string()))?;
+
+    Ok(message)
+}
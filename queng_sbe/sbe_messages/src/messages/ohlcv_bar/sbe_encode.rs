@@ -0,0 +1,91 @@
+use crate::errors::SbeEncodeError;
+use crate::utils::decimal_utils;
+use crate::OHLCVBarMessage;
+use crate::SbeEncode;
+use sbe_bindings::MessageType as SbeMessageType;
+use sbe_bindings::{message_header_codec, Encoder, OHLCVBarEncoder, WriteBuf};
+
+impl OHLCVBarMessage {
+    /// Encodes a `OHLCVBarMessage` to a new byte buffer.
+    ///
+    /// # Returns
+    ///
+    /// (usize, `Vec<u8>`) - Tuple of encoded size and byte buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the bar fails validation or a decimal exceeds the 64 bit mantissa
+    ///
+    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
+        let mut buffer = Vec::new();
+        let limit = self.encode_into(&mut buffer)?;
+        Ok((limit, buffer))
+    }
+}
+
+impl SbeEncode for OHLCVBarMessage {
+    /// Encodes a `OHLCVBarMessage` into the given byte buffer, replacing its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - `OHLCVBarMessage` to encode
+    /// * `buffer` - Byte buffer to encode into, reused across calls
+    ///
+    /// # Returns
+    ///
+    /// usize - Encoded size
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the bar fails validation or a decimal exceeds the 64 bit mantissa
+    ///
+    /// # Process
+    ///
+    /// - Validate the bar
+    /// - Resize the buffer to 65 bytes
+    /// - Create default `OHLCVBarEncoder`
+    /// - Wrap buffer in `WriteBuf`
+    /// - Encode header
+    /// - Encode `message_type`
+    /// - Encode `symbol_id` and `date_time` as microseconds since the Unix epoch
+    /// - Encode open, high, low, close and volume as SBE decimal
+    /// - Return encoded size
+    ///
+    fn encode_into(&self, buffer: &mut Vec<u8>) -> Result<usize, SbeEncodeError> {
+        self.validate().map_err(|e| SbeEncodeError(e.to_string()))?;
+
+        // precise buffer size is 65 bytes for the entire message.
+        buffer.clear();
+        buffer.resize(65, 0);
+
+        let mut csg = OHLCVBarEncoder::default();
+
+        csg = csg.wrap(
+            WriteBuf::new(buffer.as_mut_slice()),
+            message_header_codec::ENCODED_LENGTH,
+        );
+
+        csg = csg
+            .header(0)
+            .parent()
+            .expect("[OHLCVBarMessage]: Failed to encode header");
+
+        let value = SbeMessageType::from(self.message_type as u16);
+        csg.message_type(value);
+
+        let value = self.symbol_id;
+        csg.symbol_id(value);
+
+        let value = self.date_time.timestamp_micros();
+        csg.date_time(value);
+
+        csg = decimal_utils::encode_decimal("open", csg.open_price_encoder(), self.open)?;
+        csg = decimal_utils::encode_decimal("high", csg.high_price_encoder(), self.high)?;
+        csg = decimal_utils::encode_decimal("low", csg.low_price_encoder(), self.low)?;
+        csg = decimal_utils::encode_decimal("close", csg.close_price_encoder(), self.close)?;
+        csg = decimal_utils::encode_decimal("volume", csg.volume_encoder(), self.volume)?;
+
+        let limit = csg.get_limit();
+        Ok(limit)
+    }
+}
@@ -0,0 +1,12 @@
+use crate::messages::order_book_snapshot::OrderBookSnapshotMessage;
+use std::fmt;
+
+impl fmt::Display for OrderBookSnapshotMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "OrderBookSnapshotMessage {{ symbol_id: {}, date_time: {}, sequence_number: {}, bids: {} levels, asks: {} levels }}",
+            self.symbol_id, self.date_time, self.sequence_number, self.bids.len(), self.asks.len()
+        )
+    }
+}
@@ -0,0 +1,38 @@
+use crate::messages::order_book_snapshot::OrderBookSnapshotMessage;
+use crate::{MessageType, OrderBookLevel};
+use chrono::{DateTime, Utc};
+
+impl OrderBookSnapshotMessage {
+    #[must_use]
+    pub const fn message_type(&self) -> &MessageType {
+        &self.message_type
+    }
+    #[must_use]
+    pub const fn symbol_id(&self) -> u16 {
+        self.symbol_id
+    }
+    #[must_use]
+    pub const fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+    #[must_use]
+    pub const fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+    #[must_use]
+    pub fn bids(&self) -> &[OrderBookLevel] {
+        &self.bids
+    }
+    #[must_use]
+    pub fn asks(&self) -> &[OrderBookLevel] {
+        &self.asks
+    }
+    #[must_use]
+    pub fn best_bid(&self) -> Option<&OrderBookLevel> {
+        self.bids.first()
+    }
+    #[must_use]
+    pub fn best_ask(&self) -> Option<&OrderBookLevel> {
+        self.asks.first()
+    }
+}
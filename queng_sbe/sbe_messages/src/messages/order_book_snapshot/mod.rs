@@ -0,0 +1,141 @@
+use crate::errors::{SbeDecodeError, SbeValidationError};
+use crate::{MessageType, OrderBookLevel};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct OrderBookSnapshotMessage {
+    message_type: MessageType,
+    symbol_id: u16,
+    date_time: DateTime<Utc>,
+    sequence_number: u64,
+    bids: Vec<OrderBookLevel>,
+    asks: Vec<OrderBookLevel>,
+}
+
+impl OrderBookSnapshotMessage {
+    /// Creates a new `OrderBookSnapshotMessage` instance.
+    ///
+    /// Sets the `message_type` to `OrderBookSnapshot`.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_id` - u16 symbol ID
+    /// * `date_time` - `DateTime<Utc>` time of the snapshot
+    /// * `sequence_number` - u64 sequence number of the last update included in the snapshot
+    /// * `bids` - `Vec<OrderBookLevel>` bid levels, best (highest) price first
+    /// * `asks` - `Vec<OrderBookLevel>` ask levels, best (lowest) price first
+    ///
+    /// # Returns
+    ///
+    /// `OrderBookSnapshotMessage` instance
+    ///
+    /// # Remarks
+    ///
+    /// The constructor does not validate the snapshot; call `validate` to check it.
+    /// Encoding and decoding always validate.
+    ///
+    #[must_use]
+    pub const fn new(
+        symbol_id: u16,
+        date_time: DateTime<Utc>,
+        sequence_number: u64,
+        bids: Vec<OrderBookLevel>,
+        asks: Vec<OrderBookLevel>,
+    ) -> Self {
+        let message_type = MessageType::OrderBookSnapshot;
+        Self {
+            message_type,
+            symbol_id,
+            date_time,
+            sequence_number,
+            bids,
+            asks,
+        }
+    }
+}
+
+impl OrderBookSnapshotMessage {
+    /// Validates the snapshot.
+    ///
+    /// # Returns
+    ///
+    /// Ok if each side holds at most `u16::MAX` levels, all sizes are positive,
+    /// bid prices are strictly descending and ask prices are strictly ascending.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeValidationError` if any check fails.
+    ///
+    pub fn validate(&self) -> Result<(), SbeValidationError> {
+        self.validate_side("bids", &self.bids, |prev, next| prev > next)?;
+        self.validate_side("asks", &self.asks, |prev, next| prev < next)
+    }
+
+    fn validate_side(
+        &self,
+        side: &str,
+        levels: &[OrderBookLevel],
+        is_ordered: fn(Decimal, Decimal) -> bool,
+    ) -> Result<(), SbeValidationError> {
+        if levels.len() > u16::MAX as usize {
+            return Err(SbeValidationError(format!(
+                "[OrderBookSnapshotMessage]: {} {side} exceed the maximum of {} levels for symbol {}",
+                levels.len(),
+                u16::MAX,
+                self.symbol_id
+            )));
+        }
+
+        if let Some(level) = levels.iter().find(|level| level.size() <= Decimal::ZERO) {
+            return Err(SbeValidationError(format!(
+                "[OrderBookSnapshotMessage]: non-positive size {} in {side} for symbol {}",
+                level.size(),
+                self.symbol_id
+            )));
+        }
+
+        if let Some(pair) = levels
+            .windows(2)
+            .find(|pair| !is_ordered(pair[0].price(), pair[1].price()))
+        {
+            return Err(SbeValidationError(format!(
+                "[OrderBookSnapshotMessage]: {side} are not sorted by price, {} before {} for symbol {}",
+                pair[0].price(),
+                pair[1].price(),
+                self.symbol_id
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&[u8]> for OrderBookSnapshotMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `OrderBookSnapshotMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_order_book_snapshot_message` function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded `OrderBookSnapshotMessage`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `OrderBookSnapshotMessage`
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_order_book_snapshot_message(value)
+    }
+}
@@ -0,0 +1,88 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::{decimal_utils, decode_utils};
+use crate::{MessageType, OrderBookLevel, OrderBookSnapshotMessage};
+use chrono::DateTime;
+use sbe_bindings::order_book_snapshot_codec::{
+    SBE_ASKS_BLOCK_LENGTH, SBE_BIDS_BLOCK_LENGTH, SBE_MIN_BLOCK_LENGTH, SBE_TEMPLATE_ID,
+};
+use sbe_bindings::{Decoder, OrderBookSnapshotDecoder};
+
+/// Decodes a `OrderBookSnapshotMessage` from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded `OrderBookSnapshotMessage`
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError` if the buffer is too short for the message or one of its groups,
+/// the header does not match, the timestamp or a decimal is out of range,
+/// or the decoded snapshot fails validation
+///
+/// # Process
+///
+/// - Validate buffer length and header
+/// - Wrap header in `OrderBookSnapshotDecoder`
+/// - Decode `message_type` and validate
+/// - Decode `symbol_id` and `date_time` as microseconds since the Unix epoch
+/// - Decode `sequence_number`
+/// - Validate the bounds of the bids group and decode all bid levels
+/// - Validate the bounds of the asks group and decode all ask levels
+/// - Create, validate and return `OrderBookSnapshotMessage`
+///
+pub fn decode_order_book_snapshot_message(
+    buffer: &[u8],
+) -> Result<OrderBookSnapshotMessage, SbeDecodeError> {
+    let header = decode_utils::decode_header(buffer, SBE_TEMPLATE_ID, SBE_MIN_BLOCK_LENGTH)?;
+    let csg = OrderBookSnapshotDecoder::default().header(header);
+
+    let sbe_message_type = csg.message_type();
+    decode_utils::check_message_type(MessageType::OrderBookSnapshot, sbe_message_type as u16)?;
+
+    let symbol_id = csg.symbol_id();
+
+    let micros = csg.date_time();
+    let date_time = DateTime::from_timestamp_micros(micros).ok_or_else(|| {
+        SbeDecodeError::InvalidMessage(format!(
+            "[OrderBookSnapshotMessage]: date_time {micros} is out of range"
+        ))
+    })?;
+
+    let sequence_number = csg.sequence_number();
+
+    let count = decode_utils::check_group(buffer, csg.get_limit(), SBE_BIDS_BLOCK_LENGTH)?;
+    let mut bids_decoder = csg.bids_decoder();
+    let mut bids = Vec::with_capacity(count as usize);
+    while bids_decoder.advance().map_err(group_error)?.is_some() {
+        let price = decimal_utils::decode_decimal("bid_price", &bids_decoder.price_decoder())?;
+        let size = decimal_utils::decode_decimal("bid_size", &bids_decoder.size_decoder())?;
+        bids.push(OrderBookLevel::new(price, size));
+    }
+    let csg = bids_decoder.parent().map_err(group_error)?;
+
+    let count = decode_utils::check_group(buffer, csg.get_limit(), SBE_ASKS_BLOCK_LENGTH)?;
+    let mut asks_decoder = csg.asks_decoder();
+    let mut asks = Vec::with_capacity(count as usize);
+    while asks_decoder.advance().map_err(group_error)?.is_some() {
+        let price = decimal_utils::decode_decimal("ask_price", &asks_decoder.price_decoder())?;
+        let size = decimal_utils::decode_decimal("ask_size", &asks_decoder.size_decoder())?;
+        asks.push(OrderBookLevel::new(price, size));
+    }
+
+    let message = OrderBookSnapshotMessage::new(symbol_id, date_time, sequence_number, bids, asks);
+    message
+        .validate()
+        .map_err(|e| SbeDecodeError::InvalidMessage(e.to_string()))?;
+
+    Ok(message)
+}
+
+fn group_error(e: sbe_bindings::SbeErr) -> SbeDecodeError {
+    SbeDecodeError::InvalidMessage(format!(
+        "[OrderBookSnapshotMessage]: Failed to decode levels: {e}"
+    ))
+}
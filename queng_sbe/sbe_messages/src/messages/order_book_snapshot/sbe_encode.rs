@@ -0,0 +1,130 @@
+use crate::errors::SbeEncodeError;
+use crate::utils::decimal_utils;
+use crate::OrderBookSnapshotMessage;
+use crate::SbeEncode;
+use sbe_bindings::order_book_snapshot_codec::{
+    SBE_ASKS_BLOCK_LENGTH, SBE_BIDS_BLOCK_LENGTH, SBE_BLOCK_LENGTH,
+};
+use sbe_bindings::MessageType as SbeMessageType;
+use sbe_bindings::{
+    group_size_encoding_codec, message_header_codec, Encoder, OrderBookSnapshotEncoder, WriteBuf,
+};
+
+impl OrderBookSnapshotMessage {
+    /// Encodes a `OrderBookSnapshotMessage` to a new byte buffer.
+    ///
+    /// # Returns
+    ///
+    /// (usize, `Vec<u8>`) - Tuple of encoded size and byte buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the snapshot fails validation or a decimal exceeds the 64 bit mantissa
+    ///
+    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
+        let mut buffer = Vec::new();
+        let limit = self.encode_into(&mut buffer)?;
+        Ok((limit, buffer))
+    }
+}
+
+impl SbeEncode for OrderBookSnapshotMessage {
+    /// Encodes a `OrderBookSnapshotMessage` into the given byte buffer, replacing its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - `OrderBookSnapshotMessage` to encode
+    /// * `buffer` - Byte buffer to encode into, reused across calls
+    ///
+    /// # Returns
+    ///
+    /// usize - Encoded size
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the snapshot fails validation or a decimal exceeds the 64 bit mantissa
+    ///
+    /// # Process
+    ///
+    /// - Validate the snapshot
+    /// - Resize the buffer to fit the header, the message block and both groups
+    /// - Create default `OrderBookSnapshotEncoder`
+    /// - Wrap buffer in `WriteBuf`
+    /// - Encode header
+    /// - Encode `message_type`
+    /// - Encode `symbol_id` and `date_time` as microseconds since the Unix epoch
+    /// - Encode `sequence_number`
+    /// - Encode the bids group, then the asks group, with price and size as SBE decimal
+    /// - Return encoded size
+    ///
+    fn encode_into(&self, buffer: &mut Vec<u8>) -> Result<usize, SbeEncodeError> {
+        self.validate().map_err(|e| SbeEncodeError(e.to_string()))?;
+
+        // precise buffer size depends on the number of levels per side.
+        let size = message_header_codec::ENCODED_LENGTH
+            + SBE_BLOCK_LENGTH as usize
+            + 2 * group_size_encoding_codec::ENCODED_LENGTH
+            + self.bids.len() * SBE_BIDS_BLOCK_LENGTH as usize
+            + self.asks.len() * SBE_ASKS_BLOCK_LENGTH as usize;
+        buffer.clear();
+        buffer.resize(size, 0);
+
+        let mut csg = OrderBookSnapshotEncoder::default();
+
+        csg = csg.wrap(
+            WriteBuf::new(buffer.as_mut_slice()),
+            message_header_codec::ENCODED_LENGTH,
+        );
+
+        csg = csg
+            .header(0)
+            .parent()
+            .expect("[OrderBookSnapshotMessage]: Failed to encode header");
+
+        let value = SbeMessageType::from(self.message_type as u16);
+        csg.message_type(value);
+
+        let value = self.symbol_id;
+        csg.symbol_id(value);
+
+        let value = self.date_time.timestamp_micros();
+        csg.date_time(value);
+
+        let value = self.sequence_number;
+        csg.sequence_number(value);
+
+        let mut bids = csg.bids_encoder(level_count(self.bids.len())?);
+        for level in &self.bids {
+            bids.advance().map_err(group_error)?;
+            bids = decimal_utils::encode_decimal("bid_price", bids.price_encoder(), level.price())?;
+            bids = decimal_utils::encode_decimal("bid_size", bids.size_encoder(), level.size())?;
+        }
+        csg = bids.parent().map_err(group_error)?;
+
+        let mut asks = csg.asks_encoder(level_count(self.asks.len())?);
+        for level in &self.asks {
+            asks.advance().map_err(group_error)?;
+            asks = decimal_utils::encode_decimal("ask_price", asks.price_encoder(), level.price())?;
+            asks = decimal_utils::encode_decimal("ask_size", asks.size_encoder(), level.size())?;
+        }
+        csg = asks.parent().map_err(group_error)?;
+
+        let limit = csg.get_limit();
+        Ok(limit)
+    }
+}
+
+fn level_count(len: usize) -> Result<u16, SbeEncodeError> {
+    u16::try_from(len).map_err(|_| {
+        SbeEncodeError(format!(
+            "[OrderBookSnapshotMessage]: {len} levels exceed the maximum of {}",
+            u16::MAX
+        ))
+    })
+}
+
+fn group_error(e: sbe_bindings::SbeErr) -> SbeEncodeError {
+    SbeEncodeError(format!(
+        "[OrderBookSnapshotMessage]: Failed to encode levels: {e}"
+    ))
+}
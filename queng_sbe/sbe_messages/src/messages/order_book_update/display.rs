@@ -0,0 +1,12 @@
+use crate::messages::order_book_update::OrderBookUpdateMessage;
+use std::fmt;
+
+impl fmt::Display for OrderBookUpdateMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "OrderBookUpdateMessage {{ symbol_id: {}, date_time: {}, sequence_number: {}, levels: {} }}",
+            self.symbol_id, self.date_time, self.sequence_number, self.levels.len()
+        )
+    }
+}
@@ -0,0 +1,26 @@
+use crate::messages::order_book_update::OrderBookUpdateMessage;
+use crate::{MessageType, OrderBookLevelUpdate};
+use chrono::{DateTime, Utc};
+
+impl OrderBookUpdateMessage {
+    #[must_use]
+    pub const fn message_type(&self) -> &MessageType {
+        &self.message_type
+    }
+    #[must_use]
+    pub const fn symbol_id(&self) -> u16 {
+        self.symbol_id
+    }
+    #[must_use]
+    pub const fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+    #[must_use]
+    pub const fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+    #[must_use]
+    pub fn levels(&self) -> &[OrderBookLevelUpdate] {
+        &self.levels
+    }
+}
@@ -0,0 +1,127 @@
+use crate::errors::{SbeDecodeError, SbeValidationError};
+use crate::{BookSide, MessageType, OrderBookLevelUpdate};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct OrderBookUpdateMessage {
+    message_type: MessageType,
+    symbol_id: u16,
+    date_time: DateTime<Utc>,
+    sequence_number: u64,
+    levels: Vec<OrderBookLevelUpdate>,
+}
+
+impl OrderBookUpdateMessage {
+    /// Creates a new `OrderBookUpdateMessage` instance.
+    ///
+    /// Sets the `message_type` to `OrderBookUpdate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_id` - u16 symbol ID
+    /// * `date_time` - `DateTime<Utc>` time of the update
+    /// * `sequence_number` - u64 sequence number of the update; a gap to the previous
+    ///   update or snapshot means an update was missed and the book must be rebuilt
+    /// * `levels` - `Vec<OrderBookLevelUpdate>` changed levels of both sides
+    ///
+    /// # Returns
+    ///
+    /// `OrderBookUpdateMessage` instance
+    ///
+    /// # Remarks
+    ///
+    /// The constructor does not validate the update; call `validate` to check it.
+    /// Encoding and decoding always validate.
+    ///
+    #[must_use]
+    pub const fn new(
+        symbol_id: u16,
+        date_time: DateTime<Utc>,
+        sequence_number: u64,
+        levels: Vec<OrderBookLevelUpdate>,
+    ) -> Self {
+        let message_type = MessageType::OrderBookUpdate;
+        Self {
+            message_type,
+            symbol_id,
+            date_time,
+            sequence_number,
+            levels,
+        }
+    }
+}
+
+impl OrderBookUpdateMessage {
+    /// Validates the update.
+    ///
+    /// # Returns
+    ///
+    /// Ok if the update holds at most `u16::MAX` levels,
+    /// every level has a side and no size is negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeValidationError` if any check fails.
+    ///
+    pub fn validate(&self) -> Result<(), SbeValidationError> {
+        if self.levels.len() > u16::MAX as usize {
+            return Err(SbeValidationError(format!(
+                "[OrderBookUpdateMessage]: {} levels exceed the maximum of {} for symbol {}",
+                self.levels.len(),
+                u16::MAX,
+                self.symbol_id
+            )));
+        }
+
+        for level in &self.levels {
+            if level.side() == BookSide::NullVal {
+                return Err(SbeValidationError(format!(
+                    "[OrderBookUpdateMessage]: level at price {} has no side for symbol {}",
+                    level.price(),
+                    self.symbol_id
+                )));
+            }
+
+            if level.size() < Decimal::ZERO {
+                return Err(SbeValidationError(format!(
+                    "[OrderBookUpdateMessage]: negative size {} at price {} for symbol {}",
+                    level.size(),
+                    level.price(),
+                    self.symbol_id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&[u8]> for OrderBookUpdateMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `OrderBookUpdateMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_order_book_update_message` function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded `OrderBookUpdateMessage`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `OrderBookUpdateMessage`
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_order_book_update_message(value)
+    }
+}
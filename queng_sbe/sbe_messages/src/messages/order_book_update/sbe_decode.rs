@@ -0,0 +1,80 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::{decimal_utils, decode_utils};
+use crate::{BookSide, MessageType, OrderBookLevelUpdate, OrderBookUpdateMessage};
+use chrono::DateTime;
+use sbe_bindings::order_book_update_codec::{
+    SBE_LEVELS_BLOCK_LENGTH, SBE_MIN_BLOCK_LENGTH, SBE_TEMPLATE_ID,
+};
+use sbe_bindings::{Decoder, OrderBookUpdateDecoder};
+
+/// Decodes a `OrderBookUpdateMessage` from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded `OrderBookUpdateMessage`
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError` if the buffer is too short for the message or its levels,
+/// the header does not match, a side is unknown, the timestamp or a decimal is out of range,
+/// or the decoded update fails validation
+///
+/// # Process
+///
+/// - Validate buffer length and header
+/// - Wrap header in `OrderBookUpdateDecoder`
+/// - Decode `message_type` and validate
+/// - Decode `symbol_id` and `date_time` as microseconds since the Unix epoch
+/// - Decode `sequence_number`
+/// - Validate the bounds of the levels group and decode all levels
+/// - Create, validate and return `OrderBookUpdateMessage`
+///
+pub fn decode_order_book_update_message(
+    buffer: &[u8],
+) -> Result<OrderBookUpdateMessage, SbeDecodeError> {
+    let header = decode_utils::decode_header(buffer, SBE_TEMPLATE_ID, SBE_MIN_BLOCK_LENGTH)?;
+    let csg = OrderBookUpdateDecoder::default().header(header);
+
+    let sbe_message_type = csg.message_type();
+    decode_utils::check_message_type(MessageType::OrderBookUpdate, sbe_message_type as u16)?;
+
+    let symbol_id = csg.symbol_id();
+
+    let micros = csg.date_time();
+    let date_time = DateTime::from_timestamp_micros(micros).ok_or_else(|| {
+        SbeDecodeError::InvalidMessage(format!(
+            "[OrderBookUpdateMessage]: date_time {micros} is out of range"
+        ))
+    })?;
+
+    let sequence_number = csg.sequence_number();
+
+    let count = decode_utils::check_group(buffer, csg.get_limit(), SBE_LEVELS_BLOCK_LENGTH)?;
+    let mut levels_decoder = csg.levels_decoder();
+    let mut levels = Vec::with_capacity(count as usize);
+    while levels_decoder.advance().map_err(group_error)?.is_some() {
+        let side_raw = levels_decoder.side();
+        let side = BookSide::from(side_raw);
+        decode_utils::check_enum_value("side", side_raw, side as u8)?;
+        let price = decimal_utils::decode_decimal("price", &levels_decoder.price_decoder())?;
+        let size = decimal_utils::decode_decimal("size", &levels_decoder.size_decoder())?;
+        levels.push(OrderBookLevelUpdate::new(side, price, size));
+    }
+
+    let message = OrderBookUpdateMessage::new(symbol_id, date_time, sequence_number, levels);
+    message
+        .validate()
+        .map_err(|e| SbeDecodeError::InvalidMessage(e.to_string()))?;
+
+    Ok(message)
+}
+
+fn group_error(e: sbe_bindings::SbeErr) -> SbeDecodeError {
+    SbeDecodeError::InvalidMessage(format!(
+        "[OrderBookUpdateMessage]: Failed to decode levels: {e}"
+    ))
+}
@@ -0,0 +1,119 @@
+use crate::errors::SbeEncodeError;
+use crate::utils::decimal_utils;
+use crate::OrderBookUpdateMessage;
+use crate::SbeEncode;
+use sbe_bindings::order_book_update_codec::{SBE_BLOCK_LENGTH, SBE_LEVELS_BLOCK_LENGTH};
+use sbe_bindings::MessageType as SbeMessageType;
+use sbe_bindings::{
+    group_size_encoding_codec, message_header_codec, Encoder, OrderBookUpdateEncoder, WriteBuf,
+};
+
+impl OrderBookUpdateMessage {
+    /// Encodes a `OrderBookUpdateMessage` to a new byte buffer.
+    ///
+    /// # Returns
+    ///
+    /// (usize, `Vec<u8>`) - Tuple of encoded size and byte buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the update fails validation or a decimal exceeds the 64 bit mantissa
+    ///
+    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
+        let mut buffer = Vec::new();
+        let limit = self.encode_into(&mut buffer)?;
+        Ok((limit, buffer))
+    }
+}
+
+impl SbeEncode for OrderBookUpdateMessage {
+    /// Encodes a `OrderBookUpdateMessage` into the given byte buffer, replacing its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - `OrderBookUpdateMessage` to encode
+    /// * `buffer` - Byte buffer to encode into, reused across calls
+    ///
+    /// # Returns
+    ///
+    /// usize - Encoded size
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the update fails validation or a decimal exceeds the 64 bit mantissa
+    ///
+    /// # Process
+    ///
+    /// - Validate the update
+    /// - Resize the buffer to fit the header, the message block and the levels group
+    /// - Create default `OrderBookUpdateEncoder`
+    /// - Wrap buffer in `WriteBuf`
+    /// - Encode header
+    /// - Encode `message_type`
+    /// - Encode `symbol_id` and `date_time` as microseconds since the Unix epoch
+    /// - Encode `sequence_number`
+    /// - Encode the levels group with side, and price and size as SBE decimal
+    /// - Return encoded size
+    ///
+    fn encode_into(&self, buffer: &mut Vec<u8>) -> Result<usize, SbeEncodeError> {
+        self.validate().map_err(|e| SbeEncodeError(e.to_string()))?;
+
+        // precise buffer size depends on the number of levels.
+        let size = message_header_codec::ENCODED_LENGTH
+            + SBE_BLOCK_LENGTH as usize
+            + group_size_encoding_codec::ENCODED_LENGTH
+            + self.levels.len() * SBE_LEVELS_BLOCK_LENGTH as usize;
+        buffer.clear();
+        buffer.resize(size, 0);
+
+        let mut csg = OrderBookUpdateEncoder::default();
+
+        csg = csg.wrap(
+            WriteBuf::new(buffer.as_mut_slice()),
+            message_header_codec::ENCODED_LENGTH,
+        );
+
+        csg = csg
+            .header(0)
+            .parent()
+            .expect("[OrderBookUpdateMessage]: Failed to encode header");
+
+        let value = SbeMessageType::from(self.message_type as u16);
+        csg.message_type(value);
+
+        let value = self.symbol_id;
+        csg.symbol_id(value);
+
+        let value = self.date_time.timestamp_micros();
+        csg.date_time(value);
+
+        let value = self.sequence_number;
+        csg.sequence_number(value);
+
+        let count = u16::try_from(self.levels.len()).map_err(|_| {
+            SbeEncodeError(format!(
+                "[OrderBookUpdateMessage]: {} levels exceed the maximum of {}",
+                self.levels.len(),
+                u16::MAX
+            ))
+        })?;
+
+        let mut levels = csg.levels_encoder(count);
+        for level in &self.levels {
+            levels.advance().map_err(group_error)?;
+            levels.side(level.side() as u8);
+            levels = decimal_utils::encode_decimal("price", levels.price_encoder(), level.price())?;
+            levels = decimal_utils::encode_decimal("size", levels.size_encoder(), level.size())?;
+        }
+        csg = levels.parent().map_err(group_error)?;
+
+        let limit = csg.get_limit();
+        Ok(limit)
+    }
+}
+
+fn group_error(e: sbe_bindings::SbeErr) -> SbeEncodeError {
+    SbeEncodeError(format!(
+        "[OrderBookUpdateMessage]: Failed to encode levels: {e}"
+    ))
+}
@@ -0,0 +1,12 @@
+use crate::messages::quote::QuoteMessage;
+use std::fmt;
+
+impl fmt::Display for QuoteMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "QuoteMessage {{ symbol_id: {}, date_time: {}, bid_price: {}, bid_size: {}, ask_price: {}, ask_size: {} }}",
+            self.symbol_id, self.date_time, self.bid_price, self.bid_size, self.ask_price, self.ask_size
+        )
+    }
+}
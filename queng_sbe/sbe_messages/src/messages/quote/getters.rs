@@ -0,0 +1,35 @@
+use crate::messages::quote::QuoteMessage;
+use crate::MessageType;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+impl QuoteMessage {
+    #[must_use]
+    pub const fn message_type(&self) -> &MessageType {
+        &self.message_type
+    }
+    #[must_use]
+    pub const fn symbol_id(&self) -> u16 {
+        self.symbol_id
+    }
+    #[must_use]
+    pub const fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+    #[must_use]
+    pub const fn bid_price(&self) -> Decimal {
+        self.bid_price
+    }
+    #[must_use]
+    pub const fn bid_size(&self) -> Decimal {
+        self.bid_size
+    }
+    #[must_use]
+    pub const fn ask_price(&self) -> Decimal {
+        self.ask_price
+    }
+    #[must_use]
+    pub const fn ask_size(&self) -> Decimal {
+        self.ask_size
+    }
+}
@@ -0,0 +1,121 @@
+use crate::errors::{SbeDecodeError, SbeValidationError};
+use crate::MessageType;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct QuoteMessage {
+    message_type: MessageType,
+    symbol_id: u16,
+    date_time: DateTime<Utc>,
+    bid_price: Decimal,
+    bid_size: Decimal,
+    ask_price: Decimal,
+    ask_size: Decimal,
+}
+
+impl QuoteMessage {
+    /// Creates a new `QuoteMessage` instance.
+    ///
+    /// Sets the `message_type` to `Quote`.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_id` - u16 symbol ID
+    /// * `date_time` - `DateTime<Utc>` time of the quote
+    /// * `bid_price` - `Decimal` best bid price
+    /// * `bid_size` - `Decimal` size at the best bid
+    /// * `ask_price` - `Decimal` best ask price
+    /// * `ask_size` - `Decimal` size at the best ask
+    ///
+    /// # Returns
+    ///
+    /// `QuoteMessage` instance
+    ///
+    /// # Remarks
+    ///
+    /// The constructor does not validate the quote; call `validate` to check it.
+    /// Encoding and decoding always validate.
+    ///
+    #[must_use]
+    pub const fn new(
+        symbol_id: u16,
+        date_time: DateTime<Utc>,
+        bid_price: Decimal,
+        bid_size: Decimal,
+        ask_price: Decimal,
+        ask_size: Decimal,
+    ) -> Self {
+        let message_type = MessageType::Quote;
+        Self {
+            message_type,
+            symbol_id,
+            date_time,
+            bid_price,
+            bid_size,
+            ask_price,
+            ask_size,
+        }
+    }
+}
+
+impl QuoteMessage {
+    /// Validates the quote.
+    ///
+    /// # Returns
+    ///
+    /// Ok if neither size is negative and, when both sides are quoted,
+    /// the bid price is not above the ask price.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeValidationError` if either check fails.
+    ///
+    pub fn validate(&self) -> Result<(), SbeValidationError> {
+        if self.bid_size < Decimal::ZERO || self.ask_size < Decimal::ZERO {
+            return Err(SbeValidationError(format!(
+                "[QuoteMessage]: negative size, bid {} ask {} for symbol {}",
+                self.bid_size, self.ask_size, self.symbol_id
+            )));
+        }
+
+        let is_two_sided = !self.bid_size.is_zero() && !self.ask_size.is_zero();
+        if is_two_sided && self.bid_price > self.ask_price {
+            return Err(SbeValidationError(format!(
+                "[QuoteMessage]: bid {} is above ask {} for symbol {}",
+                self.bid_price, self.ask_price, self.symbol_id
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&[u8]> for QuoteMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `QuoteMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_quote_message` function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded `QuoteMessage`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `QuoteMessage`
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_quote_message(value)
+    }
+}
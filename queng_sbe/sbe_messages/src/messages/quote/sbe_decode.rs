@@ -0,0 +1,61 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::{decimal_utils, decode_utils};
+use crate::{MessageType, QuoteMessage};
+use chrono::DateTime;
+use sbe_bindings::quote_codec::{SBE_MIN_BLOCK_LENGTH, SBE_TEMPLATE_ID};
+use sbe_bindings::QuoteDecoder;
+
+/// Decodes a `QuoteMessage` from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded `QuoteMessage`
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError` if the buffer is too short, the header does not match,
+/// the timestamp or a decimal is out of range, or the decoded quote fails validation
+///
+/// # Process
+///
+/// - Validate buffer length and header
+/// - Wrap header in `QuoteDecoder`
+/// - Decode `message_type` and validate
+/// - Decode `symbol_id` and `date_time` as microseconds since the Unix epoch
+/// - Decode bid price and size, then ask price and size as `Decimal`
+/// - Create, validate and return `QuoteMessage`
+///
+pub fn decode_quote_message(buffer: &[u8]) -> Result<QuoteMessage, SbeDecodeError> {
+    let header = decode_utils::decode_header(buffer, SBE_TEMPLATE_ID, SBE_MIN_BLOCK_LENGTH)?;
+    let csg = QuoteDecoder::default().header(header);
+
+    let sbe_message_type = csg.message_type();
+    decode_utils::check_message_type(MessageType::Quote, sbe_message_type as u16)?;
+
+    let symbol_id = csg.symbol_id();
+
+    let micros = csg.date_time();
+    let date_time = DateTime::from_timestamp_micros(micros).ok_or_else(|| {
+        SbeDecodeError::InvalidMessage(format!(
+            "[QuoteMessage]: date_time {micros} is out of range"
+        ))
+    })?;
+
+    let bid_price = decimal_utils::decode_decimal("bid_price", &csg.bid_price_decoder())?;
+    let bid_size = decimal_utils::decode_decimal("bid_size", &csg.bid_size_decoder())?;
+    let ask_price = decimal_utils::decode_decimal("ask_price", &csg.ask_price_decoder())?;
+    let ask_size = decimal_utils::decode_decimal("ask_size", &csg.ask_size_decoder())?;
+
+    let message = QuoteMessage::new(
+        symbol_id, date_time, bid_price, bid_size, ask_price, ask_size,
+    );
+    message
+        .validate()
+        .map_err(|e| SbeDecodeError::InvalidMessage(e.to_string()))?;
+
+    Ok(message)
+}
@@ -0,0 +1,90 @@
+use crate::errors::SbeEncodeError;
+use crate::utils::decimal_utils;
+use crate::QuoteMessage;
+use crate::SbeEncode;
+use sbe_bindings::MessageType as SbeMessageType;
+use sbe_bindings::{message_header_codec, Encoder, QuoteEncoder, WriteBuf};
+
+impl QuoteMessage {
+    /// Encodes a `QuoteMessage` to a new byte buffer.
+    ///
+    /// # Returns
+    ///
+    /// (usize, `Vec<u8>`) - Tuple of encoded size and byte buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the quote fails validation or a decimal exceeds the 64 bit mantissa
+    ///
+    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
+        let mut buffer = Vec::new();
+        let limit = self.encode_into(&mut buffer)?;
+        Ok((limit, buffer))
+    }
+}
+
+impl SbeEncode for QuoteMessage {
+    /// Encodes a `QuoteMessage` into the given byte buffer, replacing its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - `QuoteMessage` to encode
+    /// * `buffer` - Byte buffer to encode into, reused across calls
+    ///
+    /// # Returns
+    ///
+    /// usize - Encoded size
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the quote fails validation or a decimal exceeds the 64 bit mantissa
+    ///
+    /// # Process
+    ///
+    /// - Validate the quote
+    /// - Resize the buffer to 56 bytes
+    /// - Create default `QuoteEncoder`
+    /// - Wrap buffer in `WriteBuf`
+    /// - Encode header
+    /// - Encode `message_type`
+    /// - Encode `symbol_id` and `date_time` as microseconds since the Unix epoch
+    /// - Encode bid price and size, then ask price and size as SBE decimal
+    /// - Return encoded size
+    ///
+    fn encode_into(&self, buffer: &mut Vec<u8>) -> Result<usize, SbeEncodeError> {
+        self.validate().map_err(|e| SbeEncodeError(e.to_string()))?;
+
+        // precise buffer size is 56 bytes for the entire message.
+        buffer.clear();
+        buffer.resize(56, 0);
+
+        let mut csg = QuoteEncoder::default();
+
+        csg = csg.wrap(
+            WriteBuf::new(buffer.as_mut_slice()),
+            message_header_codec::ENCODED_LENGTH,
+        );
+
+        csg = csg
+            .header(0)
+            .parent()
+            .expect("[QuoteMessage]: Failed to encode header");
+
+        let value = SbeMessageType::from(self.message_type as u16);
+        csg.message_type(value);
+
+        let value = self.symbol_id;
+        csg.symbol_id(value);
+
+        let value = self.date_time.timestamp_micros();
+        csg.date_time(value);
+
+        csg = decimal_utils::encode_decimal("bid_price", csg.bid_price_encoder(), self.bid_price)?;
+        csg = decimal_utils::encode_decimal("bid_size", csg.bid_size_encoder(), self.bid_size)?;
+        csg = decimal_utils::encode_decimal("ask_price", csg.ask_price_encoder(), self.ask_price)?;
+        csg = decimal_utils::encode_decimal("ask_size", csg.ask_size_encoder(), self.ask_size)?;
+
+        let limit = csg.get_limit();
+        Ok(limit)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::errors::SbeDecodeError;
+use crate::{DataType, ExchangeID, MessageType, TimeResolution};
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StartDataMessage {
+    message_type: MessageType,
+    client_id: u16,
+    exchange_id: ExchangeID,
+    symbol_id: u16,
+    time_resolution: TimeResolution,
+    data_type_id: DataType,
+}
+
+impl StartDataMessage {
+    /// Creates a new `StartDataMessage` instance.
+    ///
+    /// Sets the `message_type` to `StartData`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - u16 client ID
+    /// * `exchange_id` - `ExchangeID` of the exchange to stream data from
+    /// * `symbol_id` - u16 symbol ID
+    /// * `time_resolution` - `TimeResolution` of the requested data
+    /// * `data_type_id` - `DataType` of the requested data
+    ///
+    /// # Returns
+    ///
+    /// `StartDataMessage` instance
+    ///
+    #[must_use]
+    pub const fn new(
+        client_id: u16,
+        exchange_id: ExchangeID,
+        symbol_id: u16,
+        time_resolution: TimeResolution,
+        data_type_id: DataType,
+    ) -> Self {
+        let message_type = MessageType::StartData;
+        Self {
+            message_type,
+            client_id,
+            exchange_id,
+            symbol_id,
+            time_resolution,
+            data_type_id,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for StartDataMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `StartDataMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_start_data_message` function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded `StartDataMessage`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `StartDataMessage`
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_start_data_message(value)
+    }
+}
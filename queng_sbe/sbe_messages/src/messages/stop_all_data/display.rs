@@ -0,0 +1,12 @@
+use crate::messages::stop_all_data::StopAllDataMessage;
+use std::fmt;
+
+impl fmt::Display for StopAllDataMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "StopAllDataMessage {{ client_id: {}, exchange_id: {} }}",
+            self.client_id, self.exchange_id
+        )
+    }
+}
@@ -0,0 +1,63 @@
+use crate::errors::SbeDecodeError;
+use crate::{ExchangeID, MessageType};
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StopAllDataMessage {
+    message_type: MessageType,
+    client_id: u16,
+    exchange_id: ExchangeID,
+}
+
+impl StopAllDataMessage {
+    /// Creates a new `StopAllDataMessage` instance.
+    ///
+    /// Sets the `message_type` to `StopAllData`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - u16 client ID
+    /// * `exchange_id` - `ExchangeID` of the exchange to stop all data from
+    ///
+    /// # Returns
+    ///
+    /// `StopAllDataMessage` instance
+    ///
+    #[must_use]
+    pub const fn new(client_id: u16, exchange_id: ExchangeID) -> Self {
+        let message_type = MessageType::StopAllData;
+        Self {
+            message_type,
+            client_id,
+            exchange_id,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for StopAllDataMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `StopAllDataMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_stop_all_data_message` function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded `StopAllDataMessage`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `StopAllDataMessage`
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_stop_all_data_message(value)
+    }
+}
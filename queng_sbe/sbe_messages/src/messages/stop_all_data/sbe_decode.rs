@@ -0,0 +1,45 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::decode_utils;
+use crate::{ExchangeID, MessageType, StopAllDataMessage};
+use sbe_bindings::stop_all_data_msg_codec::{SBE_MIN_BLOCK_LENGTH, SBE_TEMPLATE_ID};
+use sbe_bindings::StopAllDataMsgDecoder;
+
+/// Decodes a `StopAllDataMessage` from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded `StopAllDataMessage`
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError` if the buffer is too short, the header does not match,
+/// or a field holds an unexpected value
+///
+/// # Process
+///
+/// - Validate buffer length and header
+/// - Wrap header in `StopAllDataMsgDecoder`
+/// - Decode `message_type` and validate
+/// - Decode `client_id` and `exchange_id`
+/// - Create and return `StopAllDataMessage`
+///
+pub fn decode_stop_all_data_message(buffer: &[u8]) -> Result<StopAllDataMessage, SbeDecodeError> {
+    let header = decode_utils::decode_header(buffer, SBE_TEMPLATE_ID, SBE_MIN_BLOCK_LENGTH)?;
+    let csg = StopAllDataMsgDecoder::default().header(header);
+
+    let sbe_message_type = csg.message_type();
+    decode_utils::check_message_type(MessageType::StopAllData, sbe_message_type as u16)?;
+
+    let client_id = csg.client_id();
+    let exchange_id_raw = csg.exchange_id();
+    let exchange_id = ExchangeID::from(exchange_id_raw);
+    decode_utils::check_enum_value("exchange_id", exchange_id_raw, exchange_id as u8)?;
+
+    let message = StopAllDataMessage::new(client_id, exchange_id);
+
+    Ok(message)
+}
@@ -0,0 +1,12 @@
+use crate::messages::stop_data::StopDataMessage;
+use std::fmt;
+
+impl fmt::Display for StopDataMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "StopDataMessage {{ client_id: {}, exchange_id: {}, symbol_id: {}, data_type_id: {} }}",
+            self.client_id, self.exchange_id, self.symbol_id, self.data_type_id
+        )
+    }
+}
@@ -0,0 +1,74 @@
+use crate::errors::SbeDecodeError;
+use crate::{DataType, ExchangeID, MessageType};
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StopDataMessage {
+    message_type: MessageType,
+    client_id: u16,
+    exchange_id: ExchangeID,
+    symbol_id: u16,
+    data_type_id: DataType,
+}
+
+impl StopDataMessage {
+    /// Creates a new `StopDataMessage` instance.
+    ///
+    /// Sets the `message_type` to `StopData`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - u16 client ID
+    /// * `exchange_id` - `ExchangeID` of the exchange the data is streamed from
+    /// * `symbol_id` - u16 symbol ID
+    /// * `data_type_id` - `DataType` of the data to stop
+    ///
+    /// # Returns
+    ///
+    /// `StopDataMessage` instance
+    ///
+    #[must_use]
+    pub const fn new(
+        client_id: u16,
+        exchange_id: ExchangeID,
+        symbol_id: u16,
+        data_type_id: DataType,
+    ) -> Self {
+        let message_type = MessageType::StopData;
+        Self {
+            message_type,
+            client_id,
+            exchange_id,
+            symbol_id,
+            data_type_id,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for StopDataMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `StopDataMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_stop_data_message` function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded `StopDataMessage`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `StopDataMessage`
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_stop_data_message(value)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::decode_utils;
+use crate::{DataType, ExchangeID, MessageType, StopDataMessage};
+use sbe_bindings::stop_data_msg_codec::{SBE_MIN_BLOCK_LENGTH, SBE_TEMPLATE_ID};
+use sbe_bindings::StopDataMsgDecoder;
+
+/// Decodes a `StopDataMessage` from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded `StopDataMessage`
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError` if the buffer is too short, the header does not match,
+/// or a field holds an unexpected value
+///
+/// # Process
+///
+/// - Validate buffer length and header
+/// - Wrap header in `StopDataMsgDecoder`
+/// - Decode `message_type` and validate
+/// - Decode `client_id`, `exchange_id`, `symbol_id` and `data_type_id`
+/// - Create and return `StopDataMessage`
+///
+pub fn decode_stop_data_message(buffer: &[u8]) -> Result<StopDataMessage, SbeDecodeError> {
+    let header = decode_utils::decode_header(buffer, SBE_TEMPLATE_ID, SBE_MIN_BLOCK_LENGTH)?;
+    let csg = StopDataMsgDecoder::default().header(header);
+
+    let sbe_message_type = csg.message_type();
+    decode_utils::check_message_type(MessageType::StopData, sbe_message_type as u16)?;
+
+    let client_id = csg.client_id();
+    let exchange_id_raw = csg.exchange_id();
+    let exchange_id = ExchangeID::from(exchange_id_raw);
+    decode_utils::check_enum_value("exchange_id", exchange_id_raw, exchange_id as u8)?;
+    let symbol_id = csg.symbol_id();
+    let data_type_id_raw = csg.data_type_id();
+    let data_type_id = DataType::from(data_type_id_raw);
+    decode_utils::check_enum_value("data_type_id", data_type_id_raw, data_type_id as u8)?;
+
+    let message = StopDataMessage::new(client_id, exchange_id, symbol_id, data_type_id);
+
+    Ok(message)
+}
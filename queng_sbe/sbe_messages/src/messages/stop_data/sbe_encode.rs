@@ -0,0 +1,86 @@
+use crate::errors::SbeEncodeError;
+use crate::SbeEncode;
+use crate::StopDataMessage;
+use sbe_bindings::MessageType as SbeMessageType;
+use sbe_bindings::{message_header_codec, Encoder, StopDataMsgEncoder, WriteBuf};
+
+impl StopDataMessage {
+    /// Encodes a `StopDataMessage` to a new byte buffer.
+    ///
+    /// # Returns
+    ///
+    /// (usize, `Vec<u8>`) - Tuple of encoded size and byte buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if encoding fails
+    ///
+    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
+        let mut buffer = Vec::new();
+        let limit = self.encode_into(&mut buffer)?;
+        Ok((limit, buffer))
+    }
+}
+
+impl SbeEncode for StopDataMessage {
+    /// Encodes a `StopDataMessage` into the given byte buffer, replacing its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - `StopDataMessage` to encode
+    /// * `buffer` - Byte buffer to encode into, reused across calls
+    ///
+    /// # Returns
+    ///
+    /// usize - Encoded size
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if encoding fails
+    ///
+    /// # Process
+    ///
+    /// - Resize the buffer to 16 bytes
+    /// - Create default `StopDataMsgEncoder`
+    /// - Wrap buffer in `WriteBuf`
+    /// - Encode header
+    /// - Encode `message_type`
+    /// - Encode `client_id`, `exchange_id`, `symbol_id` and `data_type_id`
+    /// - Return encoded size
+    ///
+    fn encode_into(&self, buffer: &mut Vec<u8>) -> Result<usize, SbeEncodeError> {
+        // precise buffer size is 16 bytes for the entire message.
+        buffer.clear();
+        buffer.resize(16, 0);
+
+        let mut csg = StopDataMsgEncoder::default();
+
+        csg = csg.wrap(
+            WriteBuf::new(buffer.as_mut_slice()),
+            message_header_codec::ENCODED_LENGTH,
+        );
+
+        csg = csg
+            .header(0)
+            .parent()
+            .expect("[StopDataMessage]: Failed to encode header");
+
+        let value = SbeMessageType::from(self.message_type as u16);
+        csg.message_type(value);
+
+        let value = self.client_id;
+        csg.client_id(value);
+
+        let value = self.exchange_id as u8;
+        csg.exchange_id(value);
+
+        let value = self.symbol_id;
+        csg.symbol_id(value);
+
+        let value = self.data_type_id as u8;
+        csg.data_type_id(value);
+
+        let limit = csg.get_limit();
+        Ok(limit)
+    }
+}
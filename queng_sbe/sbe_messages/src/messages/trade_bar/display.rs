@@ -0,0 +1,12 @@
+use crate::messages::trade_bar::TradeBarMessage;
+use std::fmt;
+
+impl fmt::Display for TradeBarMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "TradeBarMessage {{ symbol_id: {}, date_time: {}, price: {}, volume: {} }}",
+            self.symbol_id, self.date_time, self.price, self.volume
+        )
+    }
+}
@@ -0,0 +1,27 @@
+use crate::messages::trade_bar::TradeBarMessage;
+use crate::MessageType;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+impl TradeBarMessage {
+    #[must_use]
+    pub const fn message_type(&self) -> &MessageType {
+        &self.message_type
+    }
+    #[must_use]
+    pub const fn symbol_id(&self) -> u16 {
+        self.symbol_id
+    }
+    #[must_use]
+    pub const fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+    #[must_use]
+    pub const fn price(&self) -> Decimal {
+        self.price
+    }
+    #[must_use]
+    pub const fn volume(&self) -> Decimal {
+        self.volume
+    }
+}
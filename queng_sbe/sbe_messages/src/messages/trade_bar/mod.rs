@@ -0,0 +1,104 @@
+use crate::errors::{SbeDecodeError, SbeValidationError};
+use crate::MessageType;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+mod display;
+mod getters;
+mod sbe_decode;
+mod sbe_encode;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TradeBarMessage {
+    message_type: MessageType,
+    symbol_id: u16,
+    date_time: DateTime<Utc>,
+    price: Decimal,
+    volume: Decimal,
+}
+
+impl TradeBarMessage {
+    /// Creates a new `TradeBarMessage` instance.
+    ///
+    /// Sets the `message_type` to `TradeBar`.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol_id` - u16 symbol ID
+    /// * `date_time` - `DateTime<Utc>` time of the trade
+    /// * `price` - `Decimal` trade price
+    /// * `volume` - `Decimal` traded volume
+    ///
+    /// # Returns
+    ///
+    /// `TradeBarMessage` instance
+    ///
+    /// # Remarks
+    ///
+    /// The constructor does not validate the trade; call `validate` to check it.
+    /// Encoding and decoding always validate.
+    ///
+    #[must_use]
+    pub const fn new(
+        symbol_id: u16,
+        date_time: DateTime<Utc>,
+        price: Decimal,
+        volume: Decimal,
+    ) -> Self {
+        let message_type = MessageType::TradeBar;
+        Self {
+            message_type,
+            symbol_id,
+            date_time,
+            price,
+            volume,
+        }
+    }
+}
+
+impl TradeBarMessage {
+    /// Validates the trade.
+    ///
+    /// # Returns
+    ///
+    /// Ok if the volume is not negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeValidationError` if the volume is negative.
+    ///
+    pub fn validate(&self) -> Result<(), SbeValidationError> {
+        if self.volume < Decimal::ZERO {
+            return Err(SbeValidationError(format!(
+                "[TradeBarMessage]: negative volume {} for symbol {}",
+                self.volume, self.symbol_id
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&[u8]> for TradeBarMessage {
+    type Error = SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode a `TradeBarMessage` from a byte slice.
+    ///
+    /// Calls the `sbe_decode::decode_trade_bar_message` function to decode the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Byte slice to decode
+    ///
+    /// # Returns
+    ///
+    /// Decoded `TradeBarMessage`
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid `TradeBarMessage`
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        sbe_decode::decode_trade_bar_message(value)
+    }
+}
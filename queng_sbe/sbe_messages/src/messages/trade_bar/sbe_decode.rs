@@ -0,0 +1,59 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::{decimal_utils, decode_utils};
+use crate::{MessageType, TradeBarMessage};
+use chrono::DateTime;
+use sbe_bindings::trade_bar_codec::{SBE_MIN_BLOCK_LENGTH, SBE_TEMPLATE_ID};
+use sbe_bindings::TradeBarDecoder;
+
+/// Decodes a `TradeBarMessage` from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded `TradeBarMessage`
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError` if the buffer is too short, the header does not match,
+/// the timestamp or a decimal is out of range, or the decoded trade fails validation
+///
+/// # Process
+///
+/// - Validate buffer length and header
+/// - Validate the schema version holds decimal prices and volumes
+/// - Wrap header in `TradeBarDecoder`
+/// - Decode `message_type` and validate
+/// - Decode `symbol_id` and `date_time` as microseconds since the Unix epoch
+/// - Decode price and volume as `Decimal`
+/// - Create, validate and return `TradeBarMessage`
+///
+pub fn decode_trade_bar_message(buffer: &[u8]) -> Result<TradeBarMessage, SbeDecodeError> {
+    let header = decode_utils::decode_header(buffer, SBE_TEMPLATE_ID, SBE_MIN_BLOCK_LENGTH)?;
+    decode_utils::check_schema_version(&header, decode_utils::SBE_DECIMAL_SCHEMA_VERSION)?;
+    let csg = TradeBarDecoder::default().header(header);
+
+    let sbe_message_type = csg.message_type();
+    decode_utils::check_message_type(MessageType::TradeBar, sbe_message_type as u16)?;
+
+    let symbol_id = csg.symbol_id();
+
+    let micros = csg.date_time();
+    let date_time = DateTime::from_timestamp_micros(micros).ok_or_else(|| {
+        SbeDecodeError::InvalidMessage(format!(
+            "[TradeBarMessage]: date_time {micros} is out of range"
+        ))
+    })?;
+
+    let price = decimal_utils::decode_decimal("price", &csg.price_decoder())?;
+    let volume = decimal_utils::decode_decimal("volume", &csg.volume_decoder())?;
+
+    let message = TradeBarMessage::new(symbol_id, date_time, price, volume);
+    message
+        .validate()
+        .map_err(|e| SbeDecodeError::InvalidMessage(e.to_string()))?;
+
+    Ok(message)
+}
@@ -0,0 +1,88 @@
+use crate::errors::SbeEncodeError;
+use crate::utils::decimal_utils;
+use crate::SbeEncode;
+use crate::TradeBarMessage;
+use sbe_bindings::MessageType as SbeMessageType;
+use sbe_bindings::{message_header_codec, Encoder, TradeBarEncoder, WriteBuf};
+
+impl TradeBarMessage {
+    /// Encodes a `TradeBarMessage` to a new byte buffer.
+    ///
+    /// # Returns
+    ///
+    /// (usize, `Vec<u8>`) - Tuple of encoded size and byte buffer
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the trade fails validation or a decimal exceeds the 64 bit mantissa
+    ///
+    pub fn encode(&self) -> Result<(usize, Vec<u8>), SbeEncodeError> {
+        let mut buffer = Vec::new();
+        let limit = self.encode_into(&mut buffer)?;
+        Ok((limit, buffer))
+    }
+}
+
+impl SbeEncode for TradeBarMessage {
+    /// Encodes a `TradeBarMessage` into the given byte buffer, replacing its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - `TradeBarMessage` to encode
+    /// * `buffer` - Byte buffer to encode into, reused across calls
+    ///
+    /// # Returns
+    ///
+    /// usize - Encoded size
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the trade fails validation or a decimal exceeds the 64 bit mantissa
+    ///
+    /// # Process
+    ///
+    /// - Validate the trade
+    /// - Resize the buffer to 38 bytes
+    /// - Create default `TradeBarEncoder`
+    /// - Wrap buffer in `WriteBuf`
+    /// - Encode header
+    /// - Encode `message_type`
+    /// - Encode `symbol_id` and `date_time` as microseconds since the Unix epoch
+    /// - Encode price and volume as SBE decimal
+    /// - Return encoded size
+    ///
+    fn encode_into(&self, buffer: &mut Vec<u8>) -> Result<usize, SbeEncodeError> {
+        self.validate().map_err(|e| SbeEncodeError(e.to_string()))?;
+
+        // precise buffer size is 38 bytes for the entire message.
+        buffer.clear();
+        buffer.resize(38, 0);
+
+        let mut csg = TradeBarEncoder::default();
+
+        csg = csg.wrap(
+            WriteBuf::new(buffer.as_mut_slice()),
+            message_header_codec::ENCODED_LENGTH,
+        );
+
+        csg = csg
+            .header(0)
+            .parent()
+            .expect("[TradeBarMessage]: Failed to encode header");
+
+        let value = SbeMessageType::from(self.message_type as u16);
+        csg.message_type(value);
+
+        let value = self.symbol_id;
+        csg.symbol_id(value);
+
+        let value = self.date_time.timestamp_micros();
+        csg.date_time(value);
+
+        csg = decimal_utils::encode_decimal("price", csg.price_encoder(), self.price)?;
+        csg = decimal_utils::encode_decimal("volume", csg.volume_encoder(), self.volume)?;
+
+        let limit = csg.get_limit();
+        Ok(limit)
+    }
+}
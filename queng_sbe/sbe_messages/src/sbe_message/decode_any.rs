@@ -0,0 +1,83 @@
+use crate::errors::SbeDecodeError;
+use crate::utils::decode_utils;
+use crate::{
+    ClientErrorMessage, ClientLoginMessage, ClientLoginReplyMessage, ClientLogoutMessage,
+    DataErrorMessage, FirstOHLCVBarMessage, FirstTradeBarMessage, HeartbeatMessage,
+    LastOHLCVBarMessage, LastTradeBarMessage, MessageType, OHLCVBarMessage,
+    OrderBookSnapshotMessage, OrderBookUpdateMessage, QuoteMessage, SbeMessage, StartDataMessage,
+    StopAllDataMessage, StopDataMessage, TradeBarMessage,
+};
+
+/// Decodes any message from a byte buffer.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// Decoded `SbeMessage`
+///
+/// # Errors
+///
+/// Returns `SbeDecodeError` if the header is invalid, the template id is unknown,
+/// or the message fails to decode
+///
+/// # Process
+///
+/// - Validate buffer length against the header and its block length
+/// - Validate schema id and version
+/// - Map the template id to a `MessageType`
+/// - Dispatch to the decoder of that message type
+///
+pub fn decode_any(buffer: &[u8]) -> Result<SbeMessage, SbeDecodeError> {
+    let header = decode_utils::peek_header(buffer)?;
+    let template_id = header.template_id();
+
+    match MessageType::from(template_id) {
+        // Client messages
+        MessageType::ClientLogin => {
+            ClientLoginMessage::try_from(buffer).map(SbeMessage::ClientLogin)
+        }
+        MessageType::ClientLogout => {
+            ClientLogoutMessage::try_from(buffer).map(SbeMessage::ClientLogout)
+        }
+        MessageType::Heartbeat => HeartbeatMessage::try_from(buffer).map(SbeMessage::Heartbeat),
+        MessageType::ClientLoginReply => {
+            ClientLoginReplyMessage::try_from(buffer).map(SbeMessage::ClientLoginReply)
+        }
+        // Data messages
+        MessageType::StartData => StartDataMessage::try_from(buffer).map(SbeMessage::StartData),
+        MessageType::StopData => StopDataMessage::try_from(buffer).map(SbeMessage::StopData),
+        MessageType::StopAllData => {
+            StopAllDataMessage::try_from(buffer).map(SbeMessage::StopAllData)
+        }
+        MessageType::OHLCVBar => OHLCVBarMessage::try_from(buffer).map(SbeMessage::OHLCVBar),
+        MessageType::FirstOHLCVBar => {
+            FirstOHLCVBarMessage::try_from(buffer).map(SbeMessage::FirstOHLCVBar)
+        }
+        MessageType::LastOHLCVBar => {
+            LastOHLCVBarMessage::try_from(buffer).map(SbeMessage::LastOHLCVBar)
+        }
+        MessageType::TradeBar => TradeBarMessage::try_from(buffer).map(SbeMessage::TradeBar),
+        MessageType::FirstTradeBar => {
+            FirstTradeBarMessage::try_from(buffer).map(SbeMessage::FirstTradeBar)
+        }
+        MessageType::LastTradeBar => {
+            LastTradeBarMessage::try_from(buffer).map(SbeMessage::LastTradeBar)
+        }
+        MessageType::OrderBookSnapshot => {
+            OrderBookSnapshotMessage::try_from(buffer).map(SbeMessage::OrderBookSnapshot)
+        }
+        MessageType::OrderBookUpdate => {
+            OrderBookUpdateMessage::try_from(buffer).map(SbeMessage::OrderBookUpdate)
+        }
+        MessageType::Quote => QuoteMessage::try_from(buffer).map(SbeMessage::Quote),
+        // Error messages
+        MessageType::ClientError => {
+            ClientErrorMessage::try_from(buffer).map(SbeMessage::ClientError)
+        }
+        MessageType::DataError => DataErrorMessage::try_from(buffer).map(SbeMessage::DataError),
+        MessageType::UnknownMessageType => Err(SbeDecodeError::UnknownTemplateId(template_id)),
+    }
+}
@@ -0,0 +1,27 @@
+use crate::SbeMessage;
+use std::fmt;
+
+impl fmt::Display for SbeMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ClientLogin(m) => write!(f, "{m}"),
+            Self::ClientLogout(m) => write!(f, "{m}"),
+            Self::Heartbeat(m) => write!(f, "{m}"),
+            Self::ClientLoginReply(m) => write!(f, "{m}"),
+            Self::StartData(m) => write!(f, "{m}"),
+            Self::StopData(m) => write!(f, "{m}"),
+            Self::StopAllData(m) => write!(f, "{m}"),
+            Self::OHLCVBar(m) => write!(f, "{m}"),
+            Self::FirstOHLCVBar(m) => write!(f, "{m}"),
+            Self::LastOHLCVBar(m) => write!(f, "{m}"),
+            Self::TradeBar(m) => write!(f, "{m}"),
+            Self::FirstTradeBar(m) => write!(f, "{m}"),
+            Self::LastTradeBar(m) => write!(f, "{m}"),
+            Self::OrderBookSnapshot(m) => write!(f, "{m}"),
+            Self::OrderBookUpdate(m) => write!(f, "{m}"),
+            Self::Quote(m) => write!(f, "{m}"),
+            Self::ClientError(m) => write!(f, "{m}"),
+            Self::DataError(m) => write!(f, "{m}"),
+        }
+    }
+}
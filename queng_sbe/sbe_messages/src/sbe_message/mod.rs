@@ -0,0 +1,87 @@
+use crate::{
+    ClientErrorMessage, ClientLoginMessage, ClientLoginReplyMessage, ClientLogoutMessage,
+    DataErrorMessage, FirstOHLCVBarMessage, FirstTradeBarMessage, HeartbeatMessage,
+    LastOHLCVBarMessage, LastTradeBarMessage, MessageType, OHLCVBarMessage,
+    OrderBookSnapshotMessage, OrderBookUpdateMessage, QuoteMessage, StartDataMessage,
+    StopAllDataMessage, StopDataMessage, TradeBarMessage,
+};
+
+mod decode_any;
+mod display;
+mod partition_key;
+mod sbe_encode;
+
+pub use decode_any::decode_any;
+
+/// `SbeMessage` enum holding any message defined in this crate.
+///
+/// Returned by `decode_any` so that services can route all incoming
+/// messages through a single match.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum SbeMessage {
+    // Client messages
+    ClientLogin(ClientLoginMessage),
+    ClientLogout(ClientLogoutMessage),
+    Heartbeat(HeartbeatMessage),
+    ClientLoginReply(ClientLoginReplyMessage),
+    // Data messages
+    StartData(StartDataMessage),
+    StopData(StopDataMessage),
+    StopAllData(StopAllDataMessage),
+    OHLCVBar(OHLCVBarMessage),
+    FirstOHLCVBar(FirstOHLCVBarMessage),
+    LastOHLCVBar(LastOHLCVBarMessage),
+    TradeBar(TradeBarMessage),
+    FirstTradeBar(FirstTradeBarMessage),
+    LastTradeBar(LastTradeBarMessage),
+    OrderBookSnapshot(OrderBookSnapshotMessage),
+    OrderBookUpdate(OrderBookUpdateMessage),
+    Quote(QuoteMessage),
+    // Error messages
+    ClientError(ClientErrorMessage),
+    DataError(DataErrorMessage),
+}
+
+impl SbeMessage {
+    /// Returns the `MessageType` of the contained message.
+    #[must_use]
+    pub const fn message_type(&self) -> MessageType {
+        match self {
+            Self::ClientLogin(_) => MessageType::ClientLogin,
+            Self::ClientLogout(_) => MessageType::ClientLogout,
+            Self::Heartbeat(_) => MessageType::Heartbeat,
+            Self::ClientLoginReply(_) => MessageType::ClientLoginReply,
+            Self::StartData(_) => MessageType::StartData,
+            Self::StopData(_) => MessageType::StopData,
+            Self::StopAllData(_) => MessageType::StopAllData,
+            Self::OHLCVBar(_) => MessageType::OHLCVBar,
+            Self::FirstOHLCVBar(_) => MessageType::FirstOHLCVBar,
+            Self::LastOHLCVBar(_) => MessageType::LastOHLCVBar,
+            Self::TradeBar(_) => MessageType::TradeBar,
+            Self::FirstTradeBar(_) => MessageType::FirstTradeBar,
+            Self::LastTradeBar(_) => MessageType::LastTradeBar,
+            Self::OrderBookSnapshot(_) => MessageType::OrderBookSnapshot,
+            Self::OrderBookUpdate(_) => MessageType::OrderBookUpdate,
+            Self::Quote(_) => MessageType::Quote,
+            Self::ClientError(_) => MessageType::ClientError,
+            Self::DataError(_) => MessageType::DataError,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for SbeMessage {
+    type Error = crate::SbeDecodeError;
+
+    /// Implements the `TryFrom` trait to decode any `SbeMessage` from a byte slice.
+    ///
+    /// Calls the `decode_any` function to decode the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SbeDecodeError` if the byte slice does not hold a valid message
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        decode_any(value)
+    }
+}
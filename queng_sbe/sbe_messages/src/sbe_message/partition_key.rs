@@ -0,0 +1,51 @@
+use crate::{PartitionKey, SbeMessage};
+
+impl PartitionKey for SbeMessage {
+    /// Returns the symbol id of the wrapped message, if any.
+    fn symbol_key(&self) -> Option<u16> {
+        match self {
+            Self::ClientLogin(m) => m.symbol_key(),
+            Self::ClientLogout(m) => m.symbol_key(),
+            Self::Heartbeat(m) => m.symbol_key(),
+            Self::ClientLoginReply(m) => m.symbol_key(),
+            Self::StartData(m) => m.symbol_key(),
+            Self::StopData(m) => m.symbol_key(),
+            Self::StopAllData(m) => m.symbol_key(),
+            Self::OHLCVBar(m) => m.symbol_key(),
+            Self::FirstOHLCVBar(m) => m.symbol_key(),
+            Self::LastOHLCVBar(m) => m.symbol_key(),
+            Self::TradeBar(m) => m.symbol_key(),
+            Self::FirstTradeBar(m) => m.symbol_key(),
+            Self::LastTradeBar(m) => m.symbol_key(),
+            Self::OrderBookSnapshot(m) => m.symbol_key(),
+            Self::OrderBookUpdate(m) => m.symbol_key(),
+            Self::Quote(m) => m.symbol_key(),
+            Self::ClientError(m) => m.symbol_key(),
+            Self::DataError(m) => m.symbol_key(),
+        }
+    }
+
+    /// Returns the client id of the wrapped message, if any.
+    fn client_key(&self) -> Option<u16> {
+        match self {
+            Self::ClientLogin(m) => m.client_key(),
+            Self::ClientLogout(m) => m.client_key(),
+            Self::Heartbeat(m) => m.client_key(),
+            Self::ClientLoginReply(m) => m.client_key(),
+            Self::StartData(m) => m.client_key(),
+            Self::StopData(m) => m.client_key(),
+            Self::StopAllData(m) => m.client_key(),
+            Self::OHLCVBar(m) => m.client_key(),
+            Self::FirstOHLCVBar(m) => m.client_key(),
+            Self::LastOHLCVBar(m) => m.client_key(),
+            Self::TradeBar(m) => m.client_key(),
+            Self::FirstTradeBar(m) => m.client_key(),
+            Self::LastTradeBar(m) => m.client_key(),
+            Self::OrderBookSnapshot(m) => m.client_key(),
+            Self::OrderBookUpdate(m) => m.client_key(),
+            Self::Quote(m) => m.client_key(),
+            Self::ClientError(m) => m.client_key(),
+            Self::DataError(m) => m.client_key(),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::errors::SbeEncodeError;
+use crate::{SbeEncode, SbeMessage};
+
+impl SbeEncode for SbeMessage {
+    /// Encodes the wrapped message into the given byte buffer, replacing its content.
+    fn encode_into(&self, buffer: &mut Vec<u8>) -> Result<usize, SbeEncodeError> {
+        match self {
+            Self::ClientLogin(m) => m.encode_into(buffer),
+            Self::ClientLogout(m) => m.encode_into(buffer),
+            Self::Heartbeat(m) => m.encode_into(buffer),
+            Self::ClientLoginReply(m) => m.encode_into(buffer),
+            Self::StartData(m) => m.encode_into(buffer),
+            Self::StopData(m) => m.encode_into(buffer),
+            Self::StopAllData(m) => m.encode_into(buffer),
+            Self::OHLCVBar(m) => m.encode_into(buffer),
+            Self::FirstOHLCVBar(m) => m.encode_into(buffer),
+            Self::LastOHLCVBar(m) => m.encode_into(buffer),
+            Self::TradeBar(m) => m.encode_into(buffer),
+            Self::FirstTradeBar(m) => m.encode_into(buffer),
+            Self::LastTradeBar(m) => m.encode_into(buffer),
+            Self::OrderBookSnapshot(m) => m.encode_into(buffer),
+            Self::OrderBookUpdate(m) => m.encode_into(buffer),
+            Self::Quote(m) => m.encode_into(buffer),
+            Self::ClientError(m) => m.encode_into(buffer),
+            Self::DataError(m) => m.encode_into(buffer),
+        }
+    }
+}
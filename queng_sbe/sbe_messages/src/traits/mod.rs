@@ -0,0 +1,9 @@
+/// Module containing the traits implemented by all SBE messages.
+///
+/// # Exports
+///
+/// - `partition_key` - Symbol and client keys used for partitioning
+/// - `sbe_encode` - Encoding of a message into a reusable byte buffer
+///
+pub mod partition_key;
+pub mod sbe_encode;
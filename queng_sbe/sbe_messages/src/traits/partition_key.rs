@@ -0,0 +1,148 @@
+use crate::{
+    ClientErrorMessage, ClientLoginMessage, ClientLoginReplyMessage, ClientLogoutMessage,
+    DataErrorMessage, FirstOHLCVBarMessage, FirstTradeBarMessage, HeartbeatMessage,
+    LastOHLCVBarMessage, LastTradeBarMessage, OHLCVBarMessage, OrderBookSnapshotMessage,
+    OrderBookUpdateMessage, QuoteMessage, StartDataMessage, StopAllDataMessage, StopDataMessage,
+    TradeBarMessage,
+};
+
+/// Keys of an SBE message used to select the partition it is sent to.
+///
+/// Messages sharing a key are sent to the same partition, so consumers receive
+/// all messages of one symbol, or of one client, in order.
+///
+/// Both keys default to `None` for messages that do not carry them.
+///
+pub trait PartitionKey {
+    /// Returns the symbol id of the message, if any.
+    fn symbol_key(&self) -> Option<u16> {
+        None
+    }
+
+    /// Returns the client id of the message, if any.
+    fn client_key(&self) -> Option<u16> {
+        None
+    }
+}
+
+// Client messages
+
+impl PartitionKey for ClientLoginMessage {
+    fn client_key(&self) -> Option<u16> {
+        Some(self.client_id())
+    }
+}
+
+impl PartitionKey for ClientLogoutMessage {
+    fn client_key(&self) -> Option<u16> {
+        Some(self.client_id())
+    }
+}
+
+impl PartitionKey for HeartbeatMessage {
+    fn client_key(&self) -> Option<u16> {
+        Some(self.client_id())
+    }
+}
+
+impl PartitionKey for ClientLoginReplyMessage {
+    fn client_key(&self) -> Option<u16> {
+        Some(self.client_id())
+    }
+}
+
+// Data messages
+
+impl PartitionKey for StartDataMessage {
+    fn symbol_key(&self) -> Option<u16> {
+        Some(self.symbol_id())
+    }
+
+    fn client_key(&self) -> Option<u16> {
+        Some(self.client_id())
+    }
+}
+
+impl PartitionKey for StopDataMessage {
+    fn symbol_key(&self) -> Option<u16> {
+        Some(self.symbol_id())
+    }
+
+    fn client_key(&self) -> Option<u16> {
+        Some(self.client_id())
+    }
+}
+
+impl PartitionKey for StopAllDataMessage {
+    fn client_key(&self) -> Option<u16> {
+        Some(self.client_id())
+    }
+}
+
+impl PartitionKey for OHLCVBarMessage {
+    fn symbol_key(&self) -> Option<u16> {
+        Some(self.symbol_id())
+    }
+}
+
+impl PartitionKey for FirstOHLCVBarMessage {
+    fn symbol_key(&self) -> Option<u16> {
+        Some(self.symbol_id())
+    }
+}
+
+impl PartitionKey for LastOHLCVBarMessage {
+    fn symbol_key(&self) -> Option<u16> {
+        Some(self.symbol_id())
+    }
+}
+
+impl PartitionKey for TradeBarMessage {
+    fn symbol_key(&self) -> Option<u16> {
+        Some(self.symbol_id())
+    }
+}
+
+impl PartitionKey for FirstTradeBarMessage {
+    fn symbol_key(&self) -> Option<u16> {
+        Some(self.symbol_id())
+    }
+}
+
+impl PartitionKey for LastTradeBarMessage {
+    fn symbol_key(&self) -> Option<u16> {
+        Some(self.symbol_id())
+    }
+}
+
+impl PartitionKey for OrderBookSnapshotMessage {
+    fn symbol_key(&self) -> Option<u16> {
+        Some(self.symbol_id())
+    }
+}
+
+impl PartitionKey for OrderBookUpdateMessage {
+    fn symbol_key(&self) -> Option<u16> {
+        Some(self.symbol_id())
+    }
+}
+
+impl PartitionKey for QuoteMessage {
+    fn symbol_key(&self) -> Option<u16> {
+        Some(self.symbol_id())
+    }
+}
+
+// Error messages
+
+impl PartitionKey for ClientErrorMessage {
+    fn client_key(&self) -> Option<u16> {
+        Some(self.client_id())
+    }
+}
+
+impl PartitionKey for DataErrorMessage {
+    fn client_key(&self) -> Option<u16> {
+        Some(self.client_id())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::errors::SbeEncodeError;
+
+/// Encoding of an SBE message into a caller provided byte buffer.
+///
+/// Implemented by every message of this crate and by `SbeMessage`, so producers
+/// can send any message through one generic path and reuse a single buffer
+/// instead of allocating a new one per message.
+///
+pub trait SbeEncode {
+    /// Encodes the message into the given byte buffer, replacing its content.
+    ///
+    /// The buffer is resized to the precise size of the encoded message,
+    /// so its capacity is kept across calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - Byte buffer to encode into
+    ///
+    /// # Returns
+    ///
+    /// usize - Encoded size
+    ///
+    /// # Errors
+    ///
+    /// Returns Err if the message fails validation or encoding fails
+    ///
+    fn encode_into(&self, buffer: &mut Vec<u8>) -> Result<usize, SbeEncodeError>;
+}
@@ -0,0 +1,59 @@
+use std::fmt::{Display, Formatter};
+
+/// `BookSide` enum representing the side of an order book level.
+///
+/// Can take following values:
+///
+/// - `NullVal` = `0_u8`
+/// - `Bid` = `1_u8`
+/// - `Ask` = `2_u8`
+///
+/// # Remarks
+///
+/// Derives common Rust traits for convenience:
+/// - Clone, Copy, Debug, Default - generics
+/// - `PartialEq`, Eq - equality
+/// - `PartialOrd`, Ord - ordering
+/// - Hash - hashability
+///
+/// Represented as u8 for compactness.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum BookSide {
+    #[default]
+    NullVal = 0_u8,
+    Bid = 1_u8,
+    Ask = 2_u8,
+}
+
+impl From<u8> for BookSide {
+    /// Implements `From<u8>` trait to convert u8 to `BookSide`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - u8 value to convert
+    ///
+    /// # Returns
+    ///
+    /// `BookSide` variant corresponding to u8 value:
+    ///
+    /// - 1 -> `Bid`
+    /// - 2 -> `Ask`
+    /// - Other -> `NullVal`
+    ///
+    #[inline]
+    fn from(value: u8) -> Self {
+        match value {
+            1_u8 => Self::Bid,
+            2_u8 => Self::Ask,
+            _ => Self::NullVal,
+        }
+    }
+}
+
+impl Display for BookSide {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
@@ -9,6 +9,7 @@ use std::fmt::{Display, Formatter};
 /// - `ClientLogInError` = `2_u8`
 /// - `ClientNotLoggedIn` = `3_u8`
 /// - `ClientLogOutError` = `4_u8`
+/// - `ServiceShutdown` = `5_u8`
 ///
 /// # Remarks
 ///
@@ -30,6 +31,7 @@ pub enum ClientErrorType {
     ClientLogInError = 2_u8,
     ClientNotLoggedIn = 3_u8,
     ClientLogOutError = 4_u8,
+    ServiceShutdown = 5_u8,
 }
 
 impl From<u8> for ClientErrorType {
@@ -48,6 +50,7 @@ impl From<u8> for ClientErrorType {
     /// - 2 -> `ClientLogInError`
     /// - 3 -> `ClientNotLoggedIn`
     /// - 4 -> `ClientLogOutError`
+    /// - 5 -> `ServiceShutdown`
     /// - Other -> `UnknownClientError`
     ///
     /// # Remarks
@@ -63,6 +66,7 @@ impl From<u8> for ClientErrorType {
             2_u8 => Self::ClientLogInError,
             3_u8 => Self::ClientNotLoggedIn,
             4_u8 => Self::ClientLogOutError,
+            5_u8 => Self::ServiceShutdown,
             _ => Self::UnknownClientError,
         }
     }
@@ -18,8 +18,8 @@ pub enum DataType {
     UnknownDataType = 0_u8,
     TradeData = 1_u8,
     OHLCVData = 2_u8,
-    // OrderBookData = 3_u8,
-    // QuoteData = 4_u8,
+    OrderBookData = 3_u8,
+    QuoteData = 4_u8,
 }
 
 impl From<u8> for DataType {
@@ -36,6 +36,8 @@ impl From<u8> for DataType {
     /// - `0_u8` maps to `DataType::UnknownDataType`
     /// - `1_u8` maps to `DataType::TradeData`
     /// - `2_u8` maps to `DataType::OHLCVData`
+    /// - `3_u8` maps to `DataType::OrderBookData`
+    /// - `4_u8` maps to `DataType::QuoteData`
     ///
     /// Any other value maps to `DataType::UnknownDataType`.
     #[inline]
@@ -44,6 +46,8 @@ impl From<u8> for DataType {
             0_u8 => Self::UnknownDataType,
             1_u8 => Self::TradeData,
             2_u8 => Self::OHLCVData,
+            3_u8 => Self::OrderBookData,
+            4_u8 => Self::QuoteData,
             _ => Self::UnknownDataType,
         }
     }
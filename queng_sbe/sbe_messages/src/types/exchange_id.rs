@@ -0,0 +1,59 @@
+use std::fmt::{Display, Formatter};
+
+/// `ExchangeID` enum representing the exchanges a data integration can connect to.
+///
+/// Can take following values:
+///
+/// - `NullVal` = `0_u8`
+/// - `BNB` = `1_u8` (Binance)
+/// - `VEX` = `2_u8` (Virtual Exchange)
+///
+/// # Remarks
+///
+/// Derives common Rust traits for convenience:
+/// - Clone, Copy, Debug, Default - generics
+/// - `PartialEq`, Eq - equality
+/// - `PartialOrd`, Ord - ordering
+/// - Hash - hashability
+///
+/// Represented as u8 for compactness.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum ExchangeID {
+    #[default]
+    NullVal = 0_u8,
+    BNB = 1_u8,
+    VEX = 2_u8,
+}
+
+impl From<u8> for ExchangeID {
+    /// Implements `From<u8>` trait to convert u8 to `ExchangeID`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - u8 value to convert
+    ///
+    /// # Returns
+    ///
+    /// `ExchangeID` variant corresponding to u8 value:
+    ///
+    /// - 1 -> `BNB`
+    /// - 2 -> `VEX`
+    /// - Other -> `NullVal`
+    ///
+    #[inline]
+    fn from(value: u8) -> Self {
+        match value {
+            1_u8 => Self::BNB,
+            2_u8 => Self::VEX,
+            _ => Self::NullVal,
+        }
+    }
+}
+
+impl Display for ExchangeID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
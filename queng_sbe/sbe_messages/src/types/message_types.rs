@@ -9,6 +9,8 @@ use std::fmt::Display;
 ///   1xx = `ClientTypes`
 /// - `ClientLogin` = `101_u16`
 /// - `ClientLogout` = `102_u16`
+/// - `Heartbeat` = `103_u16`
+/// - `ClientLoginReply` = `104_u16`
 ///   2xx = `DataTypes`
 /// - `StartData` = `201_u16`
 /// - `StopData` = `202_u16`
@@ -19,6 +21,9 @@ use std::fmt::Display;
 /// - `TradeBar` = `207_u16`
 /// - `FirstTradeBar` = `208_u16`
 /// - `LastTradeBar` = `209_u16`
+/// - `OrderBookSnapshot` = `210_u16`
+/// - `OrderBookUpdate` = `211_u16`
+/// - `Quote` = `212_u16`
 ///    8xx = `ErrorTypes`
 /// - `ClientError` = `801_u16`
 /// - `DataError` = `802_u16`
@@ -42,6 +47,8 @@ pub enum MessageType {
     // Client Message Types
     ClientLogin = 101_u16,
     ClientLogout = 102_u16,
+    Heartbeat = 103_u16,
+    ClientLoginReply = 104_u16,
     // Data Message Types
     StartData = 201_u16,
     StopData = 202_u16,
@@ -52,6 +59,9 @@ pub enum MessageType {
     TradeBar = 207_u16,
     FirstTradeBar = 208_u16,
     LastTradeBar = 209_u16,
+    OrderBookSnapshot = 210_u16,
+    OrderBookUpdate = 211_u16,
+    Quote = 212_u16,
     // Error Message Types
     ClientError = 801_u16,
     DataError = 802_u16,
@@ -70,6 +80,8 @@ pub enum MessageType {
 /// - 0 -> `UnknownMessageType`
 /// - 101 -> `ClientLogin`
 /// - 102 -> `ClientLogout`
+/// - 103 -> `Heartbeat`
+/// - 104 -> `ClientLoginReply`
 /// - 201 -> `StartData`
 /// - 202 -> `StopData`
 /// - 203 -> `StopAllData`
@@ -79,6 +91,9 @@ pub enum MessageType {
 /// - 207 -> `TradeBar`
 /// - 208 -> `FirstTradeBar`
 /// - 209 -> `LastTradeBar`
+/// - 210 -> `OrderBookSnapshot`
+/// - 211 -> `OrderBookUpdate`
+/// - 212 -> `Quote`
 /// - 801 -> `ClientError`
 /// - 802 -> `DataError`
 /// - Other -> `UnknownMessageType`
@@ -96,6 +111,8 @@ impl From<u16> for MessageType {
             // Client Message Types
             101_u16 => Self::ClientLogin,
             102_u16 => Self::ClientLogout,
+            103_u16 => Self::Heartbeat,
+            104_u16 => Self::ClientLoginReply,
             // Data Message Types
             201_u16 => Self::StartData,
             202_u16 => Self::StopData,
@@ -106,6 +123,9 @@ impl From<u16> for MessageType {
             207_u16 => Self::TradeBar,
             208_u16 => Self::FirstTradeBar,
             209_u16 => Self::LastTradeBar,
+            210_u16 => Self::OrderBookSnapshot,
+            211_u16 => Self::OrderBookUpdate,
+            212_u16 => Self::Quote,
             // Error Message Types
             801_u16 => Self::ClientError,
             802_u16 => Self::DataError,
@@ -119,6 +139,8 @@ impl Display for MessageType {
         match self {
             Self::ClientLogin => write!(f, "ClientLogin"),
             Self::ClientLogout => write!(f, "ClientLogout"),
+            Self::Heartbeat => write!(f, "Heartbeat"),
+            Self::ClientLoginReply => write!(f, "ClientLoginReply"),
             Self::StartData => write!(f, "StartData"),
             Self::StopData => write!(f, "StopData"),
             Self::StopAllData => write!(f, "StopAllData"),
@@ -128,6 +150,9 @@ impl Display for MessageType {
             Self::TradeBar => write!(f, "TradeBar"),
             Self::FirstTradeBar => write!(f, "FirstTradeBar"),
             Self::LastTradeBar => write!(f, "LastTradeBar"),
+            Self::OrderBookSnapshot => write!(f, "OrderBookSnapshot"),
+            Self::OrderBookUpdate => write!(f, "OrderBookUpdate"),
+            Self::Quote => write!(f, "Quote"),
             Self::UnknownMessageType => write!(f, "UnknownMessageType"),
             Self::ClientError => write!(f, "ClientError"),
             Self::DataError => write!(f, "DataError"),
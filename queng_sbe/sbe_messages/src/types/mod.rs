@@ -1,11 +1,13 @@
 /// Module containing common data types used across SBE messages.
 ///
-/// This includes enums representing:  
+/// This includes enums and structs representing:
 ///
+/// - Order book sides and levels
 /// - Data types like trades, OHLCV etc.
 /// - Data errors for requests
 /// - Exchange identifiers
 /// - Message types
+/// - Time resolutions
 ///
 /// Grouping these common types into a module avoids duplication
 /// and provides a single source of truth for type definitions.
@@ -15,12 +17,21 @@
 ///
 /// # Exports
 ///
+/// - `book_side` - Enumeration of order book sides
 /// - `data_type` - Enumeration of data types
 /// - `data_error_types` - Enumeration of data error types
 /// - `exchange_id` - Enumeration of exchange identifiers
 /// - `message_type` - Enumeration of message types
+/// - `order_book_level` - Price level of an order book side
+/// - `order_book_level_update` - Change of an order book level
+/// - `time_resolution` - Enumeration of time resolutions
 ///
+pub mod book_side;
 pub mod client_error_types;
 pub mod data_error_types;
 pub mod data_type;
+pub mod exchange_id;
 pub mod message_types;
+pub mod order_book_level;
+pub mod order_book_level_update;
+pub mod time_resolution;
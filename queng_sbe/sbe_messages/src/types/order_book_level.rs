@@ -0,0 +1,49 @@
+use rust_decimal::Decimal;
+use std::fmt::{Display, Formatter};
+
+/// `OrderBookLevel` struct representing one price level of an order book side.
+///
+/// # Fields
+///
+/// * `price` - `Decimal` price of the level
+/// * `size` - `Decimal` total size resting at the price
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OrderBookLevel {
+    price: Decimal,
+    size: Decimal,
+}
+
+impl OrderBookLevel {
+    /// Creates a new `OrderBookLevel` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `price` - `Decimal` price of the level
+    /// * `size` - `Decimal` total size resting at the price
+    ///
+    /// # Returns
+    ///
+    /// `OrderBookLevel` instance
+    ///
+    #[must_use]
+    pub const fn new(price: Decimal, size: Decimal) -> Self {
+        Self { price, size }
+    }
+
+    #[must_use]
+    pub const fn price(&self) -> Decimal {
+        self.price
+    }
+
+    #[must_use]
+    pub const fn size(&self) -> Decimal {
+        self.size
+    }
+}
+
+impl Display for OrderBookLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} @ {}", self.size, self.price)
+    }
+}
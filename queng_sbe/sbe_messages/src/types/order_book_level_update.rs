@@ -0,0 +1,64 @@
+use crate::BookSide;
+use rust_decimal::Decimal;
+use std::fmt::{Display, Formatter};
+
+/// `OrderBookLevelUpdate` struct representing the change of one order book level.
+///
+/// # Fields
+///
+/// * `side` - `BookSide` of the level
+/// * `price` - `Decimal` price of the level
+/// * `size` - `Decimal` new total size at the price; zero removes the level
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OrderBookLevelUpdate {
+    side: BookSide,
+    price: Decimal,
+    size: Decimal,
+}
+
+impl OrderBookLevelUpdate {
+    /// Creates a new `OrderBookLevelUpdate` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - `BookSide` of the level
+    /// * `price` - `Decimal` price of the level
+    /// * `size` - `Decimal` new total size at the price; zero removes the level
+    ///
+    /// # Returns
+    ///
+    /// `OrderBookLevelUpdate` instance
+    ///
+    #[must_use]
+    pub const fn new(side: BookSide, price: Decimal, size: Decimal) -> Self {
+        Self { side, price, size }
+    }
+
+    #[must_use]
+    pub const fn side(&self) -> BookSide {
+        self.side
+    }
+
+    #[must_use]
+    pub const fn price(&self) -> Decimal {
+        self.price
+    }
+
+    #[must_use]
+    pub const fn size(&self) -> Decimal {
+        self.size
+    }
+
+    /// Returns true if the update removes the level from the book.
+    #[must_use]
+    pub fn is_removal(&self) -> bool {
+        self.size.is_zero()
+    }
+}
+
+impl Display for OrderBookLevelUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} @ {}", self.side, self.size, self.price)
+    }
+}
@@ -0,0 +1,83 @@
+use std::fmt::{Display, Formatter};
+
+/// `TimeResolution` enum representing the sampling interval of requested data.
+///
+/// Can take following values:
+///
+/// - `NoValue` = `0_u8`
+/// - `OneMin` = `1_u8`
+/// - `FiveMin` = `2_u8`
+/// - `FifteenMin` = `3_u8`
+/// - `ThirtyMin` = `4_u8`
+/// - `OneHour` = `5_u8`
+/// - `OneDay` = `6_u8`
+/// - `OneMonth` = `7_u8`
+/// - `OneYear` = `8_u8`
+///
+/// # Remarks
+///
+/// Derives common Rust traits for convenience:
+/// - Clone, Copy, Debug, Default - generics
+/// - `PartialEq`, Eq - equality
+/// - `PartialOrd`, Ord - ordering
+/// - Hash - hashability
+///
+/// Represented as u8 for compactness.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum TimeResolution {
+    #[default]
+    NoValue = 0_u8,
+    OneMin = 1_u8,
+    FiveMin = 2_u8,
+    FifteenMin = 3_u8,
+    ThirtyMin = 4_u8,
+    OneHour = 5_u8,
+    OneDay = 6_u8,
+    OneMonth = 7_u8,
+    OneYear = 8_u8,
+}
+
+impl From<u8> for TimeResolution {
+    /// Implements `From<u8>` trait to convert u8 to `TimeResolution`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - u8 value to convert
+    ///
+    /// # Returns
+    ///
+    /// `TimeResolution` variant corresponding to u8 value:
+    ///
+    /// - 1 -> `OneMin`
+    /// - 2 -> `FiveMin`
+    /// - 3 -> `FifteenMin`
+    /// - 4 -> `ThirtyMin`
+    /// - 5 -> `OneHour`
+    /// - 6 -> `OneDay`
+    /// - 7 -> `OneMonth`
+    /// - 8 -> `OneYear`
+    /// - Other -> `NoValue`
+    ///
+    #[inline]
+    fn from(value: u8) -> Self {
+        match value {
+            1_u8 => Self::OneMin,
+            2_u8 => Self::FiveMin,
+            3_u8 => Self::FifteenMin,
+            4_u8 => Self::ThirtyMin,
+            5_u8 => Self::OneHour,
+            6_u8 => Self::OneDay,
+            7_u8 => Self::OneMonth,
+            8_u8 => Self::OneYear,
+            _ => Self::NoValue,
+        }
+    }
+}
+
+impl Display for TimeResolution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
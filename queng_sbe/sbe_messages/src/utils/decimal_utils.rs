@@ -0,0 +1,222 @@
+use crate::errors::{SbeDecodeError, SbeEncodeError};
+use rust_decimal::Decimal;
+use sbe_bindings::{DecimalDecoder, DecimalEncoder, Reader, Writer};
+
+/// Largest scale a `Decimal` can hold.
+const MAX_SCALE: i8 = 28;
+
+/// Converts a `Decimal` into the mantissa and exponent of the SBE decimal composite.
+///
+/// The scale of the decimal is kept whenever the mantissa fits into an i64. Otherwise
+/// trailing zeros are moved from the mantissa into the exponent, which keeps the value.
+///
+/// # Arguments
+///
+/// * `value` - Decimal to convert
+///
+/// # Returns
+///
+/// `Some((mantissa, exponent))` with `value == mantissa * 10 ^ exponent`,
+/// or `None` if the value needs more than 64 bits of mantissa
+///
+pub(crate) fn to_sbe_decimal(value: Decimal) -> Option<(i64, i8)> {
+    let mut mantissa = value.mantissa();
+    let mut exponent = -i8::try_from(value.scale()).ok()?;
+
+    loop {
+        if let Ok(mantissa) = i64::try_from(mantissa) {
+            return Some((mantissa, exponent));
+        }
+        if mantissa % 10 != 0 {
+            return None;
+        }
+        mantissa /= 10;
+        exponent += 1;
+    }
+}
+
+/// Converts the mantissa and exponent of the SBE decimal composite into a `Decimal`.
+///
+/// # Arguments
+///
+/// * `mantissa` - i64 mantissa
+/// * `exponent` - i8 base 10 exponent
+///
+/// # Returns
+///
+/// `Some(Decimal)` equal to `mantissa * 10 ^ exponent`,
+/// or `None` if the value exceeds the range or precision of `Decimal`
+///
+pub(crate) fn from_sbe_decimal(mantissa: i64, exponent: i8) -> Option<Decimal> {
+    if exponent >= 0 {
+        let value = 10_i128
+            .checked_pow(u32::from(exponent.unsigned_abs()))
+            .and_then(|factor| i128::from(mantissa).checked_mul(factor))?;
+        return Decimal::try_from_i128_with_scale(value, 0).ok();
+    }
+
+    let mut mantissa = mantissa;
+    let mut exponent = exponent;
+    // Trailing zeros beyond the largest scale can be dropped without losing the value.
+    while exponent < -MAX_SCALE && mantissa % 10 == 0 {
+        mantissa /= 10;
+        exponent += 1;
+    }
+    if exponent < -MAX_SCALE {
+        return None;
+    }
+
+    Decimal::try_from_i128_with_scale(i128::from(mantissa), u32::from(exponent.unsigned_abs())).ok()
+}
+
+/// Encodes a `Decimal` into an SBE decimal composite field.
+///
+/// # Arguments
+///
+/// * `field` - Name of the field, used in the error message
+/// * `encoder` - Composite encoder of the field, i.e. `csg.price_encoder()`
+/// * `value` - Decimal to encode
+///
+/// # Returns
+///
+/// The message encoder the composite encoder was taken from
+///
+/// # Errors
+///
+/// Returns `SbeEncodeError` if the value needs more than 64 bits of mantissa
+///
+pub(crate) fn encode_decimal<'a, P>(
+    field: &str,
+    mut encoder: DecimalEncoder<P>,
+    value: Decimal,
+) -> Result<P, SbeEncodeError>
+where
+    P: Writer<'a> + Default,
+{
+    let (mantissa, exponent) = to_sbe_decimal(value).ok_or_else(|| {
+        SbeEncodeError(format!(
+            "{field} {value} exceeds the 64 bit mantissa of the SBE decimal"
+        ))
+    })?;
+
+    encoder.mantissa(mantissa);
+    encoder.exponent(exponent);
+
+    encoder
+        .parent()
+        .map_err(|e| SbeEncodeError(format!("Failed to encode {field}: {e}")))
+}
+
+/// Decodes a `Decimal` from an SBE decimal composite field.
+///
+/// # Arguments
+///
+/// * `field` - Name of the field, used in the error
+/// * `decoder` - Composite decoder of the field, i.e. `csg.price_decoder()`
+///
+/// # Returns
+///
+/// Decoded `Decimal`
+///
+/// # Errors
+///
+/// Returns `DecimalOutOfRange` if the value cannot be represented as `Decimal`
+///
+pub(crate) fn decode_decimal<'a, P>(
+    field: &'static str,
+    decoder: &DecimalDecoder<P>,
+) -> Result<Decimal, SbeDecodeError>
+where
+    P: Reader<'a> + Default,
+{
+    let mantissa = decoder.mantissa();
+    let exponent = decoder.exponent();
+
+    from_sbe_decimal(mantissa, exponent).ok_or_else(|| SbeDecodeError::DecimalOutOfRange {
+        field,
+        value: format!("{mantissa}e{exponent}"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(value: Decimal) -> Option<Decimal> {
+        let (mantissa, exponent) = to_sbe_decimal(value)?;
+        from_sbe_decimal(mantissa, exponent)
+    }
+
+    #[test]
+    fn test_round_trip_keeps_scale() {
+        let value = Decimal::new(-123_456_789, 8);
+        assert_eq!(to_sbe_decimal(value), Some((-123_456_789, -8)));
+
+        let decoded = round_trip(value).unwrap();
+        assert_eq!(decoded, value);
+        assert_eq!(decoded.scale(), 8);
+    }
+
+    #[test]
+    fn test_round_trip_max_scale() {
+        let value = Decimal::from_i128_with_scale(1, 28);
+        assert_eq!(to_sbe_decimal(value), Some((1, -28)));
+        assert_eq!(round_trip(value), Some(value));
+
+        let value = Decimal::from_i128_with_scale(-i128::from(i64::MAX), 28);
+        assert_eq!(round_trip(value), Some(value));
+    }
+
+    #[test]
+    fn test_round_trip_i64_bounds() {
+        for mantissa in [i64::MIN, i64::MAX, 0] {
+            let value = Decimal::from(mantissa);
+            assert_eq!(to_sbe_decimal(value), Some((mantissa, 0)));
+            assert_eq!(round_trip(value), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_mantissa_beyond_i64_moves_trailing_zeros_into_exponent() {
+        let value = Decimal::from_i128_with_scale(10_i128.pow(19), 0);
+        assert_eq!(to_sbe_decimal(value), Some((10_i64.pow(18), 1)));
+        assert_eq!(round_trip(value), Some(value));
+
+        let value = Decimal::from_i128_with_scale(10_i128.pow(20), 2);
+        assert_eq!(to_sbe_decimal(value), Some((10_i64.pow(18), 0)));
+        assert_eq!(round_trip(value), Some(value));
+    }
+
+    #[test]
+    fn test_mantissa_beyond_i64_errors() {
+        let value = Decimal::from_i128_with_scale(i128::from(i64::MAX) + 1, 0);
+        assert_eq!(to_sbe_decimal(value), None);
+
+        let value = Decimal::from_i128_with_scale(i128::from(i64::MIN) - 1, 28);
+        assert_eq!(to_sbe_decimal(value), None);
+
+        assert_eq!(to_sbe_decimal(Decimal::MAX), None);
+    }
+
+    #[test]
+    fn test_from_sbe_decimal_negative_exponent_beyond_max_scale() {
+        // Trailing zeros are dropped to fit the largest scale
+        assert_eq!(
+            from_sbe_decimal(1000, -30),
+            Some(Decimal::from_i128_with_scale(10, 28))
+        );
+
+        // Without trailing zeros the value cannot be represented
+        assert_eq!(from_sbe_decimal(1, -29), None);
+        assert_eq!(from_sbe_decimal(-1, i8::MIN), None);
+    }
+
+    #[test]
+    fn test_from_sbe_decimal_positive_exponent() {
+        assert_eq!(from_sbe_decimal(-15, 3), Some(Decimal::from(-15_000)));
+
+        // Beyond the range of Decimal
+        assert_eq!(from_sbe_decimal(i64::MAX, 10), None);
+        assert_eq!(from_sbe_decimal(1, i8::MAX), None);
+    }
+}
@@ -0,0 +1,243 @@
+use crate::errors::SbeDecodeError;
+use crate::MessageType;
+use sbe_bindings::{
+    group_size_encoding_codec, message_header_codec, MessageHeaderDecoder, ReadBuf,
+};
+
+/// Schema id shared by all generated codecs.
+pub(crate) const SBE_SCHEMA_ID: u16 = sbe_bindings::client_login_codec::SBE_SCHEMA_ID;
+
+/// First schema version, the oldest version any decoder accepts.
+pub(crate) const SBE_MIN_SCHEMA_VERSION: u16 = 1;
+
+/// Oldest schema version the decoders of OHLCV bars and trade bars accept.
+///
+/// Version 2 changed the prices and volumes of bars and trades from floats to
+/// decimals, so these messages of version 1 cannot be decoded with the current layout.
+/// The layout of all other messages of version 1 is unchanged, so they are accepted.
+///
+/// Newer versions are accepted as well: fields unknown to this decoder follow the
+/// known fields and are skipped using the block length from the header.
+pub(crate) const SBE_DECIMAL_SCHEMA_VERSION: u16 = 2;
+
+/// Checks the bounds and the message header of a buffer without knowing the message.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+///
+/// # Returns
+///
+/// The `MessageHeaderDecoder` wrapping the buffer, used to read the template id,
+/// block length and version of the message.
+///
+/// # Errors
+///
+/// * `BufferTooShort` if the buffer cannot hold the header or the block length given in the header
+/// * `WrongSchemaId` if the header belongs to another schema
+/// * `WrongSchemaVersion` if the header carries a version older than `SBE_MIN_SCHEMA_VERSION`
+///
+pub(crate) fn peek_header(
+    buffer: &[u8],
+) -> Result<MessageHeaderDecoder<ReadBuf<'_>>, SbeDecodeError> {
+    let header_length = message_header_codec::ENCODED_LENGTH;
+    if buffer.len() < header_length {
+        return Err(SbeDecodeError::BufferTooShort {
+            expected: header_length,
+            actual: buffer.len(),
+        });
+    }
+
+    let header = MessageHeaderDecoder::default().wrap(ReadBuf::new(buffer), 0);
+
+    if header.schema_id() != SBE_SCHEMA_ID {
+        return Err(SbeDecodeError::WrongSchemaId {
+            expected: SBE_SCHEMA_ID,
+            actual: header.schema_id(),
+        });
+    }
+
+    check_schema_version(&header, SBE_MIN_SCHEMA_VERSION)?;
+
+    let message_length = header_length + header.block_length() as usize;
+    if buffer.len() < message_length {
+        return Err(SbeDecodeError::BufferTooShort {
+            expected: message_length,
+            actual: buffer.len(),
+        });
+    }
+
+    Ok(header)
+}
+
+/// Checks the bounds and the message header of a buffer before any field is read.
+///
+/// The block length and version from the header are honoured: a message from an older
+/// producer may carry a shorter block as long as it holds all fields of the first version,
+/// and a message from a newer producer may carry a longer block with unknown fields.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+/// * `template_id` - Expected SBE template id of the message
+/// * `min_block_length` - Block length of all fields present since the first version
+///
+/// # Returns
+///
+/// The `MessageHeaderDecoder` wrapping the buffer, ready to be passed to a message decoder.
+///
+/// # Errors
+///
+/// * `BufferTooShort` if the buffer cannot hold the header or the block length given in the header
+/// * `WrongTemplateId` if the header belongs to another message
+/// * `WrongSchemaId` if the header belongs to another schema
+/// * `WrongSchemaVersion` if the header carries a version older than `SBE_MIN_SCHEMA_VERSION`
+/// * `BlockLengthTooShort` if the block length in the header cannot hold the required fields
+///
+pub(crate) fn decode_header(
+    buffer: &[u8],
+    template_id: u16,
+    min_block_length: u16,
+) -> Result<MessageHeaderDecoder<ReadBuf<'_>>, SbeDecodeError> {
+    let header = peek_header(buffer)?;
+
+    if header.template_id() != template_id {
+        return Err(SbeDecodeError::WrongTemplateId {
+            expected: template_id,
+            actual: header.template_id(),
+        });
+    }
+
+    if header.block_length() < min_block_length {
+        return Err(SbeDecodeError::BlockLengthTooShort {
+            expected: min_block_length,
+            actual: header.block_length(),
+        });
+    }
+
+    Ok(header)
+}
+
+/// Checks that a message was encoded with a schema version the decoder can read.
+///
+/// # Arguments
+///
+/// * `header` - Message header returned by `decode_header`
+/// * `min_version` - Oldest schema version with the current layout of the message
+///
+/// # Errors
+///
+/// Returns `WrongSchemaVersion` if the header carries a version older than `min_version`.
+///
+pub(crate) fn check_schema_version(
+    header: &MessageHeaderDecoder<ReadBuf<'_>>,
+    min_version: u16,
+) -> Result<(), SbeDecodeError> {
+    if header.version() < min_version {
+        return Err(SbeDecodeError::WrongSchemaVersion {
+            expected: min_version,
+            actual: header.version(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks the bounds of a repeating group before its dimension or any entry is read.
+///
+/// Like the message block, the block length of the group is taken from the buffer so
+/// entries from a newer producer may carry unknown fields after the known ones.
+///
+/// # Arguments
+///
+/// * `buffer` - Byte buffer to decode
+/// * `limit` - Offset of the group dimension, i.e. the limit of the parent decoder
+/// * `min_block_length` - Block length of all fields of a group entry
+///
+/// # Returns
+///
+/// The number of entries in the group
+///
+/// # Errors
+///
+/// * `BufferTooShort` if the buffer cannot hold the group dimension or all entries
+/// * `BlockLengthTooShort` if the block length of the group cannot hold the entry fields
+///
+pub(crate) fn check_group(
+    buffer: &[u8],
+    limit: usize,
+    min_block_length: u16,
+) -> Result<u16, SbeDecodeError> {
+    let dimension_length = group_size_encoding_codec::ENCODED_LENGTH;
+    if buffer.len() < limit + dimension_length {
+        return Err(SbeDecodeError::BufferTooShort {
+            expected: limit + dimension_length,
+            actual: buffer.len(),
+        });
+    }
+
+    let buf = ReadBuf::new(buffer);
+    let block_length = buf.get_u16_at(limit);
+    let count = buf.get_u16_at(limit + 2);
+
+    if block_length < min_block_length {
+        return Err(SbeDecodeError::BlockLengthTooShort {
+            expected: min_block_length,
+            actual: block_length,
+        });
+    }
+
+    let group_length = dimension_length + block_length as usize * count as usize;
+    if buffer.len() < limit + group_length {
+        return Err(SbeDecodeError::BufferTooShort {
+            expected: limit + group_length,
+            actual: buffer.len(),
+        });
+    }
+
+    Ok(count)
+}
+
+/// Checks that a decoded message type matches the expected message type.
+///
+/// # Errors
+///
+/// Returns `WrongMessageType` if the raw value does not map to the expected message type.
+///
+pub(crate) fn check_message_type(
+    expected: MessageType,
+    raw_message_type: u16,
+) -> Result<(), SbeDecodeError> {
+    if MessageType::from(raw_message_type) == expected {
+        Ok(())
+    } else {
+        Err(SbeDecodeError::WrongMessageType {
+            expected,
+            actual: raw_message_type,
+        })
+    }
+}
+
+/// Checks that a raw enum value survived the conversion into its enum.
+///
+/// The enum conversions in this crate map unknown values to a default variant;
+/// comparing the converted value with the raw value detects that fallback.
+///
+/// # Errors
+///
+/// Returns `UnknownEnumValue` if the raw value is not a known variant.
+///
+pub(crate) fn check_enum_value(
+    field: &'static str,
+    raw_value: u8,
+    converted_value: u8,
+) -> Result<(), SbeDecodeError> {
+    if raw_value == converted_value {
+        Ok(())
+    } else {
+        Err(SbeDecodeError::UnknownEnumValue {
+            field,
+            value: u16::from(raw_value),
+        })
+    }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod decimal_utils;
+pub(crate) mod decode_utils;
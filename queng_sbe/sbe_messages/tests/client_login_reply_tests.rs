@@ -0,0 +1,16 @@
+use sbe_messages::{decode_any, ClientLoginReplyMessage, MessageType, SbeMessage};
+
+#[test]
+fn test_encode_decode_client_login_reply() {
+    let message = ClientLoginReplyMessage::new(7);
+    let (size, buffer) = message.encode().unwrap();
+    assert_eq!(size, 12);
+
+    let decoded = ClientLoginReplyMessage::try_from(buffer.as_slice()).unwrap();
+    assert_eq!(decoded.message_type(), &MessageType::ClientLoginReply);
+    assert_eq!(decoded.client_id(), 7);
+    assert_eq!(decoded, message);
+
+    let decoded = decode_any(&buffer).unwrap();
+    assert_eq!(decoded, SbeMessage::ClientLoginReply(message));
+}
@@ -0,0 +1,66 @@
+use sbe_messages::{decode_any, ClientLoginMessage, MessageType, SbeMessage};
+
+/// Overwrites the schema version in the message header.
+fn set_version(buffer: &mut [u8], version: u16) {
+    // Header: blockLength @0, templateId @2, schemaId @4, version @6
+    buffer[6..8].copy_from_slice(&version.to_le_bytes());
+}
+
+#[test]
+fn test_encode_decode_client_login_with_secret() {
+    let secret = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210;
+    let message = ClientLoginMessage::with_secret(7, secret);
+    let (size, buffer) = message.encode().unwrap();
+    assert_eq!(size, 28);
+
+    let decoded = ClientLoginMessage::try_from(buffer.as_slice()).unwrap();
+    assert_eq!(decoded.message_type(), &MessageType::ClientLogin);
+    assert_eq!(decoded.client_id(), 7);
+    assert_eq!(decoded.secret(), Some(secret));
+    assert_eq!(decoded, message);
+
+    let decoded = decode_any(&buffer).unwrap();
+    assert_eq!(decoded, SbeMessage::ClientLogin(message));
+}
+
+#[test]
+fn test_encode_decode_client_login_without_secret() {
+    let message = ClientLoginMessage::new(7);
+    let (_, buffer) = message.encode().unwrap();
+
+    let decoded = ClientLoginMessage::try_from(buffer.as_slice()).unwrap();
+    assert_eq!(decoded.secret(), None);
+    assert_eq!(decoded, message);
+}
+
+#[test]
+fn test_decode_client_login_before_version_8_has_no_secret() {
+    let message = ClientLoginMessage::with_secret(7, 42);
+    let (_, mut buffer) = message.encode().unwrap();
+    set_version(&mut buffer, 7);
+
+    let decoded = ClientLoginMessage::try_from(buffer.as_slice()).unwrap();
+    assert_eq!(decoded, ClientLoginMessage::new(7));
+
+    // A login of version 7 carries the client id only
+    let mut buffer = buffer[..12].to_vec();
+    buffer[0..2].copy_from_slice(&4_u16.to_le_bytes());
+    let decoded = ClientLoginMessage::try_from(buffer.as_slice()).unwrap();
+    assert_eq!(decoded, ClientLoginMessage::new(7));
+}
+
+#[test]
+fn test_client_login_secret_hex() {
+    let message = ClientLoginMessage::with_secret(7, 0xff);
+    assert_eq!(
+        message.secret_hex().as_deref(),
+        Some("000000000000000000000000000000ff")
+    );
+    assert_eq!(ClientLoginMessage::new(7).secret_hex(), None);
+}
+
+#[test]
+fn test_client_login_display_hides_secret() {
+    let message = ClientLoginMessage::with_secret(7, 0xabcdef);
+    assert!(!message.to_string().contains("abcdef"));
+}
@@ -0,0 +1,199 @@
+use sbe_messages::{
+    decode_any, ClientLoginMessage, DataType, ExchangeID, MessageType, SbeDecodeError, SbeMessage,
+    StartDataMessage, TimeResolution,
+};
+
+// Header: blockLength @0, templateId @2, schemaId @4, version @6
+const HEADER_LENGTH: usize = 8;
+
+// Start data block: messageType @0, clientId @2, exchangeId @4, symbolId @5,
+// timeResolution @7, dataTypeId @8
+const START_DATA_BLOCK_LENGTH: u16 = 9;
+const START_DATA_TEMPLATE_ID: u16 = 201;
+const CLIENT_LOGIN_TEMPLATE_ID: u16 = 101;
+
+fn start_data() -> StartDataMessage {
+    StartDataMessage::new(
+        7,
+        ExchangeID::BNB,
+        1,
+        TimeResolution::OneMin,
+        DataType::TradeData,
+    )
+}
+
+fn encoded_start_data() -> Vec<u8> {
+    let (size, buffer) = start_data().encode().unwrap();
+    assert_eq!(size, HEADER_LENGTH + START_DATA_BLOCK_LENGTH as usize);
+    buffer
+}
+
+fn set_u16(buffer: &mut [u8], offset: usize, value: u16) {
+    buffer[offset..offset + 2].copy_from_slice(&value.to_le_bytes());
+}
+
+/// Asserts that both the message decoder and `decode_any` reject the buffer.
+fn assert_rejected(buffer: &[u8], expected: SbeDecodeError) {
+    assert_eq!(StartDataMessage::try_from(buffer), Err(expected.clone()));
+    assert_eq!(decode_any(buffer), Err(expected));
+}
+
+#[test]
+fn test_decode_start_data() {
+    let buffer = encoded_start_data();
+    assert_eq!(
+        StartDataMessage::try_from(buffer.as_slice()),
+        Ok(start_data())
+    );
+    assert_eq!(decode_any(&buffer), Ok(SbeMessage::StartData(start_data())));
+}
+
+#[test]
+fn test_decode_empty_buffer() {
+    assert_rejected(
+        &[],
+        SbeDecodeError::BufferTooShort {
+            expected: HEADER_LENGTH,
+            actual: 0,
+        },
+    );
+}
+
+#[test]
+fn test_decode_truncated_header() {
+    let buffer = encoded_start_data();
+    assert_rejected(
+        &buffer[..HEADER_LENGTH - 1],
+        SbeDecodeError::BufferTooShort {
+            expected: HEADER_LENGTH,
+            actual: HEADER_LENGTH - 1,
+        },
+    );
+}
+
+#[test]
+fn test_decode_truncated_block() {
+    let buffer = encoded_start_data();
+    for length in HEADER_LENGTH..buffer.len() {
+        assert_rejected(
+            &buffer[..length],
+            SbeDecodeError::BufferTooShort {
+                expected: buffer.len(),
+                actual: length,
+            },
+        );
+    }
+}
+
+#[test]
+fn test_decode_block_length_beyond_buffer() {
+    let mut buffer = encoded_start_data();
+    set_u16(&mut buffer, 0, START_DATA_BLOCK_LENGTH + 1);
+    assert_rejected(
+        &buffer,
+        SbeDecodeError::BufferTooShort {
+            expected: buffer.len() + 1,
+            actual: buffer.len(),
+        },
+    );
+}
+
+#[test]
+fn test_decode_block_length_too_short() {
+    let mut buffer = encoded_start_data();
+    set_u16(&mut buffer, 0, START_DATA_BLOCK_LENGTH - 1);
+    assert_rejected(
+        &buffer,
+        SbeDecodeError::BlockLengthTooShort {
+            expected: START_DATA_BLOCK_LENGTH,
+            actual: START_DATA_BLOCK_LENGTH - 1,
+        },
+    );
+}
+
+#[test]
+fn test_decode_skips_unknown_fields_of_newer_block() {
+    let mut buffer = encoded_start_data();
+    set_u16(&mut buffer, 0, START_DATA_BLOCK_LENGTH + 4);
+    buffer.extend_from_slice(&[0xff; 4]);
+
+    assert_eq!(
+        StartDataMessage::try_from(buffer.as_slice()),
+        Ok(start_data())
+    );
+    assert_eq!(decode_any(&buffer), Ok(SbeMessage::StartData(start_data())));
+}
+
+#[test]
+fn test_decode_wrong_schema_id() {
+    let mut buffer = encoded_start_data();
+    set_u16(&mut buffer, 4, 2);
+    assert_rejected(
+        &buffer,
+        SbeDecodeError::WrongSchemaId {
+            expected: 1,
+            actual: 2,
+        },
+    );
+}
+
+#[test]
+fn test_decode_wrong_template_id() {
+    let buffer = encoded_start_data();
+    assert_eq!(
+        ClientLoginMessage::try_from(buffer.as_slice()),
+        Err(SbeDecodeError::WrongTemplateId {
+            expected: CLIENT_LOGIN_TEMPLATE_ID,
+            actual: START_DATA_TEMPLATE_ID,
+        })
+    );
+}
+
+#[test]
+fn test_decode_unknown_template_id() {
+    let mut buffer = encoded_start_data();
+    set_u16(&mut buffer, 2, 999);
+    assert_eq!(
+        decode_any(&buffer),
+        Err(SbeDecodeError::UnknownTemplateId(999))
+    );
+    assert_eq!(
+        StartDataMessage::try_from(buffer.as_slice()),
+        Err(SbeDecodeError::WrongTemplateId {
+            expected: START_DATA_TEMPLATE_ID,
+            actual: 999,
+        })
+    );
+}
+
+#[test]
+fn test_decode_wrong_message_type() {
+    let mut buffer = encoded_start_data();
+    let stop_data = MessageType::StopData as u16;
+    set_u16(&mut buffer, HEADER_LENGTH, stop_data);
+    assert_rejected(
+        &buffer,
+        SbeDecodeError::WrongMessageType {
+            expected: MessageType::StartData,
+            actual: stop_data,
+        },
+    );
+}
+
+#[test]
+fn test_decode_unknown_enum_values() {
+    let cases = [
+        (HEADER_LENGTH + 4, "exchange_id"),
+        (HEADER_LENGTH + 7, "time_resolution"),
+        (HEADER_LENGTH + 8, "data_type_id"),
+    ];
+
+    for (offset, field) in cases {
+        let mut buffer = encoded_start_data();
+        buffer[offset] = 0xfe;
+        assert_rejected(
+            &buffer,
+            SbeDecodeError::UnknownEnumValue { field, value: 0xfe },
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use sbe_messages::{decode_any, HeartbeatMessage, MessageType, SbeMessage};
+
+/// Rewrites an encoded heartbeat into the layout of schema version 6,
+/// the last version before the optional timestamp was added.
+fn downgrade_to_version_6(mut buffer: Vec<u8>) -> Vec<u8> {
+    // Header: blockLength @0, templateId @2, schemaId @4, version @6
+    buffer[0..2].copy_from_slice(&4u16.to_le_bytes());
+    buffer[6..8].copy_from_slice(&6u16.to_le_bytes());
+    buffer.truncate(8 + 4);
+    buffer
+}
+
+#[test]
+fn test_encode_decode_heartbeat() {
+    let message = HeartbeatMessage::new(7);
+    let (size, buffer) = message.encode().unwrap();
+    assert_eq!(size, 20);
+
+    let decoded = HeartbeatMessage::try_from(buffer.as_slice()).unwrap();
+    assert_eq!(decoded.message_type(), &MessageType::Heartbeat);
+    assert_eq!(decoded.client_id(), 7);
+    assert_eq!(decoded.timestamp(), None);
+    assert_eq!(decoded, message);
+
+    let decoded = decode_any(&buffer).unwrap();
+    assert_eq!(decoded, SbeMessage::Heartbeat(message));
+}
+
+#[test]
+fn test_decode_heartbeat_with_timestamp() {
+    let message = HeartbeatMessage::with_timestamp(7, 1_700_000_000_000);
+    let (_, buffer) = message.encode().unwrap();
+
+    let decoded = HeartbeatMessage::try_from(buffer.as_slice()).unwrap();
+
+    assert_eq!(decoded.timestamp(), Some(1_700_000_000_000));
+    assert_eq!(decoded, message);
+}
+
+#[test]
+fn test_decode_heartbeat_from_version_6() {
+    let message = HeartbeatMessage::with_timestamp(7, 1_700_000_000_000);
+    let (_, buffer) = message.encode().unwrap();
+    let buffer = downgrade_to_version_6(buffer);
+
+    let decoded = HeartbeatMessage::try_from(buffer.as_slice()).unwrap();
+
+    assert_eq!(decoded.message_type(), &MessageType::Heartbeat);
+    assert_eq!(decoded.client_id(), 7);
+    assert_eq!(decoded.timestamp(), None);
+}
@@ -0,0 +1,257 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sbe_messages::{
+    decode_any, BookSide, OrderBookLevel, OrderBookLevelUpdate, OrderBookSnapshotMessage,
+    OrderBookUpdateMessage, SbeDecodeError, SbeMessage,
+};
+
+// Header (8) followed by the message block: messageType, symbolId, dateTime, sequenceNumber
+const GROUPS_OFFSET: usize = 8 + 20;
+// Group dimension: blockLength @0, numInGroup @2
+const DIMENSION_LENGTH: usize = 4;
+// Snapshot level: price @0, size @9
+const LEVEL_LENGTH: usize = 18;
+// Update level: side @0, price @1, size @10
+const LEVEL_UPDATE_LENGTH: usize = 19;
+
+fn date_time() -> DateTime<Utc> {
+    DateTime::from_timestamp(1_700_000_000, 123_456_000).unwrap()
+}
+
+fn level(price: i64, scale: u32, size: i64) -> OrderBookLevel {
+    OrderBookLevel::new(Decimal::new(price, scale), Decimal::from(size))
+}
+
+fn snapshot() -> OrderBookSnapshotMessage {
+    let bids = vec![level(425_005, 2, 3), level(4_250, 0, 1), level(-1, 8, 7)];
+    let asks = vec![level(42_501, 1, 2), level(9_999_999_999, 4, 5)];
+    OrderBookSnapshotMessage::new(1, date_time(), 42, bids, asks)
+}
+
+fn update() -> OrderBookUpdateMessage {
+    let levels = vec![
+        OrderBookLevelUpdate::new(BookSide::Bid, Decimal::new(425_005, 2), Decimal::ONE),
+        OrderBookLevelUpdate::new(BookSide::Ask, Decimal::new(42_501, 1), Decimal::ZERO),
+    ];
+    OrderBookUpdateMessage::new(1, date_time(), 43, levels)
+}
+
+/// Builds `count` bid levels with strictly descending prices.
+fn bids(count: usize) -> Vec<OrderBookLevel> {
+    (0..count)
+        .map(|i| level((count - i) as i64, 2, 1))
+        .collect()
+}
+
+fn set_u16(buffer: &mut [u8], offset: usize, value: u16) {
+    buffer[offset..offset + 2].copy_from_slice(&value.to_le_bytes());
+}
+
+#[test]
+fn test_snapshot_round_trip() {
+    let message = snapshot();
+    let (size, buffer) = message.encode().unwrap();
+    assert_eq!(
+        size,
+        GROUPS_OFFSET + 2 * DIMENSION_LENGTH + 5 * LEVEL_LENGTH
+    );
+
+    let decoded = OrderBookSnapshotMessage::try_from(buffer.as_slice()).unwrap();
+    assert_eq!(decoded, message);
+    assert_eq!(decoded.bids()[0].price().scale(), 2);
+    assert_eq!(
+        decode_any(&buffer),
+        Ok(SbeMessage::OrderBookSnapshot(message))
+    );
+}
+
+#[test]
+fn test_snapshot_round_trip_empty_groups() {
+    let message = OrderBookSnapshotMessage::new(1, date_time(), 42, vec![], vec![]);
+    let (size, buffer) = message.encode().unwrap();
+    assert_eq!(size, GROUPS_OFFSET + 2 * DIMENSION_LENGTH);
+    assert_eq!(
+        OrderBookSnapshotMessage::try_from(buffer.as_slice()),
+        Ok(message)
+    );
+
+    // Only one side empty
+    let message = OrderBookSnapshotMessage::new(1, date_time(), 42, bids(2), vec![]);
+    let (_, buffer) = message.encode().unwrap();
+    assert_eq!(
+        OrderBookSnapshotMessage::try_from(buffer.as_slice()),
+        Ok(message)
+    );
+}
+
+#[test]
+fn test_snapshot_round_trip_max_levels() {
+    let message =
+        OrderBookSnapshotMessage::new(1, date_time(), 42, bids(u16::MAX as usize), vec![]);
+    let (size, buffer) = message.encode().unwrap();
+    assert_eq!(
+        size,
+        GROUPS_OFFSET + 2 * DIMENSION_LENGTH + u16::MAX as usize * LEVEL_LENGTH
+    );
+    assert_eq!(
+        OrderBookSnapshotMessage::try_from(buffer.as_slice()),
+        Ok(message)
+    );
+}
+
+#[test]
+fn test_snapshot_rejects_oversized_group() {
+    let levels = u16::MAX as usize + 1;
+    let message = OrderBookSnapshotMessage::new(1, date_time(), 42, bids(levels), vec![]);
+
+    let error = message.validate().unwrap_err();
+    assert!(
+        error.0.contains(&format!("{levels} bids exceed")),
+        "{error}"
+    );
+    assert!(message.encode().is_err());
+}
+
+#[test]
+fn test_snapshot_rejects_truncated_groups() {
+    let (_, buffer) = snapshot().encode().unwrap();
+
+    // Within the last ask level
+    let length = buffer.len() - 1;
+    assert_eq!(
+        OrderBookSnapshotMessage::try_from(&buffer[..length]),
+        Err(SbeDecodeError::BufferTooShort {
+            expected: buffer.len(),
+            actual: length,
+        })
+    );
+
+    // Within the dimension of the asks group
+    let asks_offset = GROUPS_OFFSET + DIMENSION_LENGTH + 3 * LEVEL_LENGTH;
+    let length = asks_offset + 2;
+    assert_eq!(
+        OrderBookSnapshotMessage::try_from(&buffer[..length]),
+        Err(SbeDecodeError::BufferTooShort {
+            expected: asks_offset + DIMENSION_LENGTH,
+            actual: length,
+        })
+    );
+
+    // Before the bids group
+    assert_eq!(
+        decode_any(&buffer[..GROUPS_OFFSET]),
+        Err(SbeDecodeError::BufferTooShort {
+            expected: GROUPS_OFFSET + DIMENSION_LENGTH,
+            actual: GROUPS_OFFSET,
+        })
+    );
+}
+
+#[test]
+fn test_snapshot_rejects_group_count_beyond_buffer() {
+    let (_, mut buffer) = snapshot().encode().unwrap();
+    set_u16(&mut buffer, GROUPS_OFFSET + 2, u16::MAX);
+
+    assert_eq!(
+        OrderBookSnapshotMessage::try_from(buffer.as_slice()),
+        Err(SbeDecodeError::BufferTooShort {
+            expected: GROUPS_OFFSET + DIMENSION_LENGTH + u16::MAX as usize * LEVEL_LENGTH,
+            actual: buffer.len(),
+        })
+    );
+}
+
+#[test]
+fn test_snapshot_rejects_group_block_length_too_short() {
+    let (_, mut buffer) = snapshot().encode().unwrap();
+    set_u16(&mut buffer, GROUPS_OFFSET, LEVEL_LENGTH as u16 - 1);
+
+    assert_eq!(
+        OrderBookSnapshotMessage::try_from(buffer.as_slice()),
+        Err(SbeDecodeError::BlockLengthTooShort {
+            expected: LEVEL_LENGTH as u16,
+            actual: LEVEL_LENGTH as u16 - 1,
+        })
+    );
+}
+
+#[test]
+fn test_snapshot_rejects_unsorted_levels() {
+    let (_, mut buffer) = snapshot().encode().unwrap();
+    let first = GROUPS_OFFSET + DIMENSION_LENGTH;
+    let second = first + LEVEL_LENGTH;
+    let first_level = buffer[first..second].to_vec();
+    buffer.copy_within(second..second + LEVEL_LENGTH, first);
+    buffer[second..second + LEVEL_LENGTH].copy_from_slice(&first_level);
+
+    let result = OrderBookSnapshotMessage::try_from(buffer.as_slice());
+    assert!(
+        matches!(&result, Err(SbeDecodeError::InvalidMessage(e)) if e.contains("not sorted")),
+        "{result:?}"
+    );
+}
+
+#[test]
+fn test_update_round_trip() {
+    let message = update();
+    let (size, buffer) = message.encode().unwrap();
+    assert_eq!(
+        size,
+        GROUPS_OFFSET + DIMENSION_LENGTH + 2 * LEVEL_UPDATE_LENGTH
+    );
+
+    let decoded = OrderBookUpdateMessage::try_from(buffer.as_slice()).unwrap();
+    assert_eq!(decoded, message);
+    assert!(decoded.levels()[1].is_removal());
+    assert_eq!(
+        decode_any(&buffer),
+        Ok(SbeMessage::OrderBookUpdate(message))
+    );
+}
+
+#[test]
+fn test_update_round_trip_empty_group() {
+    let message = OrderBookUpdateMessage::new(1, date_time(), 43, vec![]);
+    let (size, buffer) = message.encode().unwrap();
+    assert_eq!(size, GROUPS_OFFSET + DIMENSION_LENGTH);
+    assert_eq!(
+        OrderBookUpdateMessage::try_from(buffer.as_slice()),
+        Ok(message)
+    );
+}
+
+#[test]
+fn test_update_rejects_oversized_group() {
+    let level = OrderBookLevelUpdate::new(BookSide::Bid, Decimal::ONE, Decimal::ONE);
+    let levels = vec![level; u16::MAX as usize + 1];
+    let message = OrderBookUpdateMessage::new(1, date_time(), 43, levels);
+
+    assert!(message.validate().is_err());
+    assert!(message.encode().is_err());
+}
+
+#[test]
+fn test_update_rejects_truncated_group() {
+    let (_, buffer) = update().encode().unwrap();
+    let length = buffer.len() - LEVEL_UPDATE_LENGTH;
+    assert_eq!(
+        OrderBookUpdateMessage::try_from(&buffer[..length]),
+        Err(SbeDecodeError::BufferTooShort {
+            expected: buffer.len(),
+            actual: length,
+        })
+    );
+}
+
+#[test]
+fn test_update_rejects_unknown_side() {
+    let (_, mut buffer) = update().encode().unwrap();
+    buffer[GROUPS_OFFSET + DIMENSION_LENGTH] = 0xfe;
+    assert_eq!(
+        OrderBookUpdateMessage::try_from(buffer.as_slice()),
+        Err(SbeDecodeError::UnknownEnumValue {
+            field: "side",
+            value: 0xfe,
+        })
+    );
+}
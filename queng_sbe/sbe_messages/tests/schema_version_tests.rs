@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sbe_messages::{
+    decode_any, ClientLoginMessage, OHLCVBarMessage, SbeDecodeError, SbeMessage, TradeBarMessage,
+};
+
+fn date_time() -> DateTime<Utc> {
+    DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+}
+
+/// Overwrites the schema version in the message header.
+fn set_version(buffer: &mut [u8], version: u16) {
+    // Header: blockLength @0, templateId @2, schemaId @4, version @6
+    buffer[6..8].copy_from_slice(&version.to_le_bytes());
+}
+
+#[test]
+fn test_decode_rejects_version_1() {
+    let message = TradeBarMessage::new(1, date_time(), Decimal::new(4250, 2), Decimal::new(3, 1));
+    let (_, mut buffer) = message.encode().unwrap();
+    set_version(&mut buffer, 1);
+
+    let expected = SbeDecodeError::WrongSchemaVersion {
+        expected: 2,
+        actual: 1,
+    };
+    assert_eq!(
+        TradeBarMessage::try_from(buffer.as_slice()),
+        Err(expected.clone())
+    );
+    assert_eq!(decode_any(&buffer), Err(expected));
+}
+
+#[test]
+fn test_decode_accepts_version_2() {
+    let message = TradeBarMessage::new(1, date_time(), Decimal::new(4250, 2), Decimal::new(3, 1));
+    let (_, mut buffer) = message.encode().unwrap();
+    set_version(&mut buffer, 2);
+
+    assert_eq!(TradeBarMessage::try_from(buffer.as_slice()), Ok(message));
+}
+
+#[test]
+fn test_decode_ohlcv_bar_rejects_version_1() {
+    let price = Decimal::new(4250, 2);
+    let message = OHLCVBarMessage::new(1, date_time(), price, price, price, price, Decimal::ONE);
+    let (_, mut buffer) = message.encode().unwrap();
+    set_version(&mut buffer, 1);
+
+    let expected = SbeDecodeError::WrongSchemaVersion {
+        expected: 2,
+        actual: 1,
+    };
+    assert_eq!(
+        OHLCVBarMessage::try_from(buffer.as_slice()),
+        Err(expected.clone())
+    );
+    assert_eq!(decode_any(&buffer), Err(expected));
+}
+
+#[test]
+fn test_decode_client_login_accepts_version_1() {
+    let message = ClientLoginMessage::new(7);
+    let (_, mut buffer) = message.encode().unwrap();
+    set_version(&mut buffer, 1);
+
+    assert_eq!(
+        ClientLoginMessage::try_from(buffer.as_slice()),
+        Ok(message.clone())
+    );
+    assert_eq!(decode_any(&buffer), Ok(SbeMessage::ClientLogin(message)));
+}
@@ -1,5 +1,6 @@
 mod config;
 
+use data_integration_factory::{DataIntegration, SampleDataIntegration};
 use std::error::Error;
 
 const DBG: bool = true;
@@ -9,12 +10,14 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let service_name: &str = "sample_data_service";
     let ims_data_integration_config = config::ims_data_integration_config();
     let ims_data_iggy_config = config::ims_data_iggy_config();
+    let integration = DataIntegration::SampleDataIntegration(SampleDataIntegration::new());
 
     ims_data_service::start(
         DBG,
         service_name,
         ims_data_integration_config,
         ims_data_iggy_config,
+        integration,
     )
     .await
     .expect("Failed to start server");
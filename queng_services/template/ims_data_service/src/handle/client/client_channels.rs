@@ -0,0 +1,187 @@
+use crate::handle::MessageHandler;
+use common_errors::MessageProcessingError;
+use common_iggy::{IggyConfig, IggyUser};
+use common_ims::IntegrationMessageConfig;
+use message_bus::{MessageBus, MessageBusError};
+
+/// The data and error channel of a logged in client.
+///
+/// Both channels are topics of a stream only the client's stream user can read.
+/// Messages are sent through the message bus of the service, so the channels of all
+/// clients share one connection and cloning the channels is cheap.
+#[derive(Debug, Clone)]
+pub(crate) struct ClientChannels {
+    stream_id: String,
+    data_topic_id: String,
+    error_topic_id: String,
+    username: String,
+}
+
+impl ClientChannels {
+    /// Sends an encoded message on the data channel of the client.
+    pub(crate) async fn send_data<B: MessageBus>(
+        &self,
+        bus: &B,
+        bytes: Vec<u8>,
+    ) -> Result<(), MessageBusError> {
+        bus.send(&self.stream_id, &self.data_topic_id, None, vec![bytes])
+            .await
+    }
+
+    /// Sends an encoded message on the error channel of the client.
+    pub(crate) async fn send_error<B: MessageBus>(
+        &self,
+        bus: &B,
+        bytes: Vec<u8>,
+    ) -> Result<(), MessageBusError> {
+        bus.send(&self.stream_id, &self.error_topic_id, None, vec![bytes])
+            .await
+    }
+}
+
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
+    /// Returns the iggy config of a client, naming its stream and its stream user.
+    ///
+    /// The stream is "stream_{client_id}" and the stream user "client_{client_id}".
+    ///
+    /// # Parameters
+    ///
+    /// - `client_id`: The ID of the client
+    /// - `secret`: The password of the client stream user
+    ///
+    pub(crate) fn client_iggy_config(&self, client_id: u16, secret: &str) -> IggyConfig {
+        let user = IggyUser::new(&format!("client_{client_id}"), secret);
+        IggyConfig::from_client_id(
+            user,
+            u32::from(client_id),
+            self.iggy_config().messages_per_batch(),
+            self.iggy_config().auto_commit(),
+        )
+    }
+
+    /// Returns the message config of a client, naming its data and error channel.
+    ///
+    /// # Parameters
+    ///
+    /// - `client_id`: The ID of the client
+    ///
+    pub(crate) fn client_message_config(&self, client_id: u16) -> IntegrationMessageConfig {
+        let version = self
+            .integration_config()
+            .integration_message_config()
+            .version();
+        IntegrationMessageConfig::new(client_id, *version)
+    }
+
+    /// Creates the stream, the stream user and the data and error channels of a client.
+    ///
+    /// The stream user may only read the client stream and logs in with the secret
+    /// the client sent with this login. A stream left over from a previous session is reused,
+    /// a stream user left over from a previous session is replaced, so an old secret
+    /// cannot be used to read the channels.
+    ///
+    /// # Parameters
+    ///
+    /// - `client_id`: The ID of the client
+    /// - `secret`: The password of the client stream user
+    ///
+    /// # Returns
+    ///
+    /// The `ClientChannels` of the client, or a MessageProcessingError if there was an issue.
+    ///
+    /// # Errors
+    ///
+    /// - MessageProcessingError if the stream, the channels or the user cannot be created.
+    ///
+    pub(crate) async fn open_client_channels(
+        &self,
+        client_id: u16,
+        secret: &str,
+    ) -> Result<ClientChannels, MessageProcessingError> {
+        let channel_error = |e: &dyn std::fmt::Display| {
+            MessageProcessingError(format!(
+                "[open_client_channels]: Failed to open channels of client {client_id}: {e}"
+            ))
+        };
+
+        let client_config = self.client_iggy_config(client_id, secret);
+        let message_config = self.client_message_config(client_id);
+        let stream = client_config.stream_name();
+        let user = client_config.user();
+        let data_channel = message_config.data_channel();
+        let error_channel = message_config.error_channel();
+
+        // Create the client stream with its data and error channel; a stream left over is reused
+        match self.bus().create_stream(stream).await {
+            Ok(()) | Err(MessageBusError::StreamAlreadyExists(_)) => {}
+            Err(e) => return Err(channel_error(&e)),
+        }
+
+        let partitions_count = self.iggy_config().topic_config().partitions_count();
+        for topic in [&data_channel, &error_channel] {
+            match self
+                .bus()
+                .create_topic(stream, topic, partitions_count)
+                .await
+            {
+                Ok(()) | Err(MessageBusError::TopicAlreadyExists { .. }) => {}
+                Err(e) => return Err(channel_error(&e)),
+            }
+        }
+
+        // Replace a stream user left over from a previous session with a new secret
+        match self.bus().delete_user(user.username()).await {
+            Ok(()) | Err(MessageBusError::UserNotFound(_)) => {}
+            Err(e) => return Err(channel_error(&e)),
+        }
+
+        // Allow the client stream user to read the client stream only
+        self.bus()
+            .create_user(stream, user.username(), user.password())
+            .await
+            .map_err(|e| channel_error(&e))?;
+
+        let channels = ClientChannels {
+            stream_id: stream.to_string(),
+            data_topic_id: data_channel,
+            error_topic_id: error_channel,
+            username: user.username().to_string(),
+        };
+
+        Ok(channels)
+    }
+
+    /// Deletes the stream and the stream user of a client.
+    ///
+    /// # Parameters
+    ///
+    /// - `client_id`: The ID of the client
+    /// - `channels`: The `ClientChannels` of the client
+    ///
+    /// # Errors
+    ///
+    /// - MessageProcessingError if the stream or the user cannot be deleted.
+    ///
+    pub(crate) async fn close_client_channels(
+        &self,
+        client_id: u16,
+        channels: ClientChannels,
+    ) -> Result<(), MessageProcessingError> {
+        let channel_error = |e: &dyn std::fmt::Display| {
+            MessageProcessingError(format!(
+                "[close_client_channels]: Failed to close channels of client {client_id}: {e}"
+            ))
+        };
+
+        // Deleting the stream deletes the data and error topics
+        match self.bus().delete_stream(&channels.stream_id).await {
+            Ok(()) | Err(MessageBusError::StreamNotFound(_)) => {}
+            Err(e) => return Err(channel_error(&e)),
+        }
+
+        match self.bus().delete_user(&channels.username).await {
+            Ok(()) | Err(MessageBusError::UserNotFound(_)) => Ok(()),
+            Err(e) => Err(channel_error(&e)),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::handle::MessageHandler;
+use common_errors::MessageProcessingError;
+use message_bus::MessageBus;
+use sbe_messages::{ClientErrorType, HeartbeatMessage};
+
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
+    /// Handles a heartbeat message of a client.
+    ///
+    /// Marks the session of a logged in client as seen. Only heartbeats keep a session
+    /// alive; a client must keep sending them even while it sends other requests.
+    /// A client that is not logged in, i.e. because it was logged out after missing
+    /// too many heartbeats, gets a ClientNotLoggedIn error so it can log in again.
+    ///
+    /// # Parameters
+    ///
+    /// - `heartbeat_msg`: The incoming HeartbeatMessage from the client
+    ///
+    /// # Returns
+    ///
+    /// Result with no value if successful, or a MessageProcessingError if an error occurs.
+    ///
+    /// # Errors
+    ///
+    /// - MessageProcessingError if the error cannot be sent.
+    ///
+    pub(crate) async fn handle_client_heartbeat(
+        &self,
+        heartbeat_msg: &HeartbeatMessage,
+    ) -> Result<(), MessageProcessingError> {
+        let client_id = heartbeat_msg.client_id();
+
+        let logged_in = self.sessions().write().await.touch(client_id);
+        if !logged_in {
+            return self
+                .send_client_error(client_id, ClientErrorType::ClientNotLoggedIn)
+                .await;
+        }
+
+        Ok(())
+    }
+}
@@ -1,9 +1,10 @@
-use crate::service::Server;
+use crate::handle::MessageHandler;
+use crate::session::ClientSession;
 use common_errors::MessageProcessingError;
-use message_producer::MessageProducer;
-use sbe_messages::{ClientErrorType, ClientLoginMessage};
+use message_bus::MessageBus;
+use sbe_messages::{ClientErrorType, ClientLoginMessage, ClientLoginReplyMessage};
 
-impl Server {
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
     /// Handles a client login message by validating the client ID and logging them in.
     ///
     /// Gets the client's control channel, checks if they are already logged in, and logs them in if not.
@@ -22,7 +23,6 @@ impl Server {
     /// - MessageProcessingError if there is an issue getting the client's control channel, checking their login status,
     ///   or logging them in.
     ///
-    /// ```
     pub(crate) async fn handle_client_login(
         &self,
         client_login_msg: &ClientLoginMessage,
@@ -55,7 +55,7 @@ impl Server {
                 //
                 false => {
                     // println!("::handle_client_login]: Client not logged in, proceed with login");
-                    let res = self.client_login(client_id).await;
+                    let res = self.client_login(client_login_msg).await;
 
                     match res {
                         Ok(_) => {}
@@ -99,14 +99,17 @@ impl Server {
         Ok(())
     }
 
-    /// Login a client by adding them to the client database.
+    /// Login a client by opening their channels and starting their session.
     ///
-    /// Locks the client manager, creates a config for the client,
-    /// and attempts to add them to the database.
+    /// Creates the client stream with its data and error channels, readable only
+    /// by the client's stream user, and adds a session holding the channels to the session registry.
+    /// The stream user logs in with the secret of the login message, so the secret is
+    /// never sent back. Once the channels are ready, a `ClientLoginReply` is sent
+    /// on the error channel of the client.
     ///
     /// # Parameters
     ///
-    /// - `client_id`: The ID of the client to log in
+    /// - `client_login_msg`: The login message of the client, carrying its secret
     ///
     /// # Returns
     ///
@@ -115,27 +118,48 @@ impl Server {
     ///
     /// # Errors
     ///
-    /// - MessageProcessingError if there was an issue adding the client to the database.
+    /// - MessageProcessingError if the login carries no secret, the channels of the client
+    ///   cannot be created or the login reply cannot be sent.
     ///
-    pub(crate) async fn client_login(&self, client_id: u16) -> Result<(), MessageProcessingError> {
-        // Get new client
-        let user = self.client_stream_user();
-        let stream_id = "".to_string();
-        let topic_id = "".to_string();
+    pub(crate) async fn client_login(
+        &self,
+        client_login_msg: &ClientLoginMessage,
+    ) -> Result<(), MessageProcessingError> {
+        let client_id = client_login_msg.client_id();
+        let Some(secret) = client_login_msg.secret_hex() else {
+            return Err(MessageProcessingError(format!(
+                "[client_login]: Login of client {client_id} carries no secret"
+            )));
+        };
 
-        // Create an iggy client and initialize it as producer
-        let producer = MessageProducer::new(stream_id, topic_id, user)
-            .await
-            .expect("Failed to create producer client");
+        let channels = self.open_client_channels(client_id, &secret).await?;
 
-        // lock the client_data_producers hashmap
-        let mut client_data_producers = self.client_producers().write().await;
+        // lock the session registry
+        let mut sessions = self.sessions().write().await;
 
-        // add the client data producer to the hashmap
-        client_data_producers.insert(client_id, producer);
+        // add the client session to the registry
+        sessions.insert(client_id, ClientSession::new(channels.clone()));
 
-        // Unlock the client_data_producers hashmap
-        drop(client_data_producers);
+        // Unlock the session registry
+        drop(sessions);
+
+        // Acknowledge the login on the error channel only the client can read
+        let sent = match ClientLoginReplyMessage::new(client_id).encode() {
+            Ok((_, bytes)) => channels
+                .send_error(self.bus().as_ref(), bytes)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+
+        if let Err(e) = sent {
+            if let Err(logout_err) = self.client_logout(client_id).await {
+                println!("[QDGW/client_login] ClientLogInError: {logout_err}");
+            }
+            return Err(MessageProcessingError(format!(
+                "[client_login]: Failed to send login reply to client {client_id}: {e}"
+            )));
+        }
 
         Ok(())
     }
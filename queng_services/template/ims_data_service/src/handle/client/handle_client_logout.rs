@@ -1,8 +1,9 @@
-use crate::service::Server;
+use crate::handle::MessageHandler;
 use common_errors::MessageProcessingError;
+use message_bus::MessageBus;
 use sbe_messages::{ClientErrorType, ClientLogoutMessage};
 
-impl Server {
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
     /// Handles a client logout message by validating the client ID and logging them out.
     ///
     /// Gets the client's control channel, checks if they are logged in, and logs them out if so.
@@ -100,7 +101,8 @@ impl Server {
 
     /// Logs out a client by removing them from the client database.
     ///
-    /// Locks the client manager and removes the client with the given ID.
+    /// Removes the session of the client with the given ID, stops the data the client
+    /// is subscribed to and deletes the client's channels.
     ///
     /// # Parameters
     ///
@@ -113,18 +115,27 @@ impl Server {
     ///
     /// # Errors
     ///
-    /// - MessageProcessingError if there was an issue removing the client from the database.
+    /// - MessageProcessingError if there was an issue stopping the data of the client
+    ///   or deleting the client's channels.
     ///
     pub(crate) async fn client_logout(&self, client_id: u16) -> Result<(), MessageProcessingError> {
-        // lock the client_data_producers hashmap
-        let mut client_data_producers = self.client_producers().write().await;
+        // Remove the client's session from the registry
+        let Some(session) = self.sessions().write().await.remove(client_id) else {
+            return Ok(());
+        };
 
-        // Remove the client's data producer from the hashmap
-        client_data_producers.remove(&client_id);
+        self.dbg_print(&format!(
+            "Client {client_id} logged out after {:?}",
+            session.login_time().elapsed()
+        ));
 
-        // Unlock the client_data_producers hashmap
-        drop(client_data_producers);
+        // Stop the data of the client; the channels are closed even if that fails
+        let removed: Vec<_> = session.subscriptions().iter().copied().collect();
+        let released = self.release_subscriptions(&removed).await;
+        let closed = self
+            .close_client_channels(client_id, session.into_channels())
+            .await;
 
-        Ok(())
+        released.and(closed)
     }
 }
@@ -1,11 +1,12 @@
-use crate::service::Server;
+use crate::handle::MessageHandler;
 use common_errors::MessageProcessingError;
+use message_bus::MessageBus;
 
-impl Server {
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
     /// Checks if a client with the specified ID is logged in.
     ///
-    /// This method checks the client producers map to verify if a client with the given ID
-    /// has an active producer, indicating they are logged in.
+    /// This method checks the session registry to verify if a client with the given ID
+    /// has a session, indicating they are logged in.
     ///
     /// # Arguments
     ///
@@ -20,14 +21,14 @@ impl Server {
     /// # Errors
     ///
     /// Returns a `MessageProcessingError` if:
-    /// * Failed to acquire read lock on client producers map
+    /// * Failed to acquire read lock on the session registry
     /// * Lock is poisoned due to a panic in another thread
     pub(crate) async fn check_client_login(
         &self,
         client_id: u16,
     ) -> Result<bool, MessageProcessingError> {
-        let client_db = self.client_producers().read().await;
+        let sessions = self.sessions().read().await;
 
-        Ok(client_db.contains_key(&client_id))
+        Ok(sessions.contains(client_id))
     }
 }
@@ -1,3 +1,7 @@
+mod client_channels;
+mod handle_client_heartbeat;
 mod handle_client_login;
 mod handle_client_logout;
 mod handle_client_utils;
+
+pub(crate) use client_channels::ClientChannels;
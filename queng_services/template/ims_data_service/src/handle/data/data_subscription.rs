@@ -0,0 +1,77 @@
+use sbe_messages::{DataErrorType, DataType, ExchangeID, StartDataMessage, TimeResolution};
+
+/// The data a client requested with a `StartData` message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct DataSubscription {
+    exchange_id: ExchangeID,
+    symbol_id: u16,
+    data_type: DataType,
+    time_resolution: TimeResolution,
+}
+
+impl DataSubscription {
+    /// Creates the subscription requested by a `StartData` message.
+    ///
+    /// # Parameters
+    ///
+    /// * `start_data_msg` - The incoming StartDataMessage from the client
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataErrorType::DataTypeNotKnownError` if the exchange or the data type is unknown,
+    /// or if OHLCV data is requested without a time resolution.
+    ///
+    pub(crate) fn from_start_data(
+        start_data_msg: &StartDataMessage,
+    ) -> Result<Self, DataErrorType> {
+        let exchange_id = *start_data_msg.exchange_id();
+        let data_type = *start_data_msg.data_type_id();
+        let time_resolution = *start_data_msg.time_resolution();
+
+        let unknown = exchange_id == ExchangeID::NullVal
+            || data_type == DataType::UnknownDataType
+            || (data_type == DataType::OHLCVData && time_resolution == TimeResolution::NoValue);
+
+        if unknown {
+            return Err(DataErrorType::DataTypeNotKnownError);
+        }
+
+        Ok(Self {
+            exchange_id,
+            symbol_id: start_data_msg.symbol_id(),
+            data_type,
+            time_resolution,
+        })
+    }
+
+    /// Returns the data id under which the subscription is started in the data integration.
+    ///
+    /// # Returns
+    ///
+    /// A String in the format: "{exchange_id}-{symbol_id}-{data_type}-{time_resolution}".
+    ///
+    pub(crate) fn data_id(&self) -> String {
+        format!(
+            "{}-{}-{}-{}",
+            self.exchange_id, self.symbol_id, self.data_type, self.time_resolution
+        )
+    }
+
+    /// Returns true if the subscription is for the given exchange.
+    pub(crate) fn is_on_exchange(&self, exchange_id: ExchangeID) -> bool {
+        self.exchange_id == exchange_id
+    }
+
+    /// Returns true if the subscription is for the given symbol and data type,
+    /// regardless of its time resolution.
+    pub(crate) fn is_for(
+        &self,
+        exchange_id: ExchangeID,
+        symbol_id: u16,
+        data_type: DataType,
+    ) -> bool {
+        self.exchange_id == exchange_id
+            && self.symbol_id == symbol_id
+            && self.data_type == data_type
+    }
+}
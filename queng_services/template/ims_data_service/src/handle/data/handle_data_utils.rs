@@ -0,0 +1,196 @@
+use crate::handle::{ClientChannels, DataSubscription};
+use crate::handle::{Guarded, MessageHandler};
+use crate::session::SessionRegistry;
+use common_errors::MessageProcessingError;
+use data_integration_traits::DataIntegrationFactory;
+use message_bus::MessageBus;
+use sbe_messages::DataErrorType;
+use std::collections::hash_map::Entry;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// Number of data messages buffered between the data integration and the client channels.
+const DATA_CHANNEL_CAPACITY: usize = 1024;
+
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
+    /// Starts the data of a subscription in the data integration, unless another client
+    /// already receives it.
+    ///
+    /// The data is forwarded to the data channel of every client subscribed to it.
+    /// The forwarder is registered before the data is started, so no data is missed,
+    /// and removed again if the data cannot be started.
+    ///
+    /// No lock is held while the data integration starts the data, so forwarders and
+    /// other requests are not blocked by a slow integration. Control messages are handled
+    /// one at a time, so the start and stop of the same data cannot interleave.
+    ///
+    /// # Parameters
+    ///
+    /// * `subscription` - The subscription to start
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataErrorType::DataUnavailableError` if the data integration fails to start the data.
+    ///
+    pub(crate) async fn start_subscription(
+        &self,
+        subscription: DataSubscription,
+    ) -> Result<(), DataErrorType> {
+        let (sender, receiver) = mpsc::channel(DATA_CHANNEL_CAPACITY);
+
+        match self.data_forwarders().write().await.entry(subscription) {
+            Entry::Occupied(_) => return Ok(()),
+            Entry::Vacant(entry) => {
+                entry.insert(tokio::spawn(forward_data(
+                    subscription,
+                    receiver,
+                    self.sessions().clone(),
+                    self.bus().clone(),
+                )));
+            }
+        }
+
+        let data_id = subscription.data_id();
+        if let Err(e) = self.integration().start_date(&data_id, sender).await {
+            println!("[IMSData/start_subscription]: Failed to start data {data_id}: {e}");
+            if let Some(forwarder) = self.data_forwarders().write().await.remove(&subscription) {
+                forwarder.abort();
+            }
+            return Err(DataErrorType::DataUnavailableError);
+        }
+
+        Ok(())
+    }
+
+    /// Removes the subscriptions of a client that match the filter.
+    ///
+    /// The data of each removed subscription keeps running until it is released.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client
+    /// * `filter` - Returns true for each subscription to remove
+    ///
+    /// # Returns
+    ///
+    /// The removed subscriptions.
+    ///
+    pub(crate) async fn remove_subscriptions(
+        &self,
+        client_id: u16,
+        filter: impl Fn(&DataSubscription) -> bool,
+    ) -> Vec<DataSubscription> {
+        let mut sessions = self.sessions().write().await;
+
+        let Some(session) = sessions.get_mut(client_id) else {
+            return Vec::new();
+        };
+        let subscriptions = session.subscriptions_mut();
+
+        let removed: Vec<DataSubscription> = subscriptions
+            .iter()
+            .filter(|s| filter(s))
+            .copied()
+            .collect();
+        for subscription in &removed {
+            subscriptions.remove(subscription);
+        }
+
+        removed
+    }
+
+    /// Stops the data of removed subscriptions no other client is subscribed to.
+    ///
+    /// If no client is subscribed to any data anymore, all data of the
+    /// data integration is stopped at once.
+    ///
+    /// The forwarders of the released data are aborted under the lock; the data
+    /// integration is called after the lock is released.
+    ///
+    /// # Parameters
+    ///
+    /// * `removed` - The subscriptions removed from a client
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MessageProcessingError` if the data integration fails to stop the data.
+    ///
+    pub(crate) async fn release_subscriptions(
+        &self,
+        removed: &[DataSubscription],
+    ) -> Result<(), MessageProcessingError> {
+        if removed.is_empty() {
+            return Ok(());
+        }
+
+        let released = {
+            let sessions = self.sessions().read().await;
+            let mut data_forwarders = self.data_forwarders().write().await;
+
+            if sessions.has_subscriptions() {
+                let released: Vec<DataSubscription> = removed
+                    .iter()
+                    .filter(|subscription| !sessions.is_subscribed(subscription))
+                    .copied()
+                    .collect();
+                for subscription in &released {
+                    if let Some(forwarder) = data_forwarders.remove(subscription) {
+                        forwarder.abort();
+                    }
+                }
+                Some(released)
+            } else {
+                for (_, forwarder) in data_forwarders.drain() {
+                    forwarder.abort();
+                }
+                None
+            }
+        };
+
+        let Some(released) = released else {
+            return self.integration().stop_all_date().await.map_err(|e| {
+                MessageProcessingError(format!(
+                    "[release_subscriptions]: Failed to stop all data: {e}"
+                ))
+            });
+        };
+
+        for subscription in released {
+            let data_id = subscription.data_id();
+            self.integration().stop_date(&data_id).await.map_err(|e| {
+                MessageProcessingError(format!(
+                    "[release_subscriptions]: Failed to stop data {data_id}: {e}"
+                ))
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Sends each data message of a subscription to the data channel of every subscribed client.
+///
+/// The channels of the subscribers are copied out of the session registry, so the registry
+/// is not locked while sending. Runs until the data integration drops the sender or the
+/// forwarder is aborted.
+async fn forward_data<B: MessageBus>(
+    subscription: DataSubscription,
+    mut receiver: mpsc::Receiver<Vec<u8>>,
+    sessions: Guarded<SessionRegistry>,
+    bus: Arc<B>,
+) {
+    while let Some(bytes) = receiver.recv().await {
+        let subscribers: Vec<(u16, ClientChannels)> = sessions
+            .read()
+            .await
+            .subscribers(&subscription)
+            .map(|(client_id, session)| (client_id, session.channels().clone()))
+            .collect();
+
+        for (client_id, channels) in subscribers {
+            if let Err(e) = channels.send_data(bus.as_ref(), bytes.clone()).await {
+                println!("[IMSData/forward_data]: Failed to send data to client {client_id}: {e}");
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::handle::DataSubscription;
+use crate::handle::MessageHandler;
+use common_errors::MessageProcessingError;
+use message_bus::MessageBus;
+use sbe_messages::{ClientErrorType, StartDataMessage};
+
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
+    /// Handles a start data message by subscribing the client to the requested data.
+    ///
+    /// Checks that the client is logged in, validates the requested data, records the subscription,
+    /// and starts the data in the data integration if no other client receives it yet.
+    /// The subscription is removed again if the data cannot be started.
+    /// Sends back any errors over the error channel.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_data_msg`: The incoming StartDataMessage from the client
+    ///
+    /// # Returns
+    ///
+    /// Result with no value if successful, or a MessageProcessingError if an error occurs.
+    ///
+    /// # Errors
+    ///
+    /// - MessageProcessingError if an error cannot be sent back to the client.
+    ///
+    pub(crate) async fn handle_start_data(
+        &self,
+        start_data_msg: &StartDataMessage,
+    ) -> Result<(), MessageProcessingError> {
+        let client_id = start_data_msg.client_id();
+
+        if !self.check_client_login(client_id).await? {
+            return self
+                .send_client_error(client_id, ClientErrorType::ClientNotLoggedIn)
+                .await;
+        }
+
+        let subscription = match DataSubscription::from_start_data(start_data_msg) {
+            Ok(subscription) => subscription,
+            Err(data_error_type) => return self.send_data_error(client_id, data_error_type).await,
+        };
+
+        if let Some(session) = self.sessions().write().await.get_mut(client_id) {
+            session.subscriptions_mut().insert(subscription);
+        }
+
+        if let Err(data_error_type) = self.start_subscription(subscription).await {
+            self.remove_subscriptions(client_id, |s| *s == subscription)
+                .await;
+            return self.send_data_error(client_id, data_error_type).await;
+        }
+
+        Ok(())
+    }
+}
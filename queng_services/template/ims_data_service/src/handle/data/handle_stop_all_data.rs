@@ -0,0 +1,52 @@
+use crate::handle::MessageHandler;
+use common_errors::MessageProcessingError;
+use message_bus::MessageBus;
+use sbe_messages::{ClientErrorType, DataErrorType, StopAllDataMessage};
+
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
+    /// Handles a stop all data message by unsubscribing the client from all data of an exchange.
+    ///
+    /// Stops the data in the data integration that no other client receives.
+    /// Sends back any errors over the error channel.
+    ///
+    /// # Parameters
+    ///
+    /// - `stop_all_data_msg`: The incoming StopAllDataMessage from the client
+    ///
+    /// # Returns
+    ///
+    /// Result with no value if successful, or a MessageProcessingError if an error occurs.
+    ///
+    /// # Errors
+    ///
+    /// - MessageProcessingError if an error cannot be sent back to the client.
+    ///
+    pub(crate) async fn handle_stop_all_data(
+        &self,
+        stop_all_data_msg: &StopAllDataMessage,
+    ) -> Result<(), MessageProcessingError> {
+        let client_id = stop_all_data_msg.client_id();
+
+        if !self.check_client_login(client_id).await? {
+            return self
+                .send_client_error(client_id, ClientErrorType::ClientNotLoggedIn)
+                .await;
+        }
+
+        let exchange_id = *stop_all_data_msg.exchange_id();
+        let removed = self
+            .remove_subscriptions(client_id, |subscription| {
+                subscription.is_on_exchange(exchange_id)
+            })
+            .await;
+
+        if let Err(e) = self.release_subscriptions(&removed).await {
+            println!("[QDGW/handle_stop_all_data] UnknownDataError: {e}");
+            return self
+                .send_data_error(client_id, DataErrorType::UnknownDataError)
+                .await;
+        }
+
+        Ok(())
+    }
+}
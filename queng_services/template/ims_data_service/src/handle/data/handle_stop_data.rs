@@ -0,0 +1,63 @@
+use crate::handle::MessageHandler;
+use common_errors::MessageProcessingError;
+use message_bus::MessageBus;
+use sbe_messages::{ClientErrorType, DataErrorType, StopDataMessage};
+
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
+    /// Handles a stop data message by unsubscribing the client from the requested data.
+    ///
+    /// Removes all subscriptions of the client to the symbol and data type, regardless of
+    /// their time resolution, and stops the data in the data integration if no other client
+    /// receives it. Sends back any errors over the error channel.
+    ///
+    /// # Parameters
+    ///
+    /// - `stop_data_msg`: The incoming StopDataMessage from the client
+    ///
+    /// # Returns
+    ///
+    /// Result with no value if successful, or a MessageProcessingError if an error occurs.
+    ///
+    /// # Errors
+    ///
+    /// - MessageProcessingError if an error cannot be sent back to the client.
+    ///
+    pub(crate) async fn handle_stop_data(
+        &self,
+        stop_data_msg: &StopDataMessage,
+    ) -> Result<(), MessageProcessingError> {
+        let client_id = stop_data_msg.client_id();
+
+        if !self.check_client_login(client_id).await? {
+            return self
+                .send_client_error(client_id, ClientErrorType::ClientNotLoggedIn)
+                .await;
+        }
+
+        let exchange_id = *stop_data_msg.exchange_id();
+        let symbol_id = stop_data_msg.symbol_id();
+        let data_type = *stop_data_msg.data_type_id();
+
+        let removed = self
+            .remove_subscriptions(client_id, |subscription| {
+                subscription.is_for(exchange_id, symbol_id, data_type)
+            })
+            .await;
+
+        // The client did not receive the data it wants to stop.
+        if removed.is_empty() {
+            return self
+                .send_data_error(client_id, DataErrorType::DataUnavailableError)
+                .await;
+        }
+
+        if let Err(e) = self.release_subscriptions(&removed).await {
+            println!("[QDGW/handle_stop_data] UnknownDataError: {e}");
+            return self
+                .send_data_error(client_id, DataErrorType::UnknownDataError)
+                .await;
+        }
+
+        Ok(())
+    }
+}
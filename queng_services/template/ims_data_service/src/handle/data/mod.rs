@@ -0,0 +1,7 @@
+mod data_subscription;
+mod handle_data_utils;
+mod handle_start_data;
+mod handle_stop_all_data;
+mod handle_stop_data;
+
+pub(crate) use data_subscription::DataSubscription;
@@ -1,57 +1,109 @@
-use crate::service::Server;
+use crate::handle::MessageHandler;
 use common_errors::MessageProcessingError;
-use iggy::models::messages::PolledMessage;
-use sbe_messages::{ClientLoginMessage, ClientLogoutMessage, MessageType};
+use message_bus::MessageBus;
+use sbe_messages::{ClientErrorType, DataErrorType, MessageType, SbeDecodeError, SbeMessage};
 
-impl Server {
-    /// Handles a single message by processing it and sending it to the appropriate
-    /// manager for further processing.
-    ///
-    /// This method takes a message payload, processes it by calling the `process_message`
-    /// method, and sends it to the appropriate handler for further processing.
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
+    /// Handles a single control message by dispatching it to the handler of its type.
     ///
     /// # Parameters
     ///
-    /// * `self` - The Server instance
-    /// * `message` - The message payload to be processed
+    /// * `self` - The MessageHandler instance
+    /// * `sbe_message` - The decoded control message
     ///
     /// # Returns
     /// * Ok on success,
     /// * Err on any processing error
     ///
-    pub(crate) async fn handle_message(
+    pub async fn handle_message(
         &self,
-        polled_message: PolledMessage,
+        sbe_message: &SbeMessage,
     ) -> Result<(), MessageProcessingError> {
-        //
-        let message = polled_message.payload.to_vec();
-        let raw_message = message.as_slice();
-        let message_type = MessageType::from(u16::from(raw_message[2]));
-
-        match message_type {
-            MessageType::ClientLogin => {
-                let client_login_msg = ClientLoginMessage::from(raw_message);
-                self.handle_client_login(&client_login_msg).await
+        match sbe_message {
+            SbeMessage::ClientLogin(client_login_msg) => {
+                self.handle_client_login(client_login_msg).await
             }
-            MessageType::ClientLogout => {
-                let client_logout_msg = ClientLogoutMessage::from(raw_message);
-                self.handle_client_logout(&client_logout_msg).await
+            SbeMessage::ClientLogout(client_logout_msg) => {
+                self.handle_client_logout(client_logout_msg).await
             }
-            MessageType::StartData => {
-                todo!()
+            SbeMessage::Heartbeat(heartbeat_msg) => {
+                self.handle_client_heartbeat(heartbeat_msg).await
             }
+            SbeMessage::StartData(start_data_msg) => self.handle_start_data(start_data_msg).await,
 
-            MessageType::StopData => {
-                todo!()
-            }
+            SbeMessage::StopData(stop_data_msg) => self.handle_stop_data(stop_data_msg).await,
 
-            MessageType::StopAllData => {
-                todo!()
+            SbeMessage::StopAllData(stop_all_data_msg) => {
+                self.handle_stop_all_data(stop_all_data_msg).await
             }
 
-            _ => Err(MessageProcessingError(
-                "[handle::handle_message]: Unknown message type. Abort processing".to_string(),
-            )),
+            _ => Err(MessageProcessingError(format!(
+                "[handle::handle_message]: Unexpected message type {}. Abort processing",
+                sbe_message.message_type()
+            ))),
+        }
+    }
+
+    /// Handles a message that failed to decode.
+    ///
+    /// Replies with a `ClientError` for client messages or a `DataError` for data messages
+    /// if the client id can be read from the raw message, and returns the decode error
+    /// as `MessageProcessingError`.
+    ///
+    /// # Parameters
+    ///
+    /// * `raw_message` - The raw message that failed to decode
+    /// * `err` - The `SbeDecodeError` returned by the decoder
+    ///
+    pub(crate) async fn handle_decode_error(
+        &self,
+        raw_message: &[u8],
+        err: SbeDecodeError,
+    ) -> Result<(), MessageProcessingError> {
+        if let Some(client_id) = peek_client_id(raw_message) {
+            match peek_message_type(raw_message) {
+                MessageType::ClientLogin | MessageType::ClientLogout | MessageType::Heartbeat => {
+                    let client_error_type = ClientErrorType::UnknownClientError;
+                    if let Err(e) = self.send_client_error(client_id, client_error_type).await {
+                        println!("[QDGW/handle_message] UnknownClientError: {e:?}");
+                    }
+                }
+                MessageType::StartData | MessageType::StopData | MessageType::StopAllData => {
+                    let data_error_type = DataErrorType::DataTypeNotKnownError;
+                    if let Err(e) = self.send_data_error(client_id, data_error_type).await {
+                        println!("[QDGW/handle_message] DataTypeNotKnownError: {e:?}");
+                    }
+                }
+                _ => {}
+            }
         }
+
+        Err(MessageProcessingError(format!(
+            "[handle::handle_message]: Failed to decode message: {err}"
+        )))
     }
 }
+
+/// Reads the message type from the template id of the SBE message header.
+///
+/// The template id is stored as little-endian u16 at offset 2 of the header
+/// and equals the `MessageType` of the message.
+fn peek_message_type(raw_message: &[u8]) -> MessageType {
+    raw_message
+        .get(2..4)
+        .map_or(MessageType::UnknownMessageType, |bytes| {
+            MessageType::from(u16::from_le_bytes([bytes[0], bytes[1]]))
+        })
+}
+
+/// Reads the client id of a control message without decoding the full message.
+///
+/// All control messages carry the client id as little-endian u16 directly after
+/// the 8 byte message header and the 2 byte message type.
+///
+/// Returns `None` if the message is too short to hold a client id.
+fn peek_client_id(raw_message: &[u8]) -> Option<u16> {
+    raw_message
+        .get(10..12)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+}
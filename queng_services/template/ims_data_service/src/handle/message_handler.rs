@@ -0,0 +1,109 @@
+use crate::handle::DataSubscription;
+use crate::session::SessionRegistry;
+use common_iggy::IggyConfig;
+use common_ims::IntegrationConfig;
+use data_integration_factory::DataIntegration;
+use message_bus::MessageBus;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+
+pub(crate) type Guarded<T> = Arc<tokio::sync::RwLock<T>>;
+
+/// Handles the control messages of an integration over a message bus.
+///
+/// Holds the sessions of the logged in clients and the data they are subscribed to.
+/// Client streams, channels and users are created and deleted through the message bus,
+/// and all replies to clients are sent through it.
+pub struct MessageHandler<B: MessageBus + Sync + 'static> {
+    dbg: bool,
+    bus: Arc<B>,
+    iggy_config: IggyConfig,
+    integration_config: IntegrationConfig,
+    sessions: Guarded<SessionRegistry>,
+    integration: DataIntegration,
+    data_forwarders: Guarded<HashMap<DataSubscription, JoinHandle<()>>>,
+}
+
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
+    /// Creates a new message handler without any logged in clients.
+    ///
+    /// The streams and topics of the integration must already exist on the message bus.
+    ///
+    /// # Arguments
+    ///
+    /// * `dbg` - Print debug messages if true
+    /// * `bus` - The message bus to send replies through
+    /// * `integration_config` - Configuration for integration endpoints and channels
+    /// * `iggy_config` - Configuration of the client streams and users
+    /// * `integration` - The data integration serving the data requests of clients
+    ///
+    #[must_use]
+    pub fn new(
+        dbg: bool,
+        bus: B,
+        integration_config: IntegrationConfig,
+        iggy_config: IggyConfig,
+        integration: DataIntegration,
+    ) -> Self {
+        Self {
+            dbg,
+            bus: Arc::new(bus),
+            iggy_config,
+            integration_config,
+            sessions: Arc::new(tokio::sync::RwLock::new(SessionRegistry::default())),
+            integration,
+            data_forwarders: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+// Getters
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
+    pub fn dbg(&self) -> bool {
+        self.dbg
+    }
+
+    pub fn bus(&self) -> &Arc<B> {
+        &self.bus
+    }
+
+    pub fn iggy_config(&self) -> &IggyConfig {
+        &self.iggy_config
+    }
+
+    pub fn integration_config(&self) -> &IntegrationConfig {
+        &self.integration_config
+    }
+
+    pub(crate) fn sessions(&self) -> &Guarded<SessionRegistry> {
+        &self.sessions
+    }
+
+    pub fn integration(&self) -> &DataIntegration {
+        &self.integration
+    }
+
+    pub(crate) fn data_forwarders(&self) -> &Guarded<HashMap<DataSubscription, JoinHandle<()>>> {
+        &self.data_forwarders
+    }
+}
+
+// Setters
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
+    pub(crate) fn set_online(&mut self) {
+        self.integration_config.set_online();
+    }
+
+    pub(crate) fn set_offline(&mut self) {
+        self.integration_config.set_offline();
+    }
+}
+
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
+    pub(crate) fn dbg_print(&self, msg: &str) {
+        if self.dbg {
+            println!("[IMSData/MessageHandler]: {msg}");
+        }
+    }
+}
@@ -1,2 +1,9 @@
 mod client;
+mod data;
 mod handle_messages;
+mod message_handler;
+
+pub(crate) use client::ClientChannels;
+pub(crate) use data::DataSubscription;
+pub(crate) use message_handler::Guarded;
+pub use message_handler::MessageHandler;
@@ -2,19 +2,25 @@ use crate::service::Service;
 use common_iggy::IggyConfig;
 use common_ims::IntegrationConfig;
 use common_service::{print_utils, shutdown_utils};
+use data_integration_factory::DataIntegration;
 use tokio::time::Instant;
 
-// mod handle;
+mod handle;
+mod reap;
 mod run;
 mod service;
+mod session;
 mod shutdown;
-// mod utils;
+mod utils;
+
+pub use handle::MessageHandler;
 
 pub async fn start(
     dbg: bool,
     service_name: &str,
     integration_config: IntegrationConfig,
     iggy_config: IggyConfig,
+    integration: DataIntegration,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let dbg_print = |msg: &str| {
         if dbg {
@@ -27,36 +33,33 @@ pub async fn start(
     dbg_print("Configuring server");
     //Creates a new server
     let server = if dbg {
-        Service::with_debug(integration_config, iggy_config)
-            .await
-            .expect("Failed to build new service")
+        Service::with_debug(integration_config, iggy_config, integration).await
     } else {
-        Service::new(integration_config, iggy_config)
-            .await
-            .expect("Failed to build new service")
-    };
+        Service::new(integration_config, iggy_config, integration).await
+    }
+    .map_err(|e| {
+        println!("[{service_name}]/main: Failed to build new service: {e}");
+        e
+    })?;
 
     dbg_print("Run service");
     let signal = shutdown_utils::signal_handler("message server signal handler");
     let service_handle = tokio::spawn(server.run(signal));
 
-    dbg_print("Set integration online");
-    //
-
     // Print service start header
     print_utils::print_duration("Starting service took:", &start.elapsed());
     print_utils::print_start_header_message_service(service_name, &stream_id);
 
     //Start server.
     match tokio::try_join!(service_handle) {
-        Ok(_) => {}
+        Ok((Ok(()),)) => {}
+        Ok((Err(e),)) => {
+            println!("[{service_name}]/main: Message service failed: {e}");
+        }
         Err(e) => {
             println!("[{service_name}]/main: Failed to start Message service: {e:?}");
         }
     }
-    //
-    dbg_print("Set integration offline");
-    //
 
     Ok(())
 }
@@ -0,0 +1,27 @@
+use crate::handle::MessageHandler;
+use message_bus::MessageBus;
+
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
+    /// Logs out every client from which no heartbeat was received within the heartbeat timeout.
+    ///
+    /// Logging out stops the data the client is subscribed to and deletes its channels.
+    /// A client that was logged out gets a ClientNotLoggedIn error on its next heartbeat
+    /// and may log in again.
+    ///
+    /// Called by the service whenever a heartbeat interval has passed.
+    ///
+    pub async fn reap_idle_sessions(&self) {
+        let timeout = self.integration_config().heartbeat_timeout();
+        let idle_clients = self.sessions().read().await.idle_clients(timeout);
+
+        for client_id in idle_clients {
+            println!(
+                "[IMSData/reap_idle_sessions]: No heartbeat of client {client_id} within {timeout:?}, logging out"
+            );
+
+            if let Err(e) = self.client_logout(client_id).await {
+                println!("[IMSData/reap_idle_sessions]: {e}");
+            }
+        }
+    }
+}
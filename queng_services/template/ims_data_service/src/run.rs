@@ -1,41 +1,108 @@
 use crate::service::Service;
 use common_errors::MessageProcessingError;
+use futures_util::StreamExt;
+use message_consumer::{ConsumeError, Delivery};
 use std::future::Future;
+use tokio::time::MissedTickBehavior;
 use tokio::{pin, select};
 
 impl Service {
+    /// Marks the integration online and consumes the control channel until the signal
+    /// completes, then shuts down the service.
+    ///
+    /// Each control message is dispatched to its handler and acknowledged afterwards,
+    /// whether or not it was handled successfully. A message that fails to decode or to be
+    /// handled is replied to on the error channel where possible and moved to the dead letter
+    /// channel, so a single bad message neither stops the service, nor is redelivered forever,
+    /// nor is lost.
+    ///
+    /// Every heartbeat interval, clients that missed too many heartbeats are logged out.
+    ///
+    /// On shutdown, the connected clients are drained within the shutdown deadline
+    /// of the integration config.
+    ///
+    /// # Arguments
+    ///
+    /// * `signal` - A future that completes when the service should stop.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MessageProcessingError` if the service fails to shut down.
+    ///
     pub async fn run(
-        self,
+        mut self,
         signal: impl Future<Output = ()> + Send + 'static,
     ) -> Result<(), MessageProcessingError> {
         // When call .await on a &mut _ reference, then pin the future. https://docs.rs/tokio/latest/tokio/macro.pin.html#examples
         let signal_future = signal;
         pin!(signal_future);
 
+        self.dbg_print("Set integration online");
+        self.set_online();
+
+        let mut consumer = self.consumer().lock().await;
+        let committer = consumer.committer();
+        let mut messages = consumer.sbe_stream();
+
+        let mut reaper = tokio::time::interval(self.integration_config().heartbeat_interval());
+        reaper.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
         loop {
             select! {
-                    _ = &mut signal_future => {break;}
-                //
-                // polled_messages = self.consumer().pi(self.poll_command()) => {
-                //     match polled_messages {
-                //         Ok(polled_messages) => {
-                //             for polled_message in polled_messages.messages {
-                //                 self.handle_message(polled_message.payload.as_ref())
-                //                    .await.expect("Failed to process message");
-                //             }
-                //         },
-                //         Err(e) => {
-                //             println!("[QDGW/run]: Error polling messages from iggy message bus: {}", e);
-                //             break;
-                //         }
-                //     }
-                // } // end match polled messages
+                _ = &mut signal_future => {break;}
+
+                _ = reaper.tick() => self.handler().reap_idle_sessions().await,
+
+                next = messages.next() => {
+                    let Some(next) = next else {
+                        self.dbg_print("Control channel closed");
+                        break;
+                    };
+
+                    let (partition_id, offset) = match next {
+                        Ok(message) => {
+                            let delivery = self
+                                .dead_letter_queue()
+                                .handle(&message, |_| self.handler().handle_message(message.message()))
+                                .await;
+                            match delivery {
+                                Ok(Delivery::Handled) => {}
+                                Ok(Delivery::DeadLettered) => println!(
+                                    "[IMSData/run]: Dead-lettered message at offset {} of partition {}",
+                                    message.offset(),
+                                    message.partition_id()
+                                ),
+                                Err(e) => println!("[IMSData/run]: {e}"),
+                            }
+                            (message.partition_id(), message.offset())
+                        }
+                        Err(ConsumeError::Decode { partition_id, offset, timestamp, payload, error }) => {
+                            if let Err(e) = self.handler().handle_decode_error(&payload, error.clone()).await {
+                                println!("[IMSData/run]: {e}");
+                            }
+                            let decode_error = ConsumeError::Decode { partition_id, offset, timestamp, payload, error };
+                            if let Err(e) = self.dead_letter_queue().dead_letter_decode_error(decode_error).await {
+                                println!("[IMSData/run]: {e}");
+                            }
+                            (partition_id, offset)
+                        }
+                        // The connection supervisor reconnects; polling resumes afterwards.
+                        Err(e) => {
+                            println!("[IMSData/run]: {e}");
+                            continue;
+                        }
+                    };
+
+                    if let Err(e) = committer.commit_offset(partition_id, offset).await {
+                        println!("[IMSData/run]: {e}");
+                    }
+                }
             } // end select
         } // end loop
 
-        self.shutdown().await.expect("Failed to shutdown iggy");
+        drop(messages);
+        drop(consumer);
 
-        Ok(())
+        self.shutdown().await
     }
 }
-
@@ -1,21 +1,35 @@
+use crate::handle::MessageHandler;
 use common_iggy::IggyConfig;
 use common_ims::IntegrationConfig;
+use common_message::StreamUser;
+use data_integration_factory::DataIntegration;
 use iggy::client::{Client, UserClient};
-use iggy::clients::client::IggyClient;
-use std::collections::HashMap;
+use message_bus::IggyMessageBus;
+use message_consumer::{CommitPolicy, DeadLetterQueue, MessageConsumer};
+use message_shared::Args;
 use std::error::Error;
+use tokio::sync::Mutex;
 
-type Guarded<T> = std::sync::Arc<tokio::sync::RwLock<T>>;
+/// Number of times a control message is handled before it is dead-lettered.
+///
+/// Handlers reply to the client and start or stop data, so a failed message
+/// is not retried but moved to the dead letter channel right away.
+const DEAD_LETTER_MAX_ATTEMPTS: u32 = 1;
 
 /// A server that handles IMS (Integration Management Service) data processing.
+///
+/// The service consumes control messages from the `{integration_id}-control` topic
+/// and passes them to a `MessageHandler` on an iggy message bus.
+/// Each logged in client gets its own stream with a data and an error channel, readable with
+/// the secret the client sent in its login; the login reply is sent on the client's error channel.
+/// Requests of clients that are not logged in are replied to on the `{integration_id}-error` topic.
+/// Clients must be able to write the control topic but not to read it, as logins carry secrets.
+/// Control messages that fail to decode or to be handled are moved to the `{integration_id}-dlq` topic.
 pub struct Service {
     dbg: bool,
-    consumer_client: IggyClient,
-    producer_client: IggyClient,
-    iggy_config: IggyConfig,
-    integration_config: IntegrationConfig,
-    client_configs: Guarded<HashMap<u16, IggyConfig>>,
-    client_producers: Guarded<HashMap<u16, IggyClient>>,
+    consumer: Mutex<MessageConsumer>,
+    dead_letter_queue: DeadLetterQueue,
+    handler: MessageHandler<IggyMessageBus>,
 }
 
 impl Service {
@@ -25,6 +39,7 @@ impl Service {
     ///
     /// * `integration_config` - Configuration for integration endpoints and channels
     /// * `iggy_config` -  Configuration for the iggy messaging system
+    /// * `integration` - The data integration serving the data requests of clients
     ///
     /// # Returns
     ///
@@ -34,13 +49,13 @@ impl Service {
     ///
     /// Returns an error if:
     /// * Failed to create the message consumer
-    /// * Failed to create the message producer
     /// * Failed to initialize communication channels
     pub async fn new(
         integration_config: IntegrationConfig,
         iggy_config: IggyConfig,
+        integration: DataIntegration,
     ) -> Result<Self, Box<dyn Error>> {
-        Self::build(false, integration_config, iggy_config).await
+        Self::build(false, integration_config, iggy_config, integration).await
     }
 
     /// Creates a new IMS data service server with debug mode enabled.
@@ -49,6 +64,7 @@ impl Service {
     ///
     /// * `integration_config` - Configuration for integration endpoints and channels
     /// * `iggy_config` -  Configuration for the iggy messaging system
+    /// * `integration` - The data integration serving the data requests of clients
     ///
     /// # Returns
     ///
@@ -58,13 +74,13 @@ impl Service {
     ///
     /// Returns an error if:
     /// * Failed to create the message consumer
-    /// * Failed to create the message producer
     /// * Failed to initialize communication channels
     pub async fn with_debug(
         integration_config: IntegrationConfig,
         iggy_config: IggyConfig,
+        integration: DataIntegration,
     ) -> Result<Self, Box<dyn Error>> {
-        Self::build(true, integration_config, iggy_config).await
+        Self::build(true, integration_config, iggy_config, integration).await
     }
 }
 
@@ -73,90 +89,126 @@ impl Service {
         dbg: bool,
         integration_config: IntegrationConfig,
         iggy_config: IggyConfig,
+        integration: DataIntegration,
     ) -> Result<Self, Box<dyn Error>> {
-        dbg!("Configure iggy producer");
-
-        let stream_id = integration_config.control_channel();
-        let topic_id = integration_config.control_channel();
-
-        dbg!("Construct iggy producer");
-        let producer = message_shared::utils::build_client(stream_id.clone(), topic_id.clone())
-            .await
-            .expect("Failed to build client");
-
-        dbg!("Connecting producer");
-        producer.connect().await.expect("Failed to connect");
-
-        dbg!("Login producer");
-        producer
-            .login_user(&iggy_config.user().username(), &iggy_config.user().password())
-            .await
-            .expect("Failed to login user");
-
-        dbg!("Construct iggy consumer");
-        let consumer = message_shared::utils::build_client(stream_id.clone(), topic_id.clone())
-            .await
-            .expect("Failed to build client");
-
-        dbg!("Connecting consumer");
-        consumer.connect().await.expect("Failed to connect");
-
-        dbg!("Login consumer");
-        consumer
-            .login_user(&iggy_config.user().username(), &iggy_config.user().password())
-            .await
-            .expect("Failed to login user");
+        let stream_id = integration_config.integration_id().to_string();
+        let user = iggy_config.user();
+        let stream_user = StreamUser::new(user.username(), user.password());
+
+        dbg_print(dbg, "Construct iggy admin client");
+        let client_args = service_args(
+            &iggy_config,
+            stream_id.clone(),
+            integration_config.control_channel(),
+        );
+        let client = message_shared::utils::build_client_from_args(&client_args).await?;
+
+        dbg_print(dbg, "Connecting admin client");
+        client.connect().await?;
+
+        dbg_print(dbg, "Login admin client");
+        client.login_user(user.username(), user.password()).await?;
+
+        dbg_print(dbg, "Provisioning integration channels");
+        let report = common_iggy::provision(&client, &integration_config, &iggy_config).await?;
+        for drift in report.drift() {
+            println!("[IMSData/Service]: {drift}");
+        }
 
-        let client_configs = std::sync::Arc::new(tokio::sync::RwLock::new(HashMap::new()));
-        let client_producers = std::sync::Arc::new(tokio::sync::RwLock::new(HashMap::new()));
+        dbg_print(dbg, "Construct control channel consumer");
+        let consumer_args = service_args(
+            &iggy_config,
+            stream_id.clone(),
+            integration_config.control_channel(),
+        );
+        let consumer_name = format!("{}-service", integration_config.integration_id());
+        let consumer = MessageConsumer::with_args(
+            consumer_args,
+            &consumer_name,
+            &stream_user,
+            CommitPolicy::Manual,
+        )
+        .await?;
+
+        dbg_print(dbg, "Construct control channel dead letter queue");
+        let dead_letter_args = service_args(
+            &iggy_config,
+            stream_id.clone(),
+            integration_config.control_channel(),
+        );
+        let dead_letter_queue = DeadLetterQueue::build(
+            &dead_letter_args,
+            &integration_config.dead_letter_channel(),
+            DEAD_LETTER_MAX_ATTEMPTS,
+        )
+        .await?;
+
+        let bus = IggyMessageBus::new(client);
+        let handler = MessageHandler::new(dbg, bus, integration_config, iggy_config, integration);
 
         Ok(Self {
             dbg,
-            consumer_client: consumer,
-            producer_client: producer,
-            iggy_config,
-            integration_config,
-            client_configs,
-            client_producers,
+            consumer: Mutex::new(consumer),
+            dead_letter_queue,
+            handler,
         })
     }
 }
 
+/// Returns the `Args` of a service client or consumer on the given stream and topic.
+pub(crate) fn service_args(iggy_config: &IggyConfig, stream_id: String, topic_id: String) -> Args {
+    Args {
+        username: iggy_config.user().username().to_string(),
+        password: iggy_config.user().password().to_string(),
+        partition_id: iggy_config.partition_id(),
+        messages_per_batch: iggy_config.messages_per_batch(),
+        ..Args::with_server(stream_id, topic_id, iggy_config.tcp_server_addr())
+    }
+}
+
 // Getters
 impl Service {
     pub fn dbg(&self) -> bool {
         self.dbg
     }
 
-    pub fn consumer(&self) -> &IggyClient {
-        &self.consumer_client
+    pub fn consumer(&self) -> &Mutex<MessageConsumer> {
+        &self.consumer
     }
 
-    pub fn producer(&self) -> &IggyClient {
-        &self.producer_client
+    pub fn dead_letter_queue(&self) -> &DeadLetterQueue {
+        &self.dead_letter_queue
     }
 
-    pub fn iggy_config(&self) -> &IggyConfig {
-        &self.iggy_config
+    pub fn handler(&self) -> &MessageHandler<IggyMessageBus> {
+        &self.handler
     }
 
     pub fn integration_config(&self) -> &IntegrationConfig {
-        &self.integration_config
+        self.handler.integration_config()
     }
+}
 
-    pub fn client_configs(&self) -> &Guarded<HashMap<u16, IggyConfig>> {
-        &self.client_configs
+// Setters
+impl Service {
+    pub(crate) fn set_online(&mut self) {
+        self.handler.set_online();
     }
 
-    pub fn client_producers(&self) -> &Guarded<HashMap<u16, IggyClient>> {
-        &self.client_producers
+    pub(crate) fn set_offline(&mut self) {
+        self.handler.set_offline();
     }
 }
 
 impl Service {
     pub(crate) fn dbg_print(&self, msg: &str) {
-        if self.dbg {
-            println!("[IMSData/Service]: {msg}");
-        }
+        dbg_print(self.dbg(), msg);
+    }
+}
+
+/// Prints a debug message if debug mode is enabled, also before the `Service` exists.
+fn dbg_print(dbg: bool, msg: &str) {
+    if dbg {
+        println!("[IMSData/Service]: {msg}");
     }
 }
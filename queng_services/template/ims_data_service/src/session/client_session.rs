@@ -0,0 +1,60 @@
+use crate::handle::{ClientChannels, DataSubscription};
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+/// The session of a logged in client.
+///
+/// Records when the client logged in and was last seen, the data it is subscribed to,
+/// and its data and error channels.
+pub(crate) struct ClientSession {
+    login_time: Instant,
+    last_seen: Instant,
+    subscriptions: HashSet<DataSubscription>,
+    channels: ClientChannels,
+}
+
+impl ClientSession {
+    /// Creates the session of a client that just logged in.
+    pub(crate) fn new(channels: ClientChannels) -> Self {
+        let now = Instant::now();
+        Self {
+            login_time: now,
+            last_seen: now,
+            subscriptions: HashSet::new(),
+            channels,
+        }
+    }
+
+    /// Records that a heartbeat of the client was received.
+    pub(crate) fn touch(&mut self) {
+        self.last_seen = Instant::now();
+    }
+
+    /// Returns true if no heartbeat of the client was received within the timeout.
+    pub(crate) fn is_idle(&self, timeout: Duration) -> bool {
+        self.last_seen.elapsed() > timeout
+    }
+
+    /// Consumes the session and returns its channels, i.e. to close them on logout.
+    pub(crate) fn into_channels(self) -> ClientChannels {
+        self.channels
+    }
+}
+
+impl ClientSession {
+    pub(crate) fn login_time(&self) -> Instant {
+        self.login_time
+    }
+
+    pub(crate) fn subscriptions(&self) -> &HashSet<DataSubscription> {
+        &self.subscriptions
+    }
+
+    pub(crate) fn subscriptions_mut(&mut self) -> &mut HashSet<DataSubscription> {
+        &mut self.subscriptions
+    }
+
+    pub(crate) fn channels(&self) -> &ClientChannels {
+        &self.channels
+    }
+}
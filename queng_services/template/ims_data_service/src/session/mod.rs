@@ -0,0 +1,5 @@
+mod client_session;
+mod session_registry;
+
+pub(crate) use client_session::ClientSession;
+pub(crate) use session_registry::SessionRegistry;
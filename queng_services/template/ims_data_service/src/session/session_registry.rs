@@ -0,0 +1,87 @@
+use crate::handle::DataSubscription;
+use crate::session::ClientSession;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// The sessions of all logged in clients, keyed by client id.
+#[derive(Default)]
+pub(crate) struct SessionRegistry {
+    sessions: HashMap<u16, ClientSession>,
+}
+
+impl SessionRegistry {
+    /// Returns true if the client is logged in.
+    pub(crate) fn contains(&self, client_id: u16) -> bool {
+        self.sessions.contains_key(&client_id)
+    }
+
+    pub(crate) fn get(&self, client_id: u16) -> Option<&ClientSession> {
+        self.sessions.get(&client_id)
+    }
+
+    pub(crate) fn get_mut(&mut self, client_id: u16) -> Option<&mut ClientSession> {
+        self.sessions.get_mut(&client_id)
+    }
+
+    pub(crate) fn insert(&mut self, client_id: u16, session: ClientSession) {
+        self.sessions.insert(client_id, session);
+    }
+
+    pub(crate) fn remove(&mut self, client_id: u16) -> Option<ClientSession> {
+        self.sessions.remove(&client_id)
+    }
+
+    /// Records that a heartbeat of the client was received.
+    ///
+    /// Returns false if the client is not logged in.
+    pub(crate) fn touch(&mut self, client_id: u16) -> bool {
+        self.sessions
+            .get_mut(&client_id)
+            .map(ClientSession::touch)
+            .is_some()
+    }
+
+    /// Removes and returns the sessions of all clients.
+    pub(crate) fn drain(&mut self) -> Vec<(u16, ClientSession)> {
+        self.sessions.drain().collect()
+    }
+
+    /// Returns the ids of all logged in clients.
+    pub(crate) fn client_ids(&self) -> Vec<u16> {
+        self.sessions.keys().copied().collect()
+    }
+
+    /// Returns the ids of the clients from which no message was received within the timeout.
+    pub(crate) fn idle_clients(&self, timeout: Duration) -> Vec<u16> {
+        self.sessions
+            .iter()
+            .filter(|(_, session)| session.is_idle(timeout))
+            .map(|(client_id, _)| *client_id)
+            .collect()
+    }
+
+    /// Returns true if any client is subscribed to any data.
+    pub(crate) fn has_subscriptions(&self) -> bool {
+        self.sessions
+            .values()
+            .any(|session| !session.subscriptions().is_empty())
+    }
+
+    /// Returns true if any client is subscribed to the data.
+    pub(crate) fn is_subscribed(&self, subscription: &DataSubscription) -> bool {
+        self.sessions
+            .values()
+            .any(|session| session.subscriptions().contains(subscription))
+    }
+
+    /// Returns the ids and sessions of the clients subscribed to the data.
+    pub(crate) fn subscribers<'a>(
+        &'a self,
+        subscription: &'a DataSubscription,
+    ) -> impl Iterator<Item = (u16, &'a ClientSession)> + 'a {
+        self.sessions
+            .iter()
+            .filter(move |(_, session)| session.subscriptions().contains(subscription))
+            .map(|(client_id, session)| (*client_id, session))
+    }
+}
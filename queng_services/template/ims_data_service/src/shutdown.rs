@@ -1,18 +1,170 @@
+use crate::handle::MessageHandler;
 use crate::service::Service;
 use common_errors::MessageProcessingError;
+use data_integration_traits::DataIntegrationFactory;
 use iggy::client::Client;
+use message_bus::MessageBus;
+use sbe_messages::ClientErrorType;
+use tokio::task::{AbortHandle, JoinHandle};
 
 impl Service {
-    pub(crate) async fn shutdown(&self) -> Result<(), MessageProcessingError> {
+    /// Shuts down the service within the shutdown deadline of the integration config.
+    ///
+    /// If the deadline passes before all clients are drained, the data forwarders
+    /// still running are aborted and the client sessions and the admin client
+    /// are still closed on a best-effort basis.
+    /// Either way, the integration config of the service is set offline; this is
+    /// local state only and is not published.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MessageProcessingError` if the service fails to shut down
+    /// or does not shut down within the deadline.
+    ///
+    pub(crate) async fn shutdown(&mut self) -> Result<(), MessageProcessingError> {
         self.dbg_print("Shutting down");
 
+        // The drain takes the forwarders out of the handler to await them, so their abort
+        // handles are kept here to abort them if the deadline passes while they are awaited.
+        let forwarders = self.handler().data_forwarder_abort_handles().await;
+
+        let deadline = self.integration_config().shutdown_deadline();
+        let result = match tokio::time::timeout(deadline, self.drain()).await {
+            Ok(result) => result,
+            Err(_) => {
+                for forwarder in forwarders {
+                    forwarder.abort();
+                }
+
+                if let Err(e) = self.close().await {
+                    println!("[IMSData/shutdown]: {e}");
+                }
+
+                Err(MessageProcessingError(format!(
+                    "[shutdown]: Failed to shutdown within {deadline:?}"
+                )))
+            }
+        };
+
+        self.dbg_print("Set integration offline");
+        self.set_offline();
+
+        result
+    }
+
+    /// Drains all clients and shuts down the iggy clients of the service.
+    ///
+    /// 1. The control channel consumer is shut down, so no further logins are accepted.
+    /// 2. Every logged in client is sent a ServiceShutdown error as final notice.
+    /// 3. All data of the data integration is stopped.
+    /// 4. The data already received from the data integration is forwarded to the clients.
+    /// 5. All clients are logged out: their sessions are removed and their streams and
+    ///    stream users are deleted, so the secrets of this run cannot be used anymore.
+    /// 6. The admin client, i.e. the connection of the message bus, is shut down.
+    ///
+    /// A failure before the admin client is shut down is logged and does not stop the drain.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MessageProcessingError` if the admin client fails to shut down.
+    ///
+    async fn drain(&self) -> Result<(), MessageProcessingError> {
         self.dbg_print("Shutting down consumer");
-        self.consumer().shutdown().await.expect("Failed to shutdown");
+        if let Err(e) = self.consumer().lock().await.shutdown().await {
+            println!("[IMSData/shutdown]: {}", shutdown_error("consumer", e));
+        }
 
-        // Check if there is any active client left, and if so, logout and shutdown
+        self.handler().drain_clients().await;
 
-        self.dbg_print("Shutting down producer");
-        self.producer().shutdown().await.expect("Failed to shutdown");
-        Ok(())
+        self.close().await
     }
+
+    /// Logs out all clients and shuts down the admin client,
+    /// i.e. steps 5 and 6 of the drain.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MessageProcessingError` if the admin client fails to shut down.
+    ///
+    async fn close(&self) -> Result<(), MessageProcessingError> {
+        self.handler().close_sessions().await;
+
+        self.dbg_print("Shutting down admin client");
+        self.handler()
+            .bus()
+            .client()
+            .shutdown()
+            .await
+            .map_err(|e| shutdown_error("admin client", e))
+    }
+}
+
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
+    /// Notifies all clients of the shutdown, stops all data and forwards the data
+    /// already received, i.e. steps 2 to 4 of the drain of the service.
+    ///
+    /// No further control messages may be handled once the drain started.
+    /// Failures are logged and do not stop the drain.
+    ///
+    pub async fn drain_clients(&self) {
+        self.dbg_print("Notifying clients");
+        let client_ids = self.sessions().read().await.client_ids();
+        for client_id in client_ids {
+            let client_error_type = ClientErrorType::ServiceShutdown;
+            if let Err(e) = self.send_client_error(client_id, client_error_type).await {
+                println!("[IMSData/shutdown]: Failed to notify client {client_id}: {e}");
+            }
+        }
+
+        self.dbg_print("Stopping all data");
+        if let Err(e) = self.integration().stop_all_date().await {
+            println!("[IMSData/shutdown]: Failed to stop all data: {e}");
+        }
+
+        // The data integration dropped its senders, so each forwarder ends
+        // once it has forwarded the data left in its channel.
+        self.dbg_print("Flushing data forwarders");
+        let data_forwarders: Vec<_> = self.data_forwarders().write().await.drain().collect();
+        for (subscription, forwarder) in data_forwarders {
+            if let Err(e) = forwarder.await {
+                println!(
+                    "[IMSData/shutdown]: Failed to flush data {}: {e}",
+                    subscription.data_id()
+                );
+            }
+        }
+    }
+
+    /// Returns handles to abort the data forwarders, which stay valid after the
+    /// forwarders were taken out of the handler.
+    pub(crate) async fn data_forwarder_abort_handles(&self) -> Vec<AbortHandle> {
+        self.data_forwarders()
+            .read()
+            .await
+            .values()
+            .map(JoinHandle::abort_handle)
+            .collect()
+    }
+
+    /// Logs out all clients, i.e. step 5 of the drain of the service.
+    ///
+    /// Removes the session of each client and deletes its stream and stream user.
+    /// The data of the clients must already be stopped.
+    ///
+    /// Failures are logged and do not stop the other clients from being logged out.
+    ///
+    pub async fn close_sessions(&self) {
+        self.dbg_print("Logging out clients");
+        let sessions = self.sessions().write().await.drain();
+        for (client_id, session) in sessions {
+            let channels = session.into_channels();
+            if let Err(e) = self.close_client_channels(client_id, channels).await {
+                println!("[IMSData/shutdown]: Failed to log out client {client_id}: {e}");
+            }
+        }
+    }
+}
+
+fn shutdown_error(name: &str, e: impl std::fmt::Display) -> MessageProcessingError {
+    MessageProcessingError(format!("[shutdown]: Failed to shutdown {name}: {e}"))
 }
@@ -1,2 +1 @@
-mod utils_config;
 mod utils_send_error;
@@ -1,2 +0,0 @@
-use crate::service::Server;
-impl Server {}
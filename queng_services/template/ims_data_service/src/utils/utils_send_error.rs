@@ -1,15 +1,15 @@
 use common_errors::MessageProcessingError;
-use message_shared::SendMessage;
-use sbe_messages::ClientErrorType;
+use message_bus::MessageBus;
+use message_bus::MessageBusError;
+use sbe_messages::{ClientErrorType, DataErrorType};
 
-use crate::service::Server;
+use crate::handle::MessageHandler;
 
-impl Server {
-    /// Sends a `ClientError` message to the given producer.
+impl<B: MessageBus + Sync + 'static> MessageHandler<B> {
+    /// Sends a `ClientError` message to a client.
     ///
     /// # Parameters
     ///
-    /// * `producer` - The topic producer to send the message on
     /// * `client_id` - The id of the client the error is for
     /// * `client_error` - The `ClientErrorType` to send
     ///
@@ -24,54 +24,94 @@ impl Server {
         client_error: ClientErrorType,
     ) -> Result<(), MessageProcessingError> {
         // Encode message as SBE binary
-        let message = sbe_utils::encode_client_error(client_id, client_error)
-            .expect("Failed to encode client error message");
+        let message =
+            sbe_utils::encode_client_error(client_id, client_error).map_err(|(_, e)| e)?;
 
         // Send message
-        self.send_error(message)
-            .await
-            .expect("Failed to send client error message");
-
-        Ok(())
+        self.send_error(client_id, message).await
     }
 
-    // /// Sends a `DataError` message to the given producer.
-    // ///
-    // /// # Parameters
-    // ///
-    // /// * `producer` - The topic producer to send the message on
-    // /// * `client_id` - The id of the client the error is for
-    // /// * `data_error` - The `DataErrorType` to send
-    // ///
-    // /// # Returns
-    // ///
-    // /// Returns a `Result` with `()` if successful, otherwise returns a
-    // /// `MessageProcessingError` on failure to send.
-    // ///
-    // pub(crate) async fn send_data_error(
-    //     &self,
-    //     client_id: u16,
-    //     data_error: DataErrorType,
-    // ) -> Result<(), MessageProcessingError> {
-    //     // Encode message as SBE binary
-    //     let message = sbe_utils::encode_data_error(client_id, data_error)
-    //         .expect("Failed to encode data error message");
-    //
-    //     // Send message
-    //     self.send_error(message)
-    //         .await
-    //         .expect("Failed to send error message");
-    //
-    //     Ok(())
-    // }
+    /// Sends a `DataError` message to a client.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The id of the client the error is for
+    /// * `data_error` - The `DataErrorType` to send
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if successful, otherwise returns a
+    /// `MessageProcessingError` on failure to send.
+    ///
+    pub(crate) async fn send_data_error(
+        &self,
+        client_id: u16,
+        data_error: DataErrorType,
+    ) -> Result<(), MessageProcessingError> {
+        // Encode message as SBE binary
+        let message = sbe_utils::encode_data_error(client_id, data_error).map_err(|(_, e)| e)?;
 
-    pub(crate) async fn send_error(&self, bytes: Vec<u8>) -> Result<(), MessageProcessingError> {
         // Send message
-        self.producer()
-            .send_one_message(bytes)
+        self.send_error(client_id, message).await
+    }
+
+    /// Sends an encoded error message to a client.
+    ///
+    /// The message is sent on the error channel of the client if the client is logged in,
+    /// and on the error channel of the integration otherwise.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The id of the client the error is for
+    /// * `bytes` - The SBE encoded error message
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if successful, otherwise returns a
+    /// `MessageProcessingError` on failure to send.
+    ///
+    pub(crate) async fn send_error(
+        &self,
+        client_id: u16,
+        bytes: Vec<u8>,
+    ) -> Result<(), MessageProcessingError> {
+        let channels = self
+            .sessions()
+            .read()
             .await
-            .expect("Failed to send error message");
+            .get(client_id)
+            .map(|session| session.channels().clone());
+
+        let result = match channels {
+            Some(channels) => channels.send_error(self.bus().as_ref(), bytes).await,
+            None => self.send_integration_error(bytes).await,
+        };
+
+        result.map_err(|e| {
+            MessageProcessingError(format!("[send_error]: Failed to send error message: {e}"))
+        })
+    }
 
-        Ok(())
+    /// Sends an encoded message on the error channel of the integration.
+    ///
+    /// # Parameters
+    ///
+    /// * `bytes` - The SBE encoded message
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with `()` if successful, otherwise returns a
+    /// `MessageBusError` on failure to send.
+    ///
+    pub(crate) async fn send_integration_error(
+        &self,
+        bytes: Vec<u8>,
+    ) -> Result<(), MessageBusError> {
+        let stream_id = self.integration_config().integration_id();
+        let topic_id = self.integration_config().error_channel();
+
+        self.bus()
+            .send(stream_id, &topic_id, None, vec![bytes])
+            .await
     }
 }
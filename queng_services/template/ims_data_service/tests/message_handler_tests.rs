@@ -0,0 +1,454 @@
+use common_iggy::{IggyConfig, IggyUser};
+use common_ims::{ImsIntegrationType, IntegrationConfig, IntegrationMessageConfig};
+use data_integration_factory::{DataIntegration, SampleDataIntegration};
+use ims_data_service::MessageHandler;
+use message_bus::{InMemoryMessageBus, MessageBus, MessageBusError};
+use sbe_messages::{
+    decode_any, ClientErrorType, ClientLoginMessage, ClientLoginReplyMessage, ClientLogoutMessage,
+    DataErrorType, DataType, ExchangeID, HeartbeatMessage, SbeMessage, StartDataMessage,
+    StopDataMessage, TimeResolution,
+};
+use std::time::Duration;
+
+const INTEGRATION_ID: &str = "ims-test";
+const CLIENT_ID: u16 = 7;
+const CLIENT_STREAM: &str = "stream_7";
+const CLIENT_USER: &str = "client_7";
+const SECRET: u128 = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210;
+const SECRET_HEX: &str = "0123456789abcdeffedcba9876543210";
+
+fn integration_config() -> IntegrationConfig {
+    IntegrationConfig::new(
+        INTEGRATION_ID.to_string(),
+        1,
+        ImsIntegrationType::Data,
+        IntegrationMessageConfig::new(1, 1),
+    )
+}
+
+/// Creates a handler on the given bus with the integration error channel provisioned.
+async fn handler(bus: &InMemoryMessageBus) -> MessageHandler<InMemoryMessageBus> {
+    handler_with_config(bus, integration_config()).await
+}
+
+async fn handler_with_config(
+    bus: &InMemoryMessageBus,
+    integration_config: IntegrationConfig,
+) -> MessageHandler<InMemoryMessageBus> {
+    let iggy_config = IggyConfig::from_client_id(IggyUser::new("ims", "ims"), 1, 100, true);
+
+    bus.create_stream(INTEGRATION_ID).await.unwrap();
+    bus.create_topic(INTEGRATION_ID, &integration_config.error_channel(), 1)
+        .await
+        .unwrap();
+
+    let integration = DataIntegration::SampleDataIntegration(SampleDataIntegration::new());
+    MessageHandler::new(
+        false,
+        bus.clone(),
+        integration_config,
+        iggy_config,
+        integration,
+    )
+}
+
+/// Polls and decodes all messages not yet polled from the first partition of a topic.
+async fn poll(bus: &InMemoryMessageBus, stream_id: &str, topic_id: &str) -> Vec<SbeMessage> {
+    bus.poll(stream_id, topic_id, 1, "test", 100, true)
+        .await
+        .unwrap()
+        .iter()
+        .map(|message| decode_any(message.payload()).unwrap())
+        .collect()
+}
+
+async fn poll_integration_errors(bus: &InMemoryMessageBus) -> Vec<SbeMessage> {
+    poll(bus, INTEGRATION_ID, &format!("{INTEGRATION_ID}-error")).await
+}
+
+async fn poll_errors_of(bus: &InMemoryMessageBus, client_id: u16) -> Vec<SbeMessage> {
+    let error_channel = IntegrationMessageConfig::new(client_id, 1).error_channel();
+    poll(bus, &format!("stream_{client_id}"), &error_channel).await
+}
+
+async fn poll_client_errors(bus: &InMemoryMessageBus) -> Vec<SbeMessage> {
+    poll_errors_of(bus, CLIENT_ID).await
+}
+
+async fn send_login(handler: &MessageHandler<InMemoryMessageBus>, message: ClientLoginMessage) {
+    let message = SbeMessage::ClientLogin(message);
+    handler.handle_message(&message).await.unwrap();
+}
+
+/// Logs in the client and consumes the login reply from its error channel.
+async fn login(bus: &InMemoryMessageBus, handler: &MessageHandler<InMemoryMessageBus>) {
+    send_login(handler, ClientLoginMessage::with_secret(CLIENT_ID, SECRET)).await;
+
+    let replies = poll_client_errors(bus).await;
+    assert_eq!(
+        replies,
+        vec![SbeMessage::ClientLoginReply(ClientLoginReplyMessage::new(
+            CLIENT_ID
+        ))]
+    );
+}
+
+fn start_data() -> SbeMessage {
+    SbeMessage::StartData(StartDataMessage::new(
+        CLIENT_ID,
+        ExchangeID::BNB,
+        1,
+        TimeResolution::OneMin,
+        DataType::TradeData,
+    ))
+}
+
+fn stop_data() -> SbeMessage {
+    SbeMessage::StopData(StopDataMessage::new(
+        CLIENT_ID,
+        ExchangeID::BNB,
+        1,
+        DataType::TradeData,
+    ))
+}
+
+fn client_error_type(message: &SbeMessage) -> ClientErrorType {
+    match message {
+        SbeMessage::ClientError(client_error) => client_error.client_error_type(),
+        other => panic!("Expected a client error, got {other}"),
+    }
+}
+
+fn data_error_type(message: &SbeMessage) -> DataErrorType {
+    match message {
+        SbeMessage::DataError(data_error) => data_error.data_error_type(),
+        other => panic!("Expected a data error, got {other}"),
+    }
+}
+
+#[tokio::test]
+async fn test_login_creates_client_stream_user_with_secret_of_client() {
+    let bus = InMemoryMessageBus::new();
+    let handler = handler(&bus).await;
+
+    login(&bus, &handler).await;
+
+    assert!(bus.can_read(CLIENT_STREAM, CLIENT_USER, SECRET_HEX).await);
+
+    // Nothing is sent on the shared error channel of the integration
+    assert!(poll_integration_errors(&bus).await.is_empty());
+
+    // The data channel of the client exists and is still empty
+    let data_channel = IntegrationMessageConfig::new(CLIENT_ID, 1).data_channel();
+    assert!(poll(&bus, CLIENT_STREAM, &data_channel).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_login_reply_is_not_readable_by_other_clients() {
+    let bus = InMemoryMessageBus::new();
+    let handler = handler(&bus).await;
+    let other_secret = 42;
+    let other_secret_hex = format!("{other_secret:032x}");
+
+    login(&bus, &handler).await;
+    send_login(&handler, ClientLoginMessage::with_secret(8, other_secret)).await;
+
+    // Each client reads its own reply on its own stream
+    assert!(
+        bus.can_read("stream_8", "client_8", &other_secret_hex)
+            .await
+    );
+    assert_eq!(
+        poll_errors_of(&bus, 8).await,
+        vec![SbeMessage::ClientLoginReply(ClientLoginReplyMessage::new(
+            8
+        ))]
+    );
+
+    // The second client can read neither the stream of the first client nor a broadcast reply
+    assert!(
+        !bus.can_read(CLIENT_STREAM, "client_8", &other_secret_hex)
+            .await
+    );
+    assert!(
+        !bus.can_read(CLIENT_STREAM, CLIENT_USER, &other_secret_hex)
+            .await
+    );
+    assert!(poll_integration_errors(&bus).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_login_without_secret_replies_login_error() {
+    let bus = InMemoryMessageBus::new();
+    let handler = handler(&bus).await;
+
+    send_login(&handler, ClientLoginMessage::new(CLIENT_ID)).await;
+
+    let errors = poll_integration_errors(&bus).await;
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        client_error_type(&errors[0]),
+        ClientErrorType::ClientLogInError
+    );
+    assert_eq!(
+        bus.delete_user(CLIENT_USER).await,
+        Err(MessageBusError::UserNotFound(CLIENT_USER.to_string()))
+    );
+}
+
+#[tokio::test]
+async fn test_login_after_logout_replaces_secret() {
+    let bus = InMemoryMessageBus::new();
+    let handler = handler(&bus).await;
+    let new_secret = 42;
+    let new_secret_hex = format!("{new_secret:032x}");
+
+    login(&bus, &handler).await;
+    let logout = SbeMessage::ClientLogout(ClientLogoutMessage::new(CLIENT_ID));
+    handler.handle_message(&logout).await.unwrap();
+    send_login(
+        &handler,
+        ClientLoginMessage::with_secret(CLIENT_ID, new_secret),
+    )
+    .await;
+
+    assert!(!bus.can_read(CLIENT_STREAM, CLIENT_USER, SECRET_HEX).await);
+    assert!(
+        bus.can_read(CLIENT_STREAM, CLIENT_USER, &new_secret_hex)
+            .await
+    );
+}
+
+#[tokio::test]
+async fn test_login_twice_replies_already_logged_in() {
+    let bus = InMemoryMessageBus::new();
+    let handler = handler(&bus).await;
+
+    login(&bus, &handler).await;
+    send_login(&handler, ClientLoginMessage::with_secret(CLIENT_ID, SECRET)).await;
+
+    let errors = poll_client_errors(&bus).await;
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        client_error_type(&errors[0]),
+        ClientErrorType::ClientAlreadyLoggedIn
+    );
+}
+
+#[tokio::test]
+async fn test_logout_deletes_client_stream_and_user() {
+    let bus = InMemoryMessageBus::new();
+    let handler = handler(&bus).await;
+
+    login(&bus, &handler).await;
+    let logout = SbeMessage::ClientLogout(ClientLogoutMessage::new(CLIENT_ID));
+    handler.handle_message(&logout).await.unwrap();
+
+    assert_eq!(
+        bus.delete_stream(CLIENT_STREAM).await,
+        Err(MessageBusError::StreamNotFound(CLIENT_STREAM.to_string()))
+    );
+    assert_eq!(
+        bus.delete_user(CLIENT_USER).await,
+        Err(MessageBusError::UserNotFound(CLIENT_USER.to_string()))
+    );
+}
+
+#[tokio::test]
+async fn test_logout_without_login_replies_not_logged_in() {
+    let bus = InMemoryMessageBus::new();
+    let handler = handler(&bus).await;
+
+    let logout = SbeMessage::ClientLogout(ClientLogoutMessage::new(CLIENT_ID));
+    handler.handle_message(&logout).await.unwrap();
+
+    let errors = poll_integration_errors(&bus).await;
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        client_error_type(&errors[0]),
+        ClientErrorType::ClientNotLoggedIn
+    );
+}
+
+#[tokio::test]
+async fn test_start_data_without_login_replies_not_logged_in() {
+    let bus = InMemoryMessageBus::new();
+    let handler = handler(&bus).await;
+
+    handler.handle_message(&start_data()).await.unwrap();
+
+    let errors = poll_integration_errors(&bus).await;
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        client_error_type(&errors[0]),
+        ClientErrorType::ClientNotLoggedIn
+    );
+}
+
+#[tokio::test]
+async fn test_start_data_with_unknown_data_type_replies_data_error() {
+    let bus = InMemoryMessageBus::new();
+    let handler = handler(&bus).await;
+
+    login(&bus, &handler).await;
+    let start_data = SbeMessage::StartData(StartDataMessage::new(
+        CLIENT_ID,
+        ExchangeID::BNB,
+        1,
+        TimeResolution::NoValue,
+        DataType::OHLCVData,
+    ));
+    handler.handle_message(&start_data).await.unwrap();
+
+    let errors = poll_client_errors(&bus).await;
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        data_error_type(&errors[0]),
+        DataErrorType::DataTypeNotKnownError
+    );
+}
+
+#[tokio::test]
+async fn test_start_and_stop_data() {
+    let bus = InMemoryMessageBus::new();
+    let handler = handler(&bus).await;
+
+    login(&bus, &handler).await;
+    handler.handle_message(&start_data()).await.unwrap();
+    handler.handle_message(&stop_data()).await.unwrap();
+
+    assert!(poll_client_errors(&bus).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_stop_data_without_start_replies_data_unavailable() {
+    let bus = InMemoryMessageBus::new();
+    let handler = handler(&bus).await;
+
+    login(&bus, &handler).await;
+    handler.handle_message(&stop_data()).await.unwrap();
+
+    let errors = poll_client_errors(&bus).await;
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        data_error_type(&errors[0]),
+        DataErrorType::DataUnavailableError
+    );
+}
+
+#[tokio::test]
+async fn test_stop_data_twice_replies_data_unavailable() {
+    let bus = InMemoryMessageBus::new();
+    let handler = handler(&bus).await;
+
+    login(&bus, &handler).await;
+    handler.handle_message(&start_data()).await.unwrap();
+    handler.handle_message(&stop_data()).await.unwrap();
+    handler.handle_message(&stop_data()).await.unwrap();
+
+    let errors = poll_client_errors(&bus).await;
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        data_error_type(&errors[0]),
+        DataErrorType::DataUnavailableError
+    );
+}
+
+/// Heartbeat settings for the reaper tests: clients are logged out after 300 ms
+/// without a heartbeat.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_millis(100);
+const MISSED_HEARTBEATS: u32 = 3;
+
+async fn reaper_handler(bus: &InMemoryMessageBus) -> MessageHandler<InMemoryMessageBus> {
+    let integration_config = integration_config()
+        .with_heartbeat_interval(HEARTBEAT_INTERVAL)
+        .unwrap()
+        .with_missed_heartbeats(MISSED_HEARTBEATS);
+    handler_with_config(bus, integration_config).await
+}
+
+fn heartbeat() -> SbeMessage {
+    SbeMessage::Heartbeat(HeartbeatMessage::new(CLIENT_ID))
+}
+
+/// Sends a heartbeat and returns true if the client is still logged in.
+async fn is_logged_in(
+    bus: &InMemoryMessageBus,
+    handler: &MessageHandler<InMemoryMessageBus>,
+) -> bool {
+    handler.handle_message(&heartbeat()).await.unwrap();
+
+    let errors = poll_integration_errors(bus).await;
+    match errors.as_slice() {
+        [] => true,
+        [error] => {
+            assert_eq!(client_error_type(error), ClientErrorType::ClientNotLoggedIn);
+            false
+        }
+        _ => panic!("Expected at most one error, got {}", errors.len()),
+    }
+}
+
+#[tokio::test]
+async fn test_heartbeat_keeps_session_alive() {
+    let bus = InMemoryMessageBus::new();
+    let handler = reaper_handler(&bus).await;
+    login(&bus, &handler).await;
+
+    tokio::time::sleep(HEARTBEAT_INTERVAL * 2).await;
+    handler.handle_message(&heartbeat()).await.unwrap();
+    tokio::time::sleep(HEARTBEAT_INTERVAL * 2).await;
+    handler.reap_idle_sessions().await;
+
+    assert!(is_logged_in(&bus, &handler).await);
+}
+
+#[tokio::test]
+async fn test_other_messages_do_not_keep_session_alive() {
+    let bus = InMemoryMessageBus::new();
+    let handler = reaper_handler(&bus).await;
+    login(&bus, &handler).await;
+
+    tokio::time::sleep(HEARTBEAT_INTERVAL * 2).await;
+    handler.handle_message(&start_data()).await.unwrap();
+    tokio::time::sleep(HEARTBEAT_INTERVAL * 2).await;
+    handler.reap_idle_sessions().await;
+
+    assert!(!is_logged_in(&bus, &handler).await);
+}
+
+#[tokio::test]
+async fn test_heartbeat_without_login_replies_not_logged_in() {
+    let bus = InMemoryMessageBus::new();
+    let handler = handler(&bus).await;
+
+    assert!(!is_logged_in(&bus, &handler).await);
+}
+
+#[tokio::test]
+async fn test_drain_notifies_and_logs_out_clients() {
+    let bus = InMemoryMessageBus::new();
+    let handler = handler(&bus).await;
+
+    login(&bus, &handler).await;
+    handler.handle_message(&start_data()).await.unwrap();
+    handler.drain_clients().await;
+
+    let errors = poll_client_errors(&bus).await;
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        client_error_type(&errors[0]),
+        ClientErrorType::ServiceShutdown
+    );
+
+    handler.close_sessions().await;
+    assert_eq!(
+        bus.delete_stream(CLIENT_STREAM).await,
+        Err(MessageBusError::StreamNotFound(CLIENT_STREAM.to_string()))
+    );
+    assert_eq!(
+        bus.delete_user(CLIENT_USER).await,
+        Err(MessageBusError::UserNotFound(CLIENT_USER.to_string()))
+    );
+    assert!(!is_logged_in(&bus, &handler).await);
+}
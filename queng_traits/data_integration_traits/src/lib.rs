@@ -1,11 +1,17 @@
 use enum_dispatch::enum_dispatch;
 use std::io::Error;
 
+/// Channel on which a data integration sends the SBE encoded messages of a data id.
+///
+/// The integration drops the sender when the data of the data id stops.
+pub type DataSender = tokio::sync::mpsc::Sender<Vec<u8>>;
+
 #[enum_dispatch(DataIntegration)]
 pub trait DataIntegrationFactory {
     fn start_date(
         &self,
         data_id: &str,
+        sender: DataSender,
     ) -> impl std::future::Future<Output = Result<(), Error>> + Send;
     fn stop_date(
         &self,